@@ -0,0 +1,91 @@
+//! Rolling health metrics for a server: how many players are still fishing,
+//! how long their streaks run, and who has drifted away. Shown by
+//! `/serverstats` and in the weekly summary.
+
+use crate::data::GuildData;
+use crate::game::FishingManager;
+
+/// The short window, in days (today included)
+pub const WEEK_DAYS: i64 = 7;
+/// The long window, in days (today included)
+pub const MONTH_DAYS: i64 = 30;
+/// Churned players listed by name; the rest are only counted
+pub const MAX_CHURNED_NAMES: usize = 10;
+
+/// A snapshot of a server's player activity
+#[derive(Debug, Clone, Default)]
+pub struct ServerStats {
+    /// Everyone who has ever landed a fish here
+    pub players: usize,
+    /// Players who fished in the last [`WEEK_DAYS`] days
+    pub active_week: usize,
+    /// Players who fished in the last [`MONTH_DAYS`] days
+    pub active_month: usize,
+    /// Mean length of the streaks still running
+    pub average_streak: f64,
+    /// Players on a streak right now
+    pub streaking: usize,
+    /// Players who fished in the last month but not the last week and
+    /// aren't on vacation
+    pub churned: usize,
+    /// Up to [`MAX_CHURNED_NAMES`] of them, most recently seen first
+    pub churned_names: Vec<String>,
+}
+
+impl ServerStats {
+    /// Share of all players who fished in the last week, 0.0 to 1.0
+    pub fn weekly_participation(&self) -> f64 {
+        share(self.active_week, self.players)
+    }
+
+    /// Share of all players who fished in the last month, 0.0 to 1.0
+    pub fn monthly_participation(&self) -> f64 {
+        share(self.active_month, self.players)
+    }
+}
+
+fn share(part: usize, whole: usize) -> f64 {
+    if whole == 0 {
+        0.0
+    } else {
+        part as f64 / whole as f64
+    }
+}
+
+/// Work out the metrics for `data` as of `today` (YYYY-MM-DD)
+pub fn server_stats(data: &GuildData, today: &str) -> ServerStats {
+    let mut stats = ServerStats::default();
+    let mut streak_total = 0;
+    let mut churned = Vec::new();
+
+    for p_user in data.persistent_users.values() {
+        if p_user.last_fished_date.is_empty() {
+            continue;
+        }
+        stats.players += 1;
+        let days_since = FishingManager::get_days_difference(&p_user.last_fished_date, today);
+        if days_since < WEEK_DAYS {
+            stats.active_week += 1;
+        }
+        if days_since < MONTH_DAYS {
+            stats.active_month += 1;
+            if days_since >= WEEK_DAYS && !p_user.on_vacation(today) {
+                churned.push((days_since, p_user.username.as_str()));
+            }
+        }
+        if p_user.streak > 0 {
+            stats.streaking += 1;
+            streak_total += p_user.streak;
+        }
+    }
+
+    stats.average_streak = share(streak_total as usize, stats.streaking);
+    stats.churned = churned.len();
+    churned.sort();
+    stats.churned_names = churned
+        .into_iter()
+        .take(MAX_CHURNED_NAMES)
+        .map(|(_, name)| name.to_string())
+        .collect();
+    stats
+}
@@ -0,0 +1,58 @@
+//! Compaction of old history into gzipped JSON lines files, so the live data
+//! stays small no matter how long the bot has been running. Each run that
+//! finds something old writes one `fishing_archive_<timestamp>.jsonl.gz`.
+
+use crate::data::{CatchRecord, DailyTotal};
+use crate::storage::StorageError;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::Serialize;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// One line of an archive file
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum ArchivedEntry {
+    #[serde(rename_all = "camelCase")]
+    Catch {
+        guild_id: String,
+        user_id: String,
+        #[serde(flatten)]
+        record: CatchRecord,
+    },
+    #[serde(rename_all = "camelCase")]
+    Day {
+        guild_id: String,
+        #[serde(flatten)]
+        day: DailyTotal,
+    },
+}
+
+/// What a compaction moved out of the live data
+#[derive(Debug, Default)]
+pub struct Compaction {
+    pub catches: usize,
+    pub days: usize,
+    /// The archive written, if anything was old enough
+    pub file: Option<PathBuf>,
+}
+
+/// Compress `entries` into a new file in `dir`
+pub async fn write(dir: &Path, entries: &[ArchivedEntry]) -> Result<PathBuf, StorageError> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    for entry in entries {
+        serde_json::to_writer(&mut encoder, entry)?;
+        encoder.write_all(b"\n")?;
+    }
+    let bytes = encoder.finish()?;
+
+    tokio::fs::create_dir_all(dir).await?;
+    let timestamp = chrono::Local::now().format("%Y-%m-%dT%H-%M-%S");
+    let path = dir.join(format!("fishing_archive_{}.jsonl.gz", timestamp));
+    // Write then rename so a crash never leaves a truncated archive behind
+    let temp_path = path.with_extension("gz.tmp");
+    tokio::fs::write(&temp_path, bytes).await?;
+    tokio::fs::rename(&temp_path, &path).await?;
+    Ok(path)
+}
@@ -0,0 +1,118 @@
+use crate::data::{GuildData, UserReset};
+use serde::{Deserialize, Serialize};
+
+/// Oldest entries are dropped past this many
+const MAX_AUDIT_ENTRIES: usize = 1000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum AuditAction {
+    #[serde(rename_all = "camelCase")]
+    GiftCoins { to: String, amount: u64 },
+    #[serde(rename_all = "camelCase")]
+    GiftItem {
+        to: String,
+        item: String,
+        amount: u64,
+    },
+    #[serde(rename_all = "camelCase")]
+    DuelWager { to: String, amount: u64 },
+    #[serde(rename_all = "camelCase")]
+    Referral { to: String },
+    #[serde(rename_all = "camelCase")]
+    RepairStreak {
+        to: String,
+        date: String,
+        streak: u64,
+    },
+    #[serde(rename_all = "camelCase")]
+    ResetUser { to: String, scope: UserReset },
+    #[serde(rename_all = "camelCase")]
+    SetStreak { to: String, from: u64, streak: u64 },
+    #[serde(rename_all = "camelCase")]
+    AdjustCatches { to: String, amount: i64, total: u64 },
+    #[serde(rename_all = "camelCase")]
+    MergeUsers { from: String, to: String },
+    #[serde(rename_all = "camelCase")]
+    ImportStreaks { added: u64, updated: u64 },
+}
+
+impl AuditAction {
+    /// Human readable line for `/fishadmin audit`
+    pub fn describe(&self) -> String {
+        match self {
+            AuditAction::GiftCoins { to, amount } => {
+                format!("gifted {} coins to <@{}>", amount, to)
+            }
+            AuditAction::GiftItem { to, item, amount } => {
+                let name = crate::items::find_item(item).map_or(item.as_str(), |i| i.name);
+                format!("gifted {}× {} to <@{}>", amount, name, to)
+            }
+            AuditAction::DuelWager { to, amount } => {
+                format!("lost a {} coin duel to <@{}>", amount, to)
+            }
+            AuditAction::Referral { to } => format!("credited themselves for inviting <@{}>", to),
+            AuditAction::RepairStreak { to, date, streak } => {
+                format!("repaired <@{}>'s streak for {} ({} days)", to, date, streak)
+            }
+            AuditAction::ResetUser { to, scope } => {
+                format!("reset <@{}>'s {}", to, scope.label())
+            }
+            AuditAction::SetStreak { to, from, streak } => {
+                format!("set <@{}>'s streak from {} to {} days", to, from, streak)
+            }
+            AuditAction::AdjustCatches { to, amount, total } => {
+                format!(
+                    "adjusted <@{}>'s catches by {:+} (now {})",
+                    to, amount, total
+                )
+            }
+            AuditAction::MergeUsers { from, to } => {
+                format!("merged <@{}>'s record into <@{}>", from, to)
+            }
+            AuditAction::ImportStreaks { added, updated } => {
+                format!(
+                    "imported streaks from a sheet ({} new players, {} updated)",
+                    added, updated
+                )
+            }
+        }
+    }
+
+    /// Every user this action touched besides the actor
+    pub fn subject(&self) -> Option<&str> {
+        match self {
+            AuditAction::GiftCoins { to, .. }
+            | AuditAction::GiftItem { to, .. }
+            | AuditAction::DuelWager { to, .. }
+            | AuditAction::Referral { to }
+            | AuditAction::RepairStreak { to, .. }
+            | AuditAction::ResetUser { to, .. }
+            | AuditAction::SetStreak { to, .. }
+            | AuditAction::AdjustCatches { to, .. }
+            | AuditAction::MergeUsers { to, .. } => Some(to),
+            AuditAction::ImportStreaks { .. } => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditEntry {
+    pub at: u64,
+    pub actor_id: String,
+    pub action: AuditAction,
+}
+
+/// Append to the audit trail, trimming the oldest entries
+pub fn record(data: &mut GuildData, actor_id: &str, action: AuditAction) {
+    data.audit_log.push(AuditEntry {
+        at: chrono::Utc::now().timestamp_millis() as u64,
+        actor_id: actor_id.to_string(),
+        action,
+    });
+    if data.audit_log.len() > MAX_AUDIT_ENTRIES {
+        let excess = data.audit_log.len() - MAX_AUDIT_ENTRIES;
+        data.audit_log.drain(..excess);
+    }
+}
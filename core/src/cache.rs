@@ -0,0 +1,118 @@
+//! Optional Redis cache for lookups that large servers make constantly:
+//! who has fished since the last reset, and which members hold a role.
+//!
+//! The data file or database stays the source of truth. The cache only ever
+//! answers "yes, already fished" and falls back to the real data on a miss,
+//! so a cold, flushed, or unreachable Redis just means slower lookups.
+
+use crate::storage::StorageError;
+use redis::AsyncCommands;
+
+/// Fished-today sets outlive a missed reset by at most this long
+const FISHED_TTL_SECS: i64 = 36 * 60 * 60;
+
+/// How long a server's role member list is trusted before it's fetched again
+pub const ROLE_MEMBERS_TTL_SECS: u64 = 10 * 60;
+
+pub struct RedisCache {
+    conn: redis::aio::ConnectionManager,
+    /// Prepended to every key, so several bots can share one Redis
+    prefix: String,
+}
+
+impl RedisCache {
+    /// Connect to `url`, e.g. `redis://127.0.0.1/`
+    pub async fn connect(url: &str, prefix: impl Into<String>) -> Result<Self, StorageError> {
+        let client = redis::Client::open(url)?;
+        Ok(Self {
+            conn: client.get_connection_manager().await?,
+            prefix: prefix.into(),
+        })
+    }
+
+    fn fished_key(&self, guild_id: &str) -> String {
+        format!("{}{}:fished", self.prefix, guild_id)
+    }
+
+    fn role_key(&self, guild_id: &str, role_id: &str) -> String {
+        format!("{}{}:role:{}", self.prefix, guild_id, role_id)
+    }
+
+    /// Whether `user_id` is known to have fished since the last reset
+    pub async fn fished_today(&self, guild_id: &str, user_id: &str) -> bool {
+        let mut conn = self.conn.clone();
+        conn.sismember(self.fished_key(guild_id), user_id)
+            .await
+            .unwrap_or_else(|e| {
+                tracing::warn!("⚠️ Redis lookup failed: {}", e);
+                false
+            })
+    }
+
+    pub async fn mark_fished(&self, guild_id: &str, user_id: &str) {
+        let key = self.fished_key(guild_id);
+        let mut conn = self.conn.clone();
+        let result: redis::RedisResult<()> = redis::pipe()
+            .sadd(&key, user_id)
+            .ignore()
+            .expire(&key, FISHED_TTL_SECS)
+            .ignore()
+            .query_async(&mut conn)
+            .await;
+        if let Err(e) = result {
+            tracing::warn!("⚠️ Redis update failed: {}", e);
+        }
+    }
+
+    pub async fn unmark_fished(&self, guild_id: &str, user_id: &str) {
+        let mut conn = self.conn.clone();
+        if let Err(e) = conn
+            .srem::<_, _, ()>(self.fished_key(guild_id), user_id)
+            .await
+        {
+            tracing::warn!("⚠️ Redis update failed: {}", e);
+        }
+    }
+
+    /// Forget who fished in these servers, e.g. after the daily reset
+    pub async fn clear_fished(&self, guild_ids: &[String]) {
+        if guild_ids.is_empty() {
+            return;
+        }
+        let keys: Vec<String> = guild_ids.iter().map(|id| self.fished_key(id)).collect();
+        let mut conn = self.conn.clone();
+        if let Err(e) = conn.del::<_, ()>(keys).await {
+            tracing::warn!("⚠️ Redis update failed: {}", e);
+        }
+    }
+
+    /// IDs of the members holding `role_id`, if fetched recently
+    pub async fn role_members(&self, guild_id: &str, role_id: &str) -> Option<Vec<String>> {
+        let mut conn = self.conn.clone();
+        let cached: Option<String> = match conn.get(self.role_key(guild_id, role_id)).await {
+            Ok(cached) => cached,
+            Err(e) => {
+                tracing::warn!("⚠️ Redis lookup failed: {}", e);
+                return None;
+            }
+        };
+        serde_json::from_str(&cached?).ok()
+    }
+
+    pub async fn set_role_members(&self, guild_id: &str, role_id: &str, member_ids: &[String]) {
+        let Ok(json) = serde_json::to_string(member_ids) else {
+            return;
+        };
+        let mut conn = self.conn.clone();
+        if let Err(e) = conn
+            .set_ex::<_, _, ()>(
+                self.role_key(guild_id, role_id),
+                json,
+                ROLE_MEMBERS_TTL_SECS,
+            )
+            .await
+        {
+            tracing::warn!("⚠️ Redis update failed: {}", e);
+        }
+    }
+}
@@ -0,0 +1,70 @@
+//! Encryption at rest for the data file and its backups, for hosts where
+//! other people can read the disk. Files are sealed with AES-256-GCM under a
+//! key from `DATA_ENCRYPTION_KEY`, and start with a short marker so plain
+//! files from before encryption was switched on are still read (and
+//! encrypted on the next save).
+
+use crate::storage::StorageError;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Nonce};
+
+/// Marks a sealed file; followed by the 12-byte nonce and the ciphertext
+const MAGIC: &[u8] = b"SPENC1";
+const NONCE_LEN: usize = 12;
+
+/// The key data files are sealed with
+#[derive(Clone)]
+pub struct DataKey(Aes256Gcm);
+
+impl DataKey {
+    /// Read `DATA_ENCRYPTION_KEY`: 32 bytes written as 64 hex characters,
+    /// e.g. from `openssl rand -hex 32`. `None` when it isn't set.
+    pub fn from_env() -> Result<Option<Self>, StorageError> {
+        let Some(hex_key) = std::env::var("DATA_ENCRYPTION_KEY")
+            .ok()
+            .filter(|v| !v.is_empty())
+        else {
+            return Ok(None);
+        };
+        let bytes = hex::decode(hex_key.trim())?;
+        if bytes.len() != 32 {
+            return Err("DATA_ENCRYPTION_KEY must be 64 hex characters (32 bytes)".into());
+        }
+        Ok(Some(Self(Aes256Gcm::new_from_slice(&bytes)?)))
+    }
+
+    /// Seal `plain` with a fresh nonce
+    pub fn encrypt(&self, plain: &[u8]) -> Result<Vec<u8>, StorageError> {
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let sealed = self
+            .0
+            .encrypt(&nonce, plain)
+            .map_err(|_| "encryption failed")?;
+        let mut out = Vec::with_capacity(MAGIC.len() + NONCE_LEN + sealed.len());
+        out.extend_from_slice(MAGIC);
+        out.extend_from_slice(&nonce);
+        out.extend_from_slice(&sealed);
+        Ok(out)
+    }
+
+    /// Open a sealed file, passing plain ones through unchanged
+    pub fn decrypt(&self, bytes: Vec<u8>) -> Result<Vec<u8>, StorageError> {
+        let Some(body) = bytes.strip_prefix(MAGIC) else {
+            return Ok(bytes);
+        };
+        if body.len() < NONCE_LEN {
+            return Err("encrypted data is truncated".into());
+        }
+        let (nonce, sealed) = body.split_at(NONCE_LEN);
+        self.0
+            .decrypt(Nonce::from_slice(nonce), sealed)
+            .map_err(|_| {
+                "could not decrypt data; is DATA_ENCRYPTION_KEY the key it was saved with?".into()
+            })
+    }
+}
+
+/// Whether `bytes` were sealed by [`DataKey::encrypt`]
+pub fn is_encrypted(bytes: &[u8]) -> bool {
+    bytes.starts_with(MAGIC)
+}
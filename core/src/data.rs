@@ -0,0 +1,1940 @@
+use crate::migrations::{self, SCHEMA_VERSION, UNASSIGNED_GUILD};
+use crate::storage::{BackupInfo, Storage, StorageError};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{
+    Mutex, Notify, RwLock, RwLockMappedWriteGuard, RwLockReadGuard, RwLockWriteGuard,
+};
+
+// Define struct similar to existing JSON structure
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct UserData {
+    pub username: String,
+    #[serde(rename = "fishedAt")]
+    pub fished_at: String, // Stored as ISO string in JSON
+    #[serde(default)]
+    pub location: Option<String>,
+    #[serde(default)]
+    pub species: Option<String>,
+    /// Whether today's bonus wheel has been spun
+    #[serde(default)]
+    pub spun: bool,
+    /// Casts made today (0 in older data means one)
+    #[serde(default)]
+    pub casts: u32,
+}
+
+/// Who brought a member to the pond, recorded with `/invitecredit`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Referral {
+    pub referrer_id: String,
+    pub at: u64,
+    /// Set once the referrer has been paid
+    #[serde(default)]
+    pub rewarded: bool,
+}
+
+/// A player's daily `/remindme` DM
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DmReminder {
+    /// Time of day on the player's clock (their `/settimezone`, else the server's)
+    pub at: chrono::NaiveTime,
+    /// The player's local day (YYYY-MM-DD) it last came due, so it fires once a day
+    #[serde(default)]
+    pub last_due: Option<String>,
+}
+
+/// A player's `/fortune` for the day
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DailyFortune {
+    pub text: String,
+    /// Multiplier applied to the player's casts until the reset
+    pub luck: f64,
+}
+
+/// Catches kept on a player's record; older ones go to the storage archive
+pub const MAX_CATCH_HISTORY: usize = 365;
+
+/// One landed fish
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CatchRecord {
+    pub at: u64,
+    pub location: String,
+    pub species: String,
+    pub size_cm: u32,
+    #[serde(default)]
+    pub shiny: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PersistentUserData {
+    pub username: String,
+    pub streak: u64,
+    /// Longest streak ever reached (records from before this was kept
+    /// start from their running streak)
+    #[serde(rename = "bestStreak", default)]
+    pub best_streak: u64,
+    #[serde(rename = "lastFishedDate")]
+    pub last_fished_date: String,
+    #[serde(rename = "totalCatches")]
+    pub total_catches: u64,
+    #[serde(default)]
+    pub coins: u64,
+    #[serde(rename = "unlockedLocations", default)]
+    pub unlocked_locations: Vec<String>,
+    /// Item ID -> quantity owned
+    #[serde(default)]
+    pub inventory: HashMap<String, u64>,
+    /// Title IDs earned so far
+    #[serde(default)]
+    pub titles: Vec<String>,
+    #[serde(rename = "equippedTitle", default)]
+    pub equipped_title: Option<String>,
+    /// ID of the team this user fishes for
+    #[serde(default)]
+    pub team: Option<String>,
+    /// Most recent catches, oldest first (capped at `MAX_CATCH_HISTORY`)
+    #[serde(default)]
+    pub history: Vec<CatchRecord>,
+    /// Species IDs ever landed, for fishdex completion
+    #[serde(default)]
+    pub fishdex: Vec<String>,
+    /// Duels won, keyed by the opponent's user ID
+    #[serde(rename = "duelWins", default)]
+    pub duel_wins: HashMap<String, u64>,
+    /// Last day (inclusive) of a declared absence; the streak is frozen until then
+    #[serde(rename = "vacationUntil", default)]
+    pub vacation_until: Option<String>,
+    /// Days an admin marked as fished via `/repairstreak`
+    #[serde(rename = "repairedDates", default)]
+    pub repaired_dates: Vec<String>,
+    /// Registered birthday as MM-DD
+    #[serde(default)]
+    pub birthday: Option<String>,
+    /// IANA timezone (e.g. `Europe/Berlin`) used when showing times to
+    /// this player; the daily reset itself stays the same for everyone
+    #[serde(default)]
+    pub timezone: Option<String>,
+}
+
+impl PersistentUserData {
+    /// A blank record for someone who has never fished
+    pub fn new(username: String) -> Self {
+        Self {
+            username,
+            streak: 0,
+            best_streak: 0,
+            last_fished_date: String::new(),
+            total_catches: 0,
+            coins: 0,
+            unlocked_locations: Vec::new(),
+            inventory: HashMap::new(),
+            titles: Vec::new(),
+            equipped_title: None,
+            team: None,
+            history: Vec::new(),
+            fishdex: Vec::new(),
+            duel_wins: HashMap::new(),
+            vacation_until: None,
+            repaired_dates: Vec::new(),
+            birthday: None,
+            timezone: None,
+        }
+    }
+
+    /// Set the running streak, keeping it as the best if it's a new high
+    pub fn set_streak(&mut self, streak: u64) {
+        self.streak = streak;
+        self.best_streak = self.best_streak.max(streak);
+    }
+
+    /// Longest streak ever reached, the running one included
+    pub fn longest_streak(&self) -> u64 {
+        self.best_streak.max(self.streak)
+    }
+
+    /// Add a catch to the history, returning any entries pushed past the cap
+    pub fn record_catch(&mut self, record: CatchRecord) -> Vec<CatchRecord> {
+        if !self.fishdex.contains(&record.species) {
+            self.fishdex.push(record.species.clone());
+        }
+        self.history.push(record);
+        let excess = self.history.len().saturating_sub(MAX_CATCH_HISTORY);
+        self.history.drain(..excess).collect()
+    }
+
+    /// Distinct known species landed, counting history from before the
+    /// fishdex was kept
+    pub fn fishdex_count(&self) -> usize {
+        let mut species: HashSet<&str> = self.fishdex.iter().map(String::as_str).collect();
+        species.extend(self.history.iter().map(|r| r.species.as_str()));
+        species
+            .into_iter()
+            .filter(|id| crate::fish::find_species(id).is_some())
+            .count()
+    }
+
+    /// The title shown next to this user's name, if any
+    pub fn title(&self) -> Option<&'static crate::titles::Title> {
+        self.equipped_title
+            .as_deref()
+            .and_then(crate::titles::find_title)
+    }
+
+    /// Whether a declared vacation covers `date` (YYYY-MM-DD)
+    pub fn on_vacation(&self, date: &str) -> bool {
+        self.vacation_until
+            .as_deref()
+            .is_some_and(|until| until >= date)
+    }
+
+    /// Whether this user may cast at the given location
+    pub fn has_unlocked(&self, location: &crate::fish::Location) -> bool {
+        location.is_free()
+            || self.total_catches >= location.unlock_catches
+            || self.unlocked_locations.iter().any(|id| id == location.id)
+    }
+}
+
+/// A channel designated as a fixed fishing spot via `/fishsetup biome:`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BiomeChannel {
+    pub location: String,
+    pub button_message_id: Option<String>,
+}
+
+/// A bot message the janitor should delete once `delete_at` (ms) passes
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingDeletion {
+    pub channel_id: String,
+    pub message_id: String,
+    pub delete_at: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MerchantOffer {
+    pub item: String,
+    pub price: u64,
+    pub stock: u64,
+}
+
+/// A merchant visit, persisted so a restart neither duplicates nor loses it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MerchantVisit {
+    pub channel_id: String,
+    pub message_id: Option<String>,
+    pub leaves_at: u64,
+    pub offers: Vec<MerchantOffer>,
+}
+
+/// Someone who boarded the boat
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrewMember {
+    pub user_id: String,
+    pub username: String,
+}
+
+/// A boat trip taking on crew, persisted so a restart can still sail it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BoatTrip {
+    pub channel_id: String,
+    pub message_id: Option<String>,
+    pub departs_at: u64,
+    pub crew: Vec<CrewMember>,
+}
+
+/// Daily totals kept for charts and recaps. A backstop: compaction normally
+/// archives old days well before this.
+pub const MAX_DAILY_HISTORY: usize = 400;
+
+/// Name on embeds unless a server renames its pond
+pub const DEFAULT_POND_NAME: &str = "Stardust Pond";
+/// Blue of everyday embeds unless a server picks its own
+pub const DEFAULT_EMBED_COLOR: u32 = 0x0099FF;
+/// Gold of summaries and records unless a server picks its own
+pub const DEFAULT_HIGHLIGHT_COLOR: u32 = 0xFFD700;
+
+/// A server's look, from [`GuildData::branding`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Branding {
+    pub pond_name: String,
+    pub color: u32,
+    pub highlight: u32,
+}
+
+/// Server-wide catches for one finished day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyTotal {
+    pub date: String,
+    pub catches: u64,
+    /// IDs of everyone who fished that day (empty for days recorded before this was kept)
+    #[serde(default)]
+    pub participants: Vec<String>,
+}
+
+/// A fish surfaced in the pond channel, waiting for the first click
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WildFish {
+    /// Unique per spawn, so stale buttons can't claim a newer fish
+    pub id: String,
+    pub channel_id: String,
+    pub message_id: Option<String>,
+    pub species: String,
+    pub size_cm: u32,
+    pub expires_at: u64,
+}
+
+/// A role handed out once a member's streak reaches `streak` days
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreakRole {
+    pub streak: u64,
+    pub role_id: String,
+}
+
+/// What a summary channel does with the members who haven't fished
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ReminderMode {
+    /// Mention them, pinging each
+    Ping,
+    /// Mention them without pinging, e.g. for a staff channel
+    List,
+    /// Leave them out
+    Off,
+}
+
+impl ReminderMode {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ReminderMode::Ping => "pings",
+            ReminderMode::List => "list without pings",
+            ReminderMode::Off => "no reminders",
+        }
+    }
+}
+
+/// A channel the daily summary goes to besides the main one
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SummaryChannel {
+    pub channel_id: String,
+    pub reminder: ReminderMode,
+}
+
+/// A member kept from fishing with `/fishban`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FishBan {
+    pub reason: Option<String>,
+    /// Admin who set the ban
+    pub banned_by: String,
+    /// Epoch millis
+    pub banned_at: u64,
+    /// Epoch millis the ban lifts at; `None` lasts until `/fishunban`
+    pub until: Option<u64>,
+}
+
+impl FishBan {
+    pub fn is_active(&self, now_millis: u64) -> bool {
+        self.until.is_none_or(|until| until > now_millis)
+    }
+}
+
+/// What server boosters get while the perk is switched on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BoosterPerk {
+    /// Slightly better odds on every cast
+    ExtraLuck,
+    /// A second cast each day
+    ExtraCast,
+    /// Catches show as crystal variants; purely cosmetic
+    CrystalFish,
+}
+
+impl BoosterPerk {
+    pub fn label(&self) -> &'static str {
+        match self {
+            BoosterPerk::ExtraLuck => "💎 Extra Luck",
+            BoosterPerk::ExtraCast => "💎 Extra Daily Cast",
+            BoosterPerk::CrystalFish => "💎 Crystal Fish",
+        }
+    }
+}
+
+/// Texts a server can reword with `/setmessage`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MessageKey {
+    /// Heads the summary's pings of members who haven't fished
+    Reminder,
+    /// The summary embed's description
+    Summary,
+    /// The note at the bottom of the summary
+    MissYou,
+}
+
+impl MessageKey {
+    pub const ALL: [MessageKey; 3] = [
+        MessageKey::Reminder,
+        MessageKey::Summary,
+        MessageKey::MissYou,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            MessageKey::Reminder => "reminder",
+            MessageKey::Summary => "summary",
+            MessageKey::MissYou => "\"We miss you\" note",
+        }
+    }
+
+    /// The text used until a server sets its own
+    pub fn default_text(&self, locale: Locale) -> &'static str {
+        match (locale, self) {
+            (Locale::En, MessageKey::Reminder) => "**Wake up! You haven't fished in a while!** 🎣",
+            (Locale::En, MessageKey::Summary) => "Here is how the pond is doing today!",
+            (Locale::En, MessageKey::MissYou) => "We miss you ❤️ \nPlease remember to fish daily 🙏🏻 Many lovely cats, cosmic dolphins and diamond rewards await us all 💎✨",
+            (Locale::Es, MessageKey::Reminder) => "**¡Despertad! ¡Hace tiempo que no pescáis!** 🎣",
+            (Locale::Es, MessageKey::Summary) => "¡Así va el estanque hoy!",
+            (Locale::Es, MessageKey::MissYou) => "Os echamos de menos ❤️ \nAcordaos de pescar cada día 🙏🏻 Muchos gatitos, delfines cósmicos y premios de diamante nos esperan 💎✨",
+        }
+    }
+}
+
+/// Language of a server's catch embeds, reminders and summaries
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Locale {
+    #[default]
+    En,
+    Es,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::En, Locale::Es];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Locale::En => "English",
+            Locale::Es => "Español",
+        }
+    }
+}
+
+/// `template` with every `{name}` in `values` replaced by its value; unknown
+/// placeholders are left as they are
+pub fn fill_template(template: &str, values: &[(&str, &str)]) -> String {
+    values
+        .iter()
+        .fold(template.to_string(), |text, (name, value)| {
+            text.replace(&format!("{{{}}}", name), value)
+        })
+}
+
+/// How much of a player `/resetuser` wipes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum UserReset {
+    /// Today's cast and fortune, so they can fish again
+    Today,
+    /// Streak, catch total, and catch history; coins and items stay
+    Stats,
+    /// Everything, as if they had never played
+    Everything,
+}
+
+impl UserReset {
+    pub fn label(&self) -> &'static str {
+        match self {
+            UserReset::Today => "today's cast",
+            UserReset::Stats => "stats",
+            UserReset::Everything => "data",
+        }
+    }
+}
+
+/// An all-time record held by one player
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerRecord {
+    pub user_id: String,
+    pub username: String,
+    pub value: u64,
+    /// Species ID, for fish records
+    #[serde(default)]
+    pub species: Option<String>,
+    pub date: String,
+}
+
+/// A record the whole server set on one day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DayRecord {
+    pub value: u64,
+    pub date: String,
+}
+
+/// All-time server records
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HallOfFame {
+    pub longest_streak: Option<PlayerRecord>,
+    /// Largest single fish, in cm
+    pub biggest_fish: Option<PlayerRecord>,
+    pub most_catches_in_day: Option<DayRecord>,
+}
+
+impl HallOfFame {
+    /// Fold a catch into the records, returning the names of any records it broke
+    pub fn record_catch(
+        &mut self,
+        user_id: &str,
+        username: &str,
+        streak: u64,
+        catch: &CatchRecord,
+        daily_count: u64,
+        date: &str,
+    ) -> Vec<&'static str> {
+        let mut broken = Vec::new();
+        let player_record = |value: u64, species: Option<String>| PlayerRecord {
+            user_id: user_id.to_string(),
+            username: username.to_string(),
+            value,
+            species,
+            date: date.to_string(),
+        };
+
+        if self
+            .longest_streak
+            .as_ref()
+            .is_none_or(|r| streak > r.value)
+        {
+            // Extending your own record day by day isn't news
+            if self
+                .longest_streak
+                .as_ref()
+                .is_none_or(|r| r.user_id != user_id)
+            {
+                broken.push("Longest Streak");
+            }
+            self.longest_streak = Some(player_record(streak, None));
+        }
+        if self
+            .biggest_fish
+            .as_ref()
+            .is_none_or(|r| catch.size_cm as u64 > r.value)
+        {
+            broken.push("Biggest Fish");
+            self.biggest_fish = Some(player_record(
+                catch.size_cm as u64,
+                Some(catch.species.clone()),
+            ));
+        }
+        if self
+            .most_catches_in_day
+            .as_ref()
+            .is_none_or(|r| daily_count > r.value)
+        {
+            if self
+                .most_catches_in_day
+                .as_ref()
+                .is_none_or(|r| r.date != date)
+            {
+                broken.push("Most Catches in a Day");
+            }
+            self.most_catches_in_day = Some(DayRecord {
+                value: daily_count,
+                date: date.to_string(),
+            });
+        }
+        broken
+    }
+}
+
+/// A server team competing for monthly points
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Team {
+    pub name: String,
+    pub created_by: String,
+    /// Points this month
+    pub points: u64,
+    /// Points since the last daily reset
+    pub points_today: u64,
+}
+
+/// The team that finished a month on top
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamWinner {
+    /// Day the month was closed (its last day)
+    pub date: String,
+    /// "YYYY-MM" of the month that was won
+    pub month: String,
+    pub team: String,
+    pub points: u64,
+}
+
+/// Result of the nightly lottery drawing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LotteryDraw {
+    pub date: String,
+    pub winner_id: String,
+    pub winner_name: String,
+    pub jackpot: u64,
+    pub tickets: u64,
+}
+
+/// The event currently affecting the pond
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivePondEvent {
+    pub kind: crate::fish::PondEventKind,
+    pub ends_at: u64,
+}
+
+/// One server's game state
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GuildData {
+    #[serde(default)]
+    pub daily_count: u64,
+
+    #[serde(default = "default_timestamp")]
+    pub last_reset_timestamp: u64,
+
+    /// Time of day, in [`timezone`](Self::timezone), this server's fishing
+    /// day ends; `None` means the default
+    /// [`RESET_HOUR`](crate::game::RESET_HOUR):[`RESET_MINUTE`](crate::game::RESET_MINUTE)
+    #[serde(default)]
+    pub reset_time: Option<chrono::NaiveTime>,
+
+    /// Time of day, in [`timezone`](Self::timezone), the daily summary posts;
+    /// `None` posts it just before the reset
+    #[serde(default)]
+    pub summary_time: Option<chrono::NaiveTime>,
+
+    /// When the daily summary last posted on its own schedule (ms)
+    #[serde(default)]
+    pub last_summary_timestamp: u64,
+
+    /// IANA timezone whose calendar days streaks, records, and daily totals
+    /// follow; `None` for UTC
+    #[serde(default)]
+    pub timezone: Option<String>,
+
+    /// Day (server time) `/pausefishing` froze the pond; `None` while open.
+    /// No casts, resets, or summaries happen while it's set.
+    #[serde(default)]
+    pub paused_since: Option<String>,
+
+    #[serde(default)]
+    pub users: HashMap<String, UserData>,
+
+    /// Today's fortunes keyed by user ID, cleared with `users` at the reset
+    #[serde(default)]
+    pub fortunes: HashMap<String, DailyFortune>,
+
+    #[serde(default)]
+    pub persistent_users: HashMap<String, PersistentUserData>,
+
+    /// Referrals keyed by the referred member's ID (one referrer each)
+    #[serde(default)]
+    pub referrals: HashMap<String, Referral>,
+
+    /// `/remindme` DMs keyed by user ID
+    #[serde(default)]
+    pub dm_reminders: HashMap<String, DmReminder>,
+
+    pub button_message_id: Option<String>,
+    pub button_channel_id: Option<String>,
+
+    /// Biome buttons keyed by channel ID
+    #[serde(default)]
+    pub biome_channels: HashMap<String, BiomeChannel>,
+
+    /// Roles whose members the summary and reminders keep track of
+    #[serde(default)]
+    pub tracked_role_ids: Vec<String>,
+
+    /// Members the daily reminder never pings (bots, alts, staff)
+    #[serde(default)]
+    pub reminder_exclusions: HashSet<String>,
+
+    /// Members who turned reminder pings off for themselves with `/fishreminders`
+    #[serde(default)]
+    pub reminder_opt_outs: HashSet<String>,
+
+    /// Members who snoozed reminders from the summary's button, with the
+    /// last day (YYYY-MM-DD) the snooze lasts
+    #[serde(default)]
+    pub reminder_snoozes: HashMap<String, String>,
+
+    /// Take the tracked roles off members who stopped fishing long ago, at
+    /// each reset
+    #[serde(default)]
+    pub auto_untrack_inactive: bool,
+
+    /// Channels `/fish` works in; empty means anywhere
+    #[serde(default)]
+    pub fish_channels: HashSet<String>,
+
+    /// Members banned from fishing, by user ID; expired bans linger until pruned
+    #[serde(default)]
+    pub fish_bans: HashMap<String, FishBan>,
+
+    /// Days nobody's streak breaks: "YYYY-MM-DD" once, or "MM-DD" every year
+    #[serde(default)]
+    pub blackout_dates: BTreeSet<String>,
+
+    /// Days of the week the summary doesn't remind anyone, Monday first
+    #[serde(default)]
+    pub quiet_weekdays: Vec<chrono::Weekday>,
+
+    /// Streak role rewards, lowest threshold first
+    #[serde(default)]
+    pub streak_roles: Vec<StreakRole>,
+
+    /// Main summary channel; also gets the weekly summary and notices
+    pub summary_channel_id: Option<String>,
+
+    /// Further daily summary channels, each with its own reminder setting
+    #[serde(default)]
+    pub extra_summary_channels: Vec<SummaryChannel>,
+
+    /// Whether the main summary channel pings members who haven't fished
+    #[serde(default = "default_true")]
+    pub ping_reminder_enabled: bool,
+
+    /// Admins who get the digest by DM after each reset
+    #[serde(default)]
+    pub admin_digest_ids: BTreeSet<String>,
+
+    /// Texts reworded with `/setmessage`; the rest use their default
+    #[serde(default)]
+    pub messages: HashMap<MessageKey, String>,
+
+    /// Language set with `/setlocale`
+    #[serde(default)]
+    pub locale: Locale,
+
+    /// Make players pass a quick challenge before a cast counts
+    #[serde(default)]
+    pub verification_enabled: bool,
+
+    #[serde(default = "default_streak")]
+    pub best_angler_streak: u64,
+
+    /// Days without fishing before a tracked member is pinged, unless one
+    /// of their roles has its own in `role_reminder_thresholds`
+    #[serde(default = "default_threshold")]
+    pub reminder_threshold: u64,
+
+    /// Per-role overrides of `reminder_threshold`, by role ID
+    #[serde(default)]
+    pub role_reminder_thresholds: HashMap<String, u64>,
+
+    /// Twitch login / YouTube channel whose go-live starts a stream frenzy
+    pub streamer_name: Option<String>,
+
+    #[serde(default = "default_frenzy_minutes")]
+    pub stream_frenzy_minutes: u64,
+
+    pub stream_frenzy_until: Option<u64>,
+
+    pub active_event: Option<ActivePondEvent>,
+
+    pub merchant: Option<MerchantVisit>,
+
+    pub boat: Option<BoatTrip>,
+
+    pub wild_fish: Option<WildFish>,
+
+    #[serde(default)]
+    pub audit_log: Vec<crate::audit::AuditEntry>,
+
+    #[serde(default)]
+    pub hall_of_fame: HallOfFame,
+
+    /// Catches per finished day, oldest first (capped at `MAX_DAILY_HISTORY`)
+    #[serde(default)]
+    pub daily_history: Vec<DailyTotal>,
+
+    /// Perk for server boosters (`None` = switched off)
+    #[serde(default)]
+    pub booster_perk: Option<BoosterPerk>,
+
+    /// Name shown on embeds in place of [`DEFAULT_POND_NAME`]
+    #[serde(default)]
+    pub pond_name: Option<String>,
+
+    /// Color of everyday embeds in place of [`DEFAULT_EMBED_COLOR`]
+    #[serde(default)]
+    pub embed_color: Option<u32>,
+
+    /// Color of summaries and records in place of [`DEFAULT_HIGHLIGHT_COLOR`]
+    #[serde(default)]
+    pub highlight_color: Option<u32>,
+
+    /// Species ID featured until the next reset
+    #[serde(default)]
+    pub fish_of_the_day: Option<String>,
+
+    /// Teams keyed by ID (the lowercased name)
+    #[serde(default)]
+    pub teams: HashMap<String, Team>,
+
+    pub last_team_winner: Option<TeamWinner>,
+
+    /// Today's lottery tickets per user ID
+    #[serde(default)]
+    pub lottery_tickets: HashMap<String, u64>,
+
+    pub last_lottery: Option<LotteryDraw>,
+
+    /// Lifetime server-wide catches per species ID
+    #[serde(default)]
+    pub species_caught: HashMap<String, u64>,
+
+    /// Lifetime server-wide shiny catches per species ID
+    #[serde(default)]
+    pub shiny_caught: HashMap<String, u64>,
+
+    /// Legendary celebration size (0 = off, up to 3)
+    #[serde(default)]
+    pub fireworks_intensity: u8,
+
+    #[serde(default)]
+    pub pending_deletions: Vec<PendingDeletion>,
+}
+
+impl GuildData {
+    /// Role IDs rewarded for a streak of `streak` days
+    pub fn streak_roles_for(&self, streak: u64) -> Vec<String> {
+        self.streak_roles
+            .iter()
+            .filter(|r| r.streak <= streak)
+            .map(|r| r.role_id.clone())
+            .collect()
+    }
+
+    /// The timezone this server's days follow
+    pub fn tz(&self) -> chrono_tz::Tz {
+        self.timezone
+            .as_deref()
+            .and_then(|name| name.parse().ok())
+            .unwrap_or(chrono_tz::Tz::UTC)
+    }
+
+    /// The clock `user_id` lives on: their own timezone if they set one,
+    /// else the server's
+    pub fn user_tz(&self, user_id: &str) -> chrono_tz::Tz {
+        self.persistent_users
+            .get(user_id)
+            .and_then(|p| p.timezone.as_deref())
+            .and_then(|name| name.parse().ok())
+            .unwrap_or_else(|| self.tz())
+    }
+
+    /// The current time on this server's clock
+    pub fn local_now(&self) -> chrono::DateTime<chrono_tz::Tz> {
+        chrono::Utc::now().with_timezone(&self.tz())
+    }
+
+    /// The date (YYYY-MM-DD) on this server's calendar at `timestamp` (ms)
+    pub fn date_of(&self, timestamp: u64) -> String {
+        chrono::DateTime::from_timestamp_millis(timestamp as i64)
+            .unwrap_or_default()
+            .with_timezone(&self.tz())
+            .format("%Y-%m-%d")
+            .to_string()
+    }
+
+    /// Today's date on this server's calendar
+    pub fn today(&self) -> String {
+        self.local_now().format("%Y-%m-%d").to_string()
+    }
+
+    /// Yesterday's date on this server's calendar
+    pub fn yesterday(&self) -> String {
+        (self.local_now().date_naive() - chrono::Duration::days(1))
+            .format("%Y-%m-%d")
+            .to_string()
+    }
+
+    /// When this server's daily reset runs, on its own clock
+    pub fn reset_at(&self) -> chrono::NaiveTime {
+        self.reset_time.unwrap_or_else(|| {
+            chrono::NaiveTime::from_hms_opt(crate::game::RESET_HOUR, crate::game::RESET_MINUTE, 0)
+                .expect("default reset time is valid")
+        })
+    }
+
+    /// The text this server uses for `key`, custom or default
+    pub fn message(&self, key: MessageKey) -> &str {
+        self.messages
+            .get(&key)
+            .map_or(key.default_text(self.locale), String::as_str)
+    }
+
+    /// Every channel the daily summary goes to, the main one first
+    pub fn summary_channels(&self) -> Vec<SummaryChannel> {
+        let main = self.summary_channel_id.iter().map(|id| SummaryChannel {
+            channel_id: id.clone(),
+            reminder: if self.ping_reminder_enabled {
+                ReminderMode::Ping
+            } else {
+                ReminderMode::Off
+            },
+        });
+        main.chain(
+            self.extra_summary_channels
+                .iter()
+                .filter(|extra| self.summary_channel_id.as_ref() != Some(&extra.channel_id))
+                .cloned(),
+        )
+        .collect()
+    }
+
+    /// Whether `/fish` may be used in `channel_id`
+    pub fn fish_allowed_in(&self, channel_id: &str) -> bool {
+        self.fish_channels.is_empty() || self.fish_channels.contains(channel_id)
+    }
+
+    /// Whether `date` (YYYY-MM-DD) is a blackout day set with `/blackout`
+    pub fn is_blackout(&self, date: &str) -> bool {
+        self.blackout_dates.contains(date)
+            || date
+                .get(5..)
+                .is_some_and(|month_day| self.blackout_dates.contains(month_day))
+    }
+
+    /// Whether `date` (YYYY-MM-DD) falls on a weekday set quiet with `/quietdays`
+    pub fn is_quiet_weekday(&self, date: &str) -> bool {
+        use chrono::Datelike;
+        chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .is_ok_and(|day| self.quiet_weekdays.contains(&day.weekday()))
+    }
+
+    /// `user_id`'s ban from fishing, if one is in force at `now_millis`
+    pub fn fish_ban(&self, user_id: &str, now_millis: u64) -> Option<&FishBan> {
+        self.fish_bans
+            .get(user_id)
+            .filter(|ban| ban.is_active(now_millis))
+    }
+
+    /// The name and colors this server's embeds use
+    pub fn branding(&self) -> Branding {
+        Branding {
+            pond_name: self
+                .pond_name
+                .clone()
+                .unwrap_or_else(|| DEFAULT_POND_NAME.to_string()),
+            color: self.embed_color.unwrap_or(DEFAULT_EMBED_COLOR),
+            highlight: self.highlight_color.unwrap_or(DEFAULT_HIGHLIGHT_COLOR),
+        }
+    }
+
+    /// Today's featured species, if one has been picked
+    pub fn featured_species(&self) -> Option<&'static crate::fish::Species> {
+        self.fish_of_the_day
+            .as_deref()
+            .and_then(crate::fish::find_species)
+    }
+
+    /// Erase everything kept about `user_id`: today's cast and fortune, the
+    /// persistent record with its history and inventory, lottery tickets,
+    /// reminder settings, referrals either way, records they hold, boat seats, daily
+    /// participation, and audit entries naming them. Returns whether
+    /// anything was found.
+    pub fn forget_user(&mut self, user_id: &str) -> bool {
+        let mut found = self.users.remove(user_id).is_some();
+        found |= self.fortunes.remove(user_id).is_some();
+        found |= self.persistent_users.remove(user_id).is_some();
+        found |= self.lottery_tickets.remove(user_id).is_some();
+        found |= self.reminder_exclusions.remove(user_id);
+        found |= self.reminder_opt_outs.remove(user_id);
+        found |= self.reminder_snoozes.remove(user_id).is_some();
+        found |= self.dm_reminders.remove(user_id).is_some();
+        found |= self.admin_digest_ids.remove(user_id);
+        for p_user in self.persistent_users.values_mut() {
+            found |= p_user.duel_wins.remove(user_id).is_some();
+        }
+
+        let referrals = self.referrals.len();
+        self.referrals
+            .retain(|referred, r| referred != user_id && r.referrer_id != user_id);
+        found |= self.referrals.len() != referrals;
+
+        for record in [
+            &mut self.hall_of_fame.longest_streak,
+            &mut self.hall_of_fame.biggest_fish,
+        ] {
+            if record.as_ref().is_some_and(|r| r.user_id == user_id) {
+                *record = None;
+                found = true;
+            }
+        }
+        if self
+            .last_lottery
+            .as_ref()
+            .is_some_and(|draw| draw.winner_id == user_id)
+        {
+            self.last_lottery = None;
+            found = true;
+        }
+        if let Some(boat) = &mut self.boat {
+            let crew = boat.crew.len();
+            boat.crew.retain(|m| m.user_id != user_id);
+            found |= boat.crew.len() != crew;
+        }
+        for day in &mut self.daily_history {
+            let participants = day.participants.len();
+            day.participants.retain(|id| id != user_id);
+            found |= day.participants.len() != participants;
+        }
+
+        let entries = self.audit_log.len();
+        self.audit_log
+            .retain(|e| e.actor_id != user_id && e.action.subject() != Some(user_id));
+        found |= self.audit_log.len() != entries;
+
+        found
+    }
+
+    /// Wipe `scope` of what's kept about `user_id`. Returns whether there
+    /// was anything to wipe.
+    pub fn reset_user(&mut self, user_id: &str, scope: UserReset) -> bool {
+        match scope {
+            UserReset::Today => {
+                let found = self.users.remove(user_id).is_some();
+                self.fortunes.remove(user_id).is_some() || found
+            }
+            UserReset::Stats => {
+                let Some(p_user) = self.persistent_users.get_mut(user_id) else {
+                    return false;
+                };
+                p_user.streak = 0;
+                p_user.best_streak = 0;
+                p_user.total_catches = 0;
+                p_user.last_fished_date.clear();
+                p_user.history.clear();
+                p_user.repaired_dates.clear();
+                p_user.vacation_until = None;
+                for record in [
+                    &mut self.hall_of_fame.longest_streak,
+                    &mut self.hall_of_fame.biggest_fish,
+                ] {
+                    if record.as_ref().is_some_and(|r| r.user_id == user_id) {
+                        *record = None;
+                    }
+                }
+                true
+            }
+            UserReset::Everything => self.forget_user(user_id),
+        }
+    }
+
+    /// Fold `old_id`'s record into `new_id`'s (e.g. after an account
+    /// switch) and erase `old_id`. Totals, coins, and items are added up,
+    /// histories and unlocks combined, and the streak of whoever fished
+    /// last is kept. Returns false if `old_id` has no record to merge.
+    pub fn merge_users(&mut self, old_id: &str, new_id: &str) -> bool {
+        if old_id == new_id {
+            return false;
+        }
+        let Some(old) = self.persistent_users.remove(old_id) else {
+            return false;
+        };
+        let new = self
+            .persistent_users
+            .entry(new_id.to_string())
+            .or_insert_with(|| PersistentUserData::new(old.username.clone()));
+
+        new.best_streak = new.longest_streak().max(old.longest_streak());
+        if old.last_fished_date > new.last_fished_date
+            || (old.last_fished_date == new.last_fished_date && old.streak > new.streak)
+        {
+            new.streak = old.streak;
+            new.last_fished_date = old.last_fished_date;
+        }
+        new.total_catches += old.total_catches;
+        new.coins += old.coins;
+        for (item, amount) in old.inventory {
+            *new.inventory.entry(item).or_insert(0) += amount;
+        }
+        for (opponent, wins) in old.duel_wins {
+            *new.duel_wins.entry(opponent).or_insert(0) += wins;
+        }
+        for (into, from) in [
+            (&mut new.unlocked_locations, old.unlocked_locations),
+            (&mut new.titles, old.titles),
+            (&mut new.fishdex, old.fishdex),
+            (&mut new.repaired_dates, old.repaired_dates),
+        ] {
+            for id in from {
+                if !into.contains(&id) {
+                    into.push(id);
+                }
+            }
+        }
+        new.history.extend(old.history);
+        new.history.sort_by_key(|record| record.at);
+        let excess = new.history.len().saturating_sub(MAX_CATCH_HISTORY);
+        new.history.drain(..excess);
+        new.vacation_until = new.vacation_until.take().max(old.vacation_until);
+        new.equipped_title = new.equipped_title.take().or(old.equipped_title);
+        new.team = new.team.take().or(old.team);
+        new.birthday = new.birthday.take().or(old.birthday);
+        new.timezone = new.timezone.take().or(old.timezone);
+        let username = new.username.clone();
+
+        // Today's cast carries over unless the new account already fished
+        if !self.users.contains_key(new_id) {
+            if let Some(user) = self.users.remove(old_id) {
+                self.users.insert(new_id.to_string(), user);
+            }
+        }
+        if !self.fortunes.contains_key(new_id) {
+            if let Some(fortune) = self.fortunes.remove(old_id) {
+                self.fortunes.insert(new_id.to_string(), fortune);
+            }
+        }
+        if !self.reminder_snoozes.contains_key(new_id) {
+            if let Some(until) = self.reminder_snoozes.remove(old_id) {
+                self.reminder_snoozes.insert(new_id.to_string(), until);
+            }
+        }
+        for set in [&mut self.reminder_exclusions, &mut self.reminder_opt_outs] {
+            if set.remove(old_id) {
+                set.insert(new_id.to_string());
+            }
+        }
+        // Duels between the two accounts don't count against anyone
+        for (user_id, p_user) in self.persistent_users.iter_mut() {
+            if let Some(wins) = p_user.duel_wins.remove(old_id) {
+                if user_id != new_id {
+                    *p_user.duel_wins.entry(new_id.to_string()).or_insert(0) += wins;
+                }
+            }
+        }
+        if let Some(new) = self.persistent_users.get_mut(new_id) {
+            new.duel_wins.remove(new_id);
+        }
+        if !self.dm_reminders.contains_key(new_id) {
+            if let Some(reminder) = self.dm_reminders.remove(old_id) {
+                self.dm_reminders.insert(new_id.to_string(), reminder);
+            }
+        }
+        if let Some(tickets) = self.lottery_tickets.remove(old_id) {
+            *self.lottery_tickets.entry(new_id.to_string()).or_insert(0) += tickets;
+        }
+        for record in [
+            &mut self.hall_of_fame.longest_streak,
+            &mut self.hall_of_fame.biggest_fish,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            if record.user_id == old_id {
+                record.user_id = new_id.to_string();
+                record.username = username.clone();
+            }
+        }
+
+        self.forget_user(old_id);
+        true
+    }
+
+    /// Bring stored names up to date from `names` (user ID -> current
+    /// display name): player records, today's catches, the boat crew, and
+    /// the hall of fame. Returns how many players were renamed.
+    pub fn rename_users(&mut self, names: &HashMap<String, String>) -> usize {
+        let mut renamed = 0;
+        for (user_id, p_user) in &mut self.persistent_users {
+            if let Some(name) = names.get(user_id).filter(|name| **name != p_user.username) {
+                p_user.username = name.clone();
+                renamed += 1;
+            }
+        }
+        for (user_id, user) in &mut self.users {
+            if let Some(name) = names.get(user_id) {
+                user.username = name.clone();
+            }
+        }
+        if let Some(boat) = &mut self.boat {
+            for member in &mut boat.crew {
+                if let Some(name) = names.get(&member.user_id) {
+                    member.username = name.clone();
+                }
+            }
+        }
+        for record in [
+            &mut self.hall_of_fame.longest_streak,
+            &mut self.hall_of_fame.biggest_fish,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            if let Some(name) = names.get(&record.user_id) {
+                record.username = name.clone();
+            }
+        }
+        renamed
+    }
+}
+
+fn default_timestamp() -> u64 {
+    chrono::Utc::now().timestamp_millis() as u64
+}
+
+fn default_true() -> bool {
+    true
+}
+fn default_streak() -> u64 {
+    5
+}
+fn default_threshold() -> u64 {
+    1
+}
+fn default_frenzy_minutes() -> u64 {
+    60
+}
+
+impl Default for GuildData {
+    fn default() -> Self {
+        Self {
+            daily_count: 0,
+            last_reset_timestamp: default_timestamp(),
+            reset_time: None,
+            summary_time: None,
+            last_summary_timestamp: 0,
+            timezone: None,
+            paused_since: None,
+            users: HashMap::new(),
+            fortunes: HashMap::new(),
+            persistent_users: HashMap::new(),
+            referrals: HashMap::new(),
+            dm_reminders: HashMap::new(),
+            button_message_id: None,
+            button_channel_id: None,
+            biome_channels: HashMap::new(),
+            tracked_role_ids: Vec::new(),
+            reminder_exclusions: HashSet::new(),
+            reminder_opt_outs: HashSet::new(),
+            reminder_snoozes: HashMap::new(),
+            auto_untrack_inactive: false,
+            fish_channels: HashSet::new(),
+            fish_bans: HashMap::new(),
+            blackout_dates: BTreeSet::new(),
+            quiet_weekdays: Vec::new(),
+            streak_roles: Vec::new(),
+            summary_channel_id: None,
+            extra_summary_channels: Vec::new(),
+            admin_digest_ids: BTreeSet::new(),
+            ping_reminder_enabled: true,
+            messages: HashMap::new(),
+            locale: Locale::default(),
+            verification_enabled: false,
+            best_angler_streak: 5,
+            reminder_threshold: 1,
+            role_reminder_thresholds: HashMap::new(),
+            streamer_name: None,
+            stream_frenzy_minutes: default_frenzy_minutes(),
+            stream_frenzy_until: None,
+            active_event: None,
+            merchant: None,
+            boat: None,
+            wild_fish: None,
+            audit_log: Vec::new(),
+            hall_of_fame: HallOfFame::default(),
+            daily_history: Vec::new(),
+            booster_perk: None,
+            pond_name: None,
+            embed_color: None,
+            highlight_color: None,
+            fish_of_the_day: None,
+            teams: HashMap::new(),
+            last_team_winner: None,
+            lottery_tickets: HashMap::new(),
+            last_lottery: None,
+            species_caught: HashMap::new(),
+            shiny_caught: HashMap::new(),
+            fireworks_intensity: 0,
+            pending_deletions: Vec::new(),
+        }
+    }
+}
+
+/// Everything the bot persists: one [`GuildData`] per server, keyed by guild ID
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FishingData {
+    /// Layout version this was saved with; see [`crate::migrations`]
+    pub schema_version: u32,
+    pub guilds: HashMap<String, GuildData>,
+}
+
+impl Default for FishingData {
+    fn default() -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            guilds: HashMap::new(),
+        }
+    }
+}
+
+/// Saved data is upgraded to the current layout before it's read
+impl<'de> Deserialize<'de> for FishingData {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Current {
+            guilds: HashMap<String, GuildData>,
+        }
+
+        let saved = serde_json::Value::deserialize(deserializer)?;
+        let current = migrations::migrate(saved)
+            .map_err(serde::de::Error::custom)
+            .and_then(|data| Current::deserialize(data).map_err(serde::de::Error::custom))?;
+        Ok(Self {
+            schema_version: SCHEMA_VERSION,
+            guilds: current.guilds,
+        })
+    }
+}
+
+/// Tries at writing a backup that reads back correctly
+const BACKUP_ATTEMPTS: usize = 3;
+
+/// Read a server's state from an uploaded save file: an export from
+/// [`DataManager::export_guild`], a full `fishing_data.json`, or a file from
+/// before multi-server support. Older layouts are upgraded on the way in.
+/// Files holding several servers must include `guild_id`.
+pub fn parse_guild_export(json: &[u8], guild_id: &str) -> Result<GuildData, StorageError> {
+    let mut data: FishingData = serde_json::from_slice(json)?;
+    if let Some(guild) = data.guilds.remove(guild_id) {
+        return Ok(guild);
+    }
+    if data.guilds.len() == 1 {
+        return Ok(data.guilds.into_values().next().expect("one server"));
+    }
+    Err(match data.guilds.len() {
+        0 => "the file has no server data".into(),
+        _ => "the file holds several servers and none of them is this one".into(),
+    })
+}
+
+/// How long a change may wait to be written once [`DataManager::run_saver`]
+/// is running, so a burst of catches costs one write instead of dozens
+pub const SAVE_DEBOUNCE: Duration = Duration::from_secs(3);
+
+/// How startup got past saved data that failed to load
+#[derive(Debug, Clone)]
+pub struct Recovery {
+    /// Why the saved data couldn't be read
+    pub error: String,
+    /// The backup loaded instead, or `None` if no backup could be read either
+    pub backup: Option<String>,
+}
+
+/// In-memory game state plus the storage it's persisted to
+pub struct DataManager {
+    pub data: RwLock<FishingData>,
+    storage: Box<dyn Storage>,
+    /// Staging instances load a snapshot but never write it back
+    read_only: bool,
+    /// Changes made since the last write
+    dirty: AtomicBool,
+    /// Set once the saver task runs; until then every save writes immediately
+    debounced: AtomicBool,
+    save_requested: Notify,
+    /// Held while state is written, so snapshots reach storage in the order
+    /// they were taken
+    writing: Mutex<()>,
+    journal: Option<crate::journal::Journal>,
+    recovery: Option<Recovery>,
+    /// When state last reached storage, in ms since the epoch (0 = not yet)
+    last_saved_at: AtomicU64,
+    /// When the last verified backup was taken, in ms since the epoch (0 = not yet)
+    last_backup_at: AtomicU64,
+    /// Off-host copy made alongside every backup
+    #[cfg(feature = "s3")]
+    remote_backup: Option<crate::s3::S3Backup>,
+}
+
+impl DataManager {
+    /// Load state from `storage`, starting fresh if there is none yet
+    pub async fn open(storage: impl Storage + 'static) -> Self {
+        Self::load(Box::new(storage), false).await
+    }
+
+    /// Load a data snapshot for a staging instance; saves and backups become no-ops
+    pub async fn open_read_only(storage: impl Storage + 'static) -> Self {
+        Self::load(Box::new(storage), true).await
+    }
+
+    async fn load(storage: Box<dyn Storage>, read_only: bool) -> Self {
+        let mut recovery = None;
+        let (data, read_only) = match storage.load().await {
+            Ok(Some(data)) => (data, read_only),
+            Ok(None) => {
+                tracing::info!("ℹ️ No existing data found, starting fresh");
+                (FishingData::default(), read_only)
+            }
+            Err(e) => {
+                tracing::error!("❌ Error loading data: {}", e);
+                // Staging never replaces the live file, so it doesn't recover either
+                let recovered = if read_only {
+                    None
+                } else {
+                    Self::recover(storage.as_ref()).await
+                };
+                recovery = Some(Recovery {
+                    error: e.to_string(),
+                    backup: recovered.as_ref().map(|(name, _)| name.clone()),
+                });
+                match recovered {
+                    Some((_, data)) => (data, read_only),
+                    // Saving over data we couldn't read (e.g. from a newer build) would lose it
+                    None => {
+                        tracing::error!("No backup could be loaded either, saving is disabled");
+                        (FishingData::default(), true)
+                    }
+                }
+            }
+        };
+
+        Self {
+            data: RwLock::new(data),
+            storage,
+            read_only,
+            dirty: AtomicBool::new(false),
+            debounced: AtomicBool::new(false),
+            save_requested: Notify::new(),
+            writing: Mutex::new(()),
+            journal: None,
+            recovery,
+            last_saved_at: AtomicU64::new(0),
+            last_backup_at: AtomicU64::new(0),
+            #[cfg(feature = "s3")]
+            remote_backup: None,
+        }
+    }
+
+    /// Load the newest backup that can still be read, keeping a copy of the
+    /// unreadable data first
+    async fn recover(storage: &dyn Storage) -> Option<(String, FishingData)> {
+        let backups = match storage.list_backups().await {
+            Ok(backups) => backups,
+            Err(e) => {
+                tracing::error!("❌ Error listing backups: {}", e);
+                return None;
+            }
+        };
+        for name in backups {
+            match storage.load_backup(&name).await {
+                Ok(data) => {
+                    if let Err(e) = storage.set_aside_unreadable().await {
+                        tracing::error!("❌ Error keeping the unreadable data: {}", e);
+                        return None;
+                    }
+                    // So the next start loads cleanly even if nothing changes before then
+                    if let Err(e) = storage.save(&data).await {
+                        tracing::error!("❌ Error saving recovered data: {}", e);
+                    }
+                    tracing::warn!("♻️ Recovered data from backup {}", name);
+                    return Some((name, data));
+                }
+                Err(e) => tracing::warn!("⚠️ Backup {} is unreadable too: {}", name, e),
+            }
+        }
+        None
+    }
+
+    /// What happened if the saved data failed to load at startup
+    pub fn recovery(&self) -> Option<&Recovery> {
+        self.recovery.as_ref()
+    }
+
+    /// When state last reached storage since startup, in ms since the epoch
+    pub fn last_saved_at(&self) -> Option<u64> {
+        Some(self.last_saved_at.load(Ordering::SeqCst)).filter(|at| *at > 0)
+    }
+
+    /// When the last verified backup was taken since startup, in ms since
+    /// the epoch
+    pub fn last_backup_at(&self) -> Option<u64> {
+        Some(self.last_backup_at.load(Ordering::SeqCst)).filter(|at| *at > 0)
+    }
+
+    /// Size of the saved state in bytes, if the storage can tell
+    pub async fn stored_size(&self) -> Option<u64> {
+        match self.storage.size().await {
+            Ok(size) => size,
+            Err(e) => {
+                tracing::error!("❌ Error reading the data size: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Append catches, resets, and settings changes to the journal at `path`
+    pub fn with_journal(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.journal = Some(crate::journal::Journal::new(path));
+        self
+    }
+
+    /// Add an event to the journal, if there is one
+    pub async fn journal(&self, event: crate::journal::JournalEvent) {
+        let Some(journal) = self.journal.as_ref().filter(|_| !self.read_only) else {
+            return;
+        };
+        if let Err(e) = journal.append(event).await {
+            tracing::error!("❌ Error writing journal: {}", e);
+        }
+    }
+
+    /// Journal the current values of the named settings (saved field names,
+    /// e.g. `trackedRoleId`) after an admin changes them
+    pub async fn journal_settings(&self, guild_id: &str, fields: &[&str]) {
+        if self.journal.is_none() {
+            return;
+        }
+        let settings = match serde_json::to_value(&*self.guild(guild_id).await) {
+            Ok(serde_json::Value::Object(guild)) => guild
+                .into_iter()
+                .filter(|(field, _)| fields.contains(&field.as_str()))
+                .collect(),
+            _ => return,
+        };
+        self.journal(crate::journal::JournalEvent::Config {
+            guild_id: guild_id.to_string(),
+            settings,
+        })
+        .await;
+    }
+
+    /// Also upload a copy of the state to object storage on every backup
+    #[cfg(feature = "s3")]
+    pub fn with_remote_backup(mut self, remote: crate::s3::S3Backup) -> Self {
+        self.remote_backup = Some(remote);
+        self
+    }
+
+    /// One server's state, created empty if the server is new
+    pub async fn guild(&self, guild_id: &str) -> RwLockReadGuard<'_, GuildData> {
+        {
+            let data = self.data.read().await;
+            if data.guilds.contains_key(guild_id) {
+                return RwLockReadGuard::map(data, |d| &d.guilds[guild_id]);
+            }
+        }
+        let mut data = self.data.write().await;
+        Self::ensure_guild(&mut data, guild_id);
+        RwLockReadGuard::map(data.downgrade(), |d| &d.guilds[guild_id])
+    }
+
+    /// Write access to one server's state, created empty if the server is new
+    pub async fn guild_mut(&self, guild_id: &str) -> RwLockMappedWriteGuard<'_, GuildData> {
+        let mut data = self.data.write().await;
+        Self::ensure_guild(&mut data, guild_id);
+        RwLockWriteGuard::map(data, |d| d.guilds.get_mut(guild_id).expect("just inserted"))
+    }
+
+    fn ensure_guild(data: &mut FishingData, guild_id: &str) {
+        if !data.guilds.contains_key(guild_id) {
+            let guild = data.guilds.remove(UNASSIGNED_GUILD).unwrap_or_else(|| {
+                tracing::info!("🏠 New server {}", guild_id);
+                GuildData::default()
+            });
+            data.guilds.insert(guild_id.to_string(), guild);
+        }
+    }
+
+    /// IDs of every server with saved state
+    pub async fn guild_ids(&self) -> Vec<String> {
+        self.data
+            .read()
+            .await
+            .guilds
+            .keys()
+            .filter(|id| id.as_str() != UNASSIGNED_GUILD)
+            .cloned()
+            .collect()
+    }
+
+    /// Persist the current state. Once [`run_saver`](Self::run_saver) is
+    /// running this only marks the state as changed and the write follows
+    /// within [`SAVE_DEBOUNCE`]; otherwise it writes right away.
+    pub async fn save(&self) {
+        if self.read_only {
+            tracing::debug!("Read-only mode: skipping save");
+            return;
+        }
+        self.dirty.store(true, Ordering::SeqCst);
+        if self.debounced.load(Ordering::SeqCst) {
+            self.save_requested.notify_one();
+        } else {
+            self.flush().await;
+        }
+    }
+
+    /// Write any pending changes now, e.g. before shutting down. The state
+    /// is copied under the lock and written after releasing it, so players
+    /// aren't kept waiting on the disk.
+    pub async fn flush(&self) {
+        if self.read_only {
+            return;
+        }
+        let _writing = self.writing.lock().await;
+        if !self.dirty.swap(false, Ordering::SeqCst) {
+            return;
+        }
+        let snapshot = self.data.read().await.clone();
+        match self.storage.save(&snapshot).await {
+            Ok(()) => self.mark_saved(),
+            Err(e) => {
+                // Try again with the next save
+                self.dirty.store(true, Ordering::SeqCst);
+                tracing::error!("❌ Error saving data: {}", e);
+            }
+        }
+    }
+
+    fn mark_saved(&self) {
+        self.last_saved_at.store(
+            chrono::Utc::now().timestamp_millis() as u64,
+            Ordering::SeqCst,
+        );
+    }
+
+    /// Batch saves in the background for as long as the bot runs
+    pub async fn run_saver(self: Arc<Self>) {
+        self.debounced.store(true, Ordering::SeqCst);
+        loop {
+            self.save_requested.notified().await;
+            tokio::time::sleep(SAVE_DEBOUNCE).await;
+            self.flush().await;
+        }
+    }
+
+    /// Apply `f` to a copy of the state and persist it, swapping it in only
+    /// once the save succeeds. On error the in-memory state is left untouched.
+    /// The write lock is held throughout, so no other change can interleave.
+    pub async fn update<R>(
+        &self,
+        f: impl FnOnce(&mut FishingData) -> R,
+    ) -> Result<R, StorageError> {
+        let _writing = self.writing.lock().await;
+        let mut data = self.data.write().await;
+        let mut next = data.clone();
+        let result = f(&mut next);
+        if !self.read_only {
+            self.storage.save(&next).await?;
+            // Everything pending was part of that write
+            self.dirty.store(false, Ordering::SeqCst);
+            self.mark_saved();
+        }
+        *data = next;
+        Ok(result)
+    }
+
+    /// Hand catches trimmed from a player's history to the storage archive
+    pub async fn archive_catches(&self, guild_id: &str, user_id: &str, records: &[CatchRecord]) {
+        if self.read_only || records.is_empty() {
+            return;
+        }
+        if let Err(e) = self
+            .storage
+            .archive_catches(guild_id, user_id, records)
+            .await
+        {
+            tracing::error!("❌ Error archiving catch history: {}", e);
+        }
+    }
+
+    /// Move catches and daily totals from before `cutoff` (ms) out of the live
+    /// data into a compressed archive in `dir`. The archive is written before
+    /// anything is removed, so a failed write loses nothing.
+    pub async fn compact(
+        &self,
+        cutoff: u64,
+        dir: &std::path::Path,
+    ) -> Result<crate::archive::Compaction, StorageError> {
+        use crate::archive::{ArchivedEntry, Compaction};
+
+        if self.read_only {
+            return Ok(Compaction::default());
+        }
+        let cutoff_date = crate::game::FishingManager::get_date_string(cutoff);
+
+        let mut compaction = Compaction::default();
+        let entries = {
+            let data = self.data.read().await;
+            let mut entries = Vec::new();
+            for (guild_id, guild) in &data.guilds {
+                for (user_id, p_user) in &guild.persistent_users {
+                    for record in p_user.history.iter().filter(|r| r.at < cutoff) {
+                        entries.push(ArchivedEntry::Catch {
+                            guild_id: guild_id.clone(),
+                            user_id: user_id.clone(),
+                            record: record.clone(),
+                        });
+                        compaction.catches += 1;
+                    }
+                }
+                for day in guild.daily_history.iter().filter(|d| d.date < cutoff_date) {
+                    entries.push(ArchivedEntry::Day {
+                        guild_id: guild_id.clone(),
+                        day: day.clone(),
+                    });
+                    compaction.days += 1;
+                }
+            }
+            entries
+        };
+        if entries.is_empty() {
+            return Ok(compaction);
+        }
+
+        compaction.file = Some(crate::archive::write(dir, &entries).await?);
+        self.update(|data| {
+            for guild in data.guilds.values_mut() {
+                for p_user in guild.persistent_users.values_mut() {
+                    p_user.history.retain(|r| r.at >= cutoff);
+                }
+                guild.daily_history.retain(|d| d.date >= cutoff_date);
+            }
+        })
+        .await?;
+        Ok(compaction)
+    }
+
+    /// Names of the saved backups, newest first
+    pub async fn list_backups(&self) -> Vec<String> {
+        match self.storage.list_backups().await {
+            Ok(backups) => backups,
+            Err(e) => {
+                tracing::error!("❌ Error listing backups: {}", e);
+                Vec::new()
+            }
+        }
+    }
+
+    /// Replace one server's state with its copy in backup `name`, leaving
+    /// every other server alone. A fresh backup is taken first so the
+    /// restore itself can be undone.
+    pub async fn restore_guild(&self, guild_id: &str, name: &str) -> Result<(), StorageError> {
+        if self.read_only {
+            return Err("data is read-only".into());
+        }
+        let mut backup = self.storage.load_backup(name).await?;
+        // Backups from before multi-server support hold one unassigned server
+        let restored = backup
+            .guilds
+            .remove(guild_id)
+            .or_else(|| backup.guilds.remove(UNASSIGNED_GUILD))
+            .ok_or("that backup has no data for this server")?;
+
+        self.replace_guild(guild_id, restored).await?;
+        tracing::info!("⏪ Restored server {} from backup {}", guild_id, name);
+        Ok(())
+    }
+
+    /// Swap one server's state for `guild`, backing up the current state first
+    pub async fn replace_guild(
+        &self,
+        guild_id: &str,
+        guild: GuildData,
+    ) -> Result<(), StorageError> {
+        if self.read_only {
+            return Err("data is read-only".into());
+        }
+        self.backup().await;
+        self.update(|data| {
+            data.guilds.insert(guild_id.to_string(), guild);
+        })
+        .await
+    }
+
+    /// One server's state as a save file of its own, which
+    /// [`parse_guild_export`] reads back
+    pub async fn export_guild(&self, guild_id: &str) -> Result<Vec<u8>, serde_json::Error> {
+        let guild = self.guild(guild_id).await.clone();
+        let export = FishingData {
+            guilds: HashMap::from([(guild_id.to_string(), guild)]),
+            ..FishingData::default()
+        };
+        serde_json::to_vec_pretty(&export)
+    }
+
+    /// Replace the in-memory state with what's saved, e.g. after fixing the
+    /// data file by hand. Changes not yet written are discarded.
+    pub async fn reload(&self) -> Result<(), StorageError> {
+        // Not halfway through a save
+        let _writing = self.writing.lock().await;
+        let loaded = self
+            .storage
+            .load()
+            .await?
+            .ok_or("there is no saved data to reload")?;
+        let mut data = self.data.write().await;
+        if self.dirty.swap(false, Ordering::SeqCst) {
+            tracing::warn!("⚠️ Discarding unsaved changes to reload the data");
+        }
+        *data = loaded;
+        tracing::info!("🔄 Reloaded data from storage");
+        Ok(())
+    }
+
+    /// Players per server, for checking a backup against
+    async fn player_counts(&self) -> HashMap<String, usize> {
+        self.data
+            .read()
+            .await
+            .guilds
+            .iter()
+            .map(|(guild_id, guild)| (guild_id.clone(), guild.persistent_users.len()))
+            .collect()
+    }
+
+    /// Read backup `name` back and make sure it holds every server and at
+    /// least as many players as `expected`. Storages that can't list their
+    /// backups aren't checked.
+    async fn verify_backup(
+        &self,
+        name: &str,
+        expected: &HashMap<String, usize>,
+    ) -> Result<(), StorageError> {
+        if !self.storage.list_backups().await?.iter().any(|b| b == name) {
+            return Ok(());
+        }
+        let backup = self.storage.load_backup(name).await?;
+        for (guild_id, players) in expected {
+            let found = backup
+                .guilds
+                .get(guild_id)
+                .map_or(0, |guild| guild.persistent_users.len());
+            if found < *players {
+                return Err(format!(
+                    "{} has {} players for server {} but {} were expected",
+                    name, found, guild_id, players
+                )
+                .into());
+            }
+        }
+        tracing::info!("💾 Backup {} verified", name);
+        Ok(())
+    }
+
+    /// Back up the saved state, reading each backup back to check it
+    pub async fn backup(&self) {
+        if self.read_only {
+            tracing::debug!("Read-only mode: skipping backup");
+            return;
+        }
+        // Failures are logged as they happen
+        let _ = self.backup_now().await;
+    }
+
+    /// Like [`backup`](Self::backup), but hands back the backup that was
+    /// taken (`None` if the storage had nothing to write) or why none was
+    pub async fn backup_now(&self) -> Result<Option<BackupInfo>, StorageError> {
+        if self.read_only {
+            return Err("data is read-only".into());
+        }
+        let mut outcome = Ok(None);
+        for attempt in 1..=BACKUP_ATTEMPTS {
+            let expected = self.player_counts().await;
+            // Back up what's in memory, not a file that's a few seconds behind
+            self.flush().await;
+            outcome = match self.storage.backup().await {
+                Ok(Some(info)) => match self.verify_backup(&info.name, &expected).await {
+                    Ok(()) => Ok(Some(info)),
+                    Err(e) => {
+                        // Never leave a bad backup where it could be restored
+                        if let Err(e) = self.storage.discard_backup(&info.name).await {
+                            tracing::error!("❌ Error discarding backup {}: {}", info.name, e);
+                        }
+                        Err(e)
+                    }
+                },
+                other => other,
+            };
+            match &outcome {
+                Ok(_) => {
+                    // Only a verified backup may push an old one out
+                    if let Err(e) = self.storage.prune_backups().await {
+                        tracing::error!("❌ Error pruning old backups: {}", e);
+                    }
+                    self.last_backup_at.store(
+                        chrono::Utc::now().timestamp_millis() as u64,
+                        Ordering::SeqCst,
+                    );
+                    break;
+                }
+                Err(e) if attempt < BACKUP_ATTEMPTS => tracing::error!(
+                    "❌ Backup attempt {} of {} failed, retrying: {}",
+                    attempt,
+                    BACKUP_ATTEMPTS,
+                    e
+                ),
+                Err(e) => tracing::error!(
+                    "🚨 BACKUP FAILED after {} attempts; no good backup was taken: {}",
+                    BACKUP_ATTEMPTS,
+                    e
+                ),
+            }
+        }
+
+        #[cfg(feature = "s3")]
+        if let Some(remote) = &self.remote_backup {
+            let name = format!(
+                "fishing_data_{}.json",
+                chrono::Utc::now().format("%Y-%m-%dT%H-%M-%S")
+            );
+            let snapshot = self.data.read().await.clone();
+            match serde_json::to_vec_pretty(&snapshot) {
+                Ok(body) => match remote.upload(&name, body).await {
+                    Ok(()) => tracing::info!("☁️ Uploaded remote backup {}", name),
+                    Err(e) => tracing::error!("❌ Error uploading remote backup: {}", e),
+                },
+                Err(e) => tracing::error!("❌ Error serializing remote backup: {}", e),
+            }
+        }
+        outcome
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn forget_user_drops_admin_digest_opt_in() {
+        let mut data = GuildData::default();
+        data.admin_digest_ids.insert("42".to_string());
+        data.admin_digest_ids.insert("7".to_string());
+
+        assert!(data.forget_user("42"));
+        assert_eq!(data.admin_digest_ids, BTreeSet::from(["7".to_string()]));
+        assert!(!data.forget_user("42"));
+    }
+}
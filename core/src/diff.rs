@@ -0,0 +1,51 @@
+//! JSON Merge Patches (RFC 7386) between saved states, so incremental
+//! backups only store what changed. A patch lists the changed fields of each
+//! object, with `null` for fields that were removed.
+
+use serde_json::{Map, Value};
+
+/// The patch turning `old` into `new`, or `None` if they're equal
+pub fn diff(old: &Value, new: &Value) -> Option<Value> {
+    let (Value::Object(old), Value::Object(new)) = (old, new) else {
+        return (old != new).then(|| new.clone());
+    };
+
+    let mut patch = Map::new();
+    for (key, new_value) in new {
+        match old.get(key) {
+            Some(old_value) => {
+                if let Some(change) = diff(old_value, new_value) {
+                    patch.insert(key.clone(), change);
+                }
+            }
+            None => {
+                patch.insert(key.clone(), new_value.clone());
+            }
+        }
+    }
+    for key in old.keys().filter(|key| !new.contains_key(*key)) {
+        patch.insert(key.clone(), Value::Null);
+    }
+    (!patch.is_empty()).then_some(Value::Object(patch))
+}
+
+/// Apply a patch made by [`diff`] to `target`
+pub fn apply(target: &mut Value, patch: &Value) {
+    let Value::Object(patch) = patch else {
+        *target = patch.clone();
+        return;
+    };
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+    let Value::Object(fields) = target else {
+        unreachable!("made an object above");
+    };
+    for (key, change) in patch {
+        if change.is_null() {
+            fields.remove(key);
+        } else {
+            apply(fields.entry(key.clone()).or_insert(Value::Null), change);
+        }
+    }
+}
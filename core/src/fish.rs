@@ -0,0 +1,352 @@
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Rarity {
+    Common,
+    Uncommon,
+    Rare,
+    Epic,
+    Legendary,
+}
+
+impl Rarity {
+    /// Relative roll weight of a single species of this rarity
+    pub fn weight(&self) -> u32 {
+        match self {
+            Rarity::Common => 60,
+            Rarity::Uncommon => 25,
+            Rarity::Rare => 10,
+            Rarity::Epic => 4,
+            Rarity::Legendary => 1,
+        }
+    }
+
+    /// Roll weight scaled by luck: each tier above Common gains proportionally more
+    pub fn weight_with_luck(&self, luck: f64) -> f64 {
+        let tier = *self as u8 as f64;
+        (self.weight() as f64 * (1.0 + (luck - 1.0) * tier)).max(0.01)
+    }
+
+    /// Coins awarded for landing a fish of this rarity
+    pub fn coins(&self) -> u64 {
+        match self {
+            Rarity::Common => 5,
+            Rarity::Uncommon => 10,
+            Rarity::Rare => 25,
+            Rarity::Epic => 60,
+            Rarity::Legendary => 150,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Rarity::Common => "Common",
+            Rarity::Uncommon => "Uncommon",
+            Rarity::Rare => "Rare",
+            Rarity::Epic => "Epic",
+            Rarity::Legendary => "Legendary",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Species {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub emoji: &'static str,
+    pub rarity: Rarity,
+    pub min_size_cm: u32,
+    pub max_size_cm: u32,
+}
+
+#[derive(Debug)]
+pub struct Location {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub emoji: &'static str,
+    pub description: &'static str,
+    /// Lifetime catches that unlock this spot for free (0 = always open)
+    pub unlock_catches: u64,
+    /// Coins that buy early access via `/unlock`
+    pub unlock_coins: u64,
+    pub pool: &'static [Species],
+}
+
+impl Location {
+    pub fn is_free(&self) -> bool {
+        self.unlock_catches == 0
+    }
+
+    /// Chance (0.0 to 1.0) that a cast here lands `species` at the given luck
+    pub fn catch_chance(&self, species: &Species, luck: f64) -> f64 {
+        let total: f64 = self
+            .pool
+            .iter()
+            .map(|s| s.rarity.weight_with_luck(luck))
+            .sum();
+        if self.pool.iter().any(|s| s.id == species.id) {
+            species.rarity.weight_with_luck(luck) / total
+        } else {
+            0.0
+        }
+    }
+
+    /// Roll a species and a size from this location's pool.
+    /// `luck` of 1.0 is normal odds; higher values favour rarer tiers.
+    pub fn roll(&self, luck: f64) -> (&'static Species, u32) {
+        let mut rng = rand::thread_rng();
+        let weights = self.pool.iter().map(|s| s.rarity.weight_with_luck(luck));
+        let dist = WeightedIndex::new(weights).expect("location pools are never empty");
+        let species = &self.pool[dist.sample(&mut rng)];
+        let size = rng.gen_range(species.min_size_cm..=species.max_size_cm);
+        (species, size)
+    }
+}
+
+pub static LOCATIONS: &[Location] = &[
+    Location {
+        id: "pond",
+        name: "Stardust Pond",
+        emoji: "🏞️",
+        description: "A calm pond, perfect for beginners",
+        unlock_catches: 0,
+        unlock_coins: 0,
+        pool: &[
+            Species {
+                id: "bluegill",
+                name: "Bluegill",
+                emoji: "🐟",
+                rarity: Rarity::Common,
+                min_size_cm: 10,
+                max_size_cm: 25,
+            },
+            Species {
+                id: "carp",
+                name: "Carp",
+                emoji: "🐟",
+                rarity: Rarity::Common,
+                min_size_cm: 30,
+                max_size_cm: 80,
+            },
+            Species {
+                id: "perch",
+                name: "Perch",
+                emoji: "🐠",
+                rarity: Rarity::Uncommon,
+                min_size_cm: 15,
+                max_size_cm: 40,
+            },
+            Species {
+                id: "koi",
+                name: "Koi",
+                emoji: "🎏",
+                rarity: Rarity::Rare,
+                min_size_cm: 30,
+                max_size_cm: 90,
+            },
+            Species {
+                id: "golden_koi",
+                name: "Golden Koi",
+                emoji: "🌟",
+                rarity: Rarity::Epic,
+                min_size_cm: 40,
+                max_size_cm: 100,
+            },
+            Species {
+                id: "stardust_koi",
+                name: "Stardust Koi",
+                emoji: "✨",
+                rarity: Rarity::Legendary,
+                min_size_cm: 60,
+                max_size_cm: 120,
+            },
+        ],
+    },
+    Location {
+        id: "river",
+        name: "Moonlit River",
+        emoji: "🌊",
+        description: "Fast water with feisty fish",
+        unlock_catches: 25,
+        unlock_coins: 150,
+        pool: &[
+            Species {
+                id: "trout",
+                name: "Trout",
+                emoji: "🐟",
+                rarity: Rarity::Common,
+                min_size_cm: 20,
+                max_size_cm: 60,
+            },
+            Species {
+                id: "catfish",
+                name: "Catfish",
+                emoji: "🐟",
+                rarity: Rarity::Common,
+                min_size_cm: 30,
+                max_size_cm: 120,
+            },
+            Species {
+                id: "salmon",
+                name: "Salmon",
+                emoji: "🍣",
+                rarity: Rarity::Uncommon,
+                min_size_cm: 50,
+                max_size_cm: 100,
+            },
+            Species {
+                id: "pike",
+                name: "Pike",
+                emoji: "🐊",
+                rarity: Rarity::Rare,
+                min_size_cm: 40,
+                max_size_cm: 130,
+            },
+            Species {
+                id: "sturgeon",
+                name: "Sturgeon",
+                emoji: "🦈",
+                rarity: Rarity::Epic,
+                min_size_cm: 100,
+                max_size_cm: 300,
+            },
+            Species {
+                id: "river_spirit_eel",
+                name: "River Spirit Eel",
+                emoji: "🐉",
+                rarity: Rarity::Legendary,
+                min_size_cm: 150,
+                max_size_cm: 400,
+            },
+        ],
+    },
+    Location {
+        id: "deep_sea",
+        name: "Deep Sea",
+        emoji: "🌌",
+        description: "Dark waters where cosmic creatures roam",
+        unlock_catches: 100,
+        unlock_coins: 600,
+        pool: &[
+            Species {
+                id: "mackerel",
+                name: "Mackerel",
+                emoji: "🐟",
+                rarity: Rarity::Common,
+                min_size_cm: 20,
+                max_size_cm: 50,
+            },
+            Species {
+                id: "tuna",
+                name: "Tuna",
+                emoji: "🐟",
+                rarity: Rarity::Uncommon,
+                min_size_cm: 100,
+                max_size_cm: 250,
+            },
+            Species {
+                id: "swordfish",
+                name: "Swordfish",
+                emoji: "🗡️",
+                rarity: Rarity::Rare,
+                min_size_cm: 150,
+                max_size_cm: 450,
+            },
+            Species {
+                id: "anglerfish",
+                name: "Anglerfish",
+                emoji: "🏮",
+                rarity: Rarity::Epic,
+                min_size_cm: 20,
+                max_size_cm: 100,
+            },
+            Species {
+                id: "cosmic_dolphin",
+                name: "Cosmic Dolphin",
+                emoji: "🐬",
+                rarity: Rarity::Legendary,
+                min_size_cm: 200,
+                max_size_cm: 400,
+            },
+        ],
+    },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PondEventKind {
+    FeedingFrenzy,
+    AlgaeBloom,
+    Storm,
+}
+
+impl PondEventKind {
+    pub const ALL: [PondEventKind; 3] = [
+        PondEventKind::FeedingFrenzy,
+        PondEventKind::AlgaeBloom,
+        PondEventKind::Storm,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            PondEventKind::FeedingFrenzy => "🍤 Feeding Frenzy",
+            PondEventKind::AlgaeBloom => "🦠 Algae Bloom",
+            PondEventKind::Storm => "⛈️ Storm",
+        }
+    }
+
+    /// Odds multiplier while the event is running
+    pub fn luck(&self) -> f64 {
+        match self {
+            PondEventKind::FeedingFrenzy => 1.5,
+            PondEventKind::AlgaeBloom => 0.7,
+            PondEventKind::Storm => 1.25,
+        }
+    }
+
+    pub fn announcement(&self) -> &'static str {
+        match self {
+            PondEventKind::FeedingFrenzy => {
+                "The fish are in a feeding frenzy! Rare catches are much more likely."
+            }
+            PondEventKind::AlgaeBloom => {
+                "An algae bloom is clouding the water. Rare fish are hiding for now."
+            }
+            PondEventKind::Storm => {
+                "A storm rolls in and stirs up the depths. Brave anglers may find something rare!"
+            }
+        }
+    }
+
+    pub fn ended_message(&self) -> &'static str {
+        match self {
+            PondEventKind::FeedingFrenzy => "The feeding frenzy has calmed down.",
+            PondEventKind::AlgaeBloom => "The algae bloom has cleared up.",
+            PondEventKind::Storm => "The storm has passed.",
+        }
+    }
+}
+
+pub fn find_location(id: &str) -> Option<&'static Location> {
+    LOCATIONS.iter().find(|l| l.id == id)
+}
+
+/// Look a species up by ID across every location's pool
+pub fn find_species(id: &str) -> Option<&'static Species> {
+    all_species().find(|species| species.id == id)
+}
+
+/// The location whose pool a species lives in
+pub fn species_location(id: &str) -> Option<&'static Location> {
+    LOCATIONS
+        .iter()
+        .find(|location| location.pool.iter().any(|species| species.id == id))
+}
+
+/// Every species, in location order
+pub fn all_species() -> impl Iterator<Item = &'static Species> {
+    LOCATIONS.iter().flat_map(|location| location.pool.iter())
+}
@@ -0,0 +1,33 @@
+use rand::Rng;
+
+/// Smallest and largest luck a fortune can grant for the day
+pub const LUCK_RANGE: (f64, f64) = (0.95, 1.10);
+
+/// Fortunes for days that lean lucky
+pub static BRIGHT: &[&str] = &[
+    "The water is warm and the fish are curious. Cast with confidence.",
+    "A shimmer beneath the surface is watching your line.",
+    "Stardust settles on your hook. Something rare is near.",
+    "The old frog on the lily pad nods at you approvingly.",
+    "Your knots are tight and your patience is deep.",
+    "The current bends toward you today.",
+];
+
+/// Fortunes for days that lean unlucky
+pub static GLOOMY: &[&str] = &[
+    "The fish are gossiping about you. Cast anyway.",
+    "A heron has claimed your favourite spot. Be patient.",
+    "Your bait looks a little tired. So do you.",
+    "Clouds gather over the pond, but clouds pass.",
+    "Something nibbled your line and left. It will be back.",
+];
+
+/// Draw a fortune: a luck multiplier in `LUCK_RANGE` (to the hundredth)
+/// and a line whose mood matches it
+pub fn draw() -> (&'static str, f64) {
+    let mut rng = rand::thread_rng();
+    let (low, high) = LUCK_RANGE;
+    let luck = (rng.gen_range(low..=high) * 100.0).round() / 100.0;
+    let pool = if luck >= 1.0 { BRIGHT } else { GLOOMY };
+    (pool[rng.gen_range(0..pool.len())], luck)
+}
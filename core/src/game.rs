@@ -0,0 +1,3471 @@
+use crate::audit::AuditAction;
+use crate::data::ActivePondEvent;
+use crate::data::{DataManager, GuildData, UserReset};
+use crate::data::{MerchantOffer, MerchantVisit};
+use crate::fish::PondEventKind;
+use crate::fish::{Location, Species};
+use crate::items::Item;
+use crate::journal::JournalEvent;
+use crate::titles::Title;
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Time of the daily reset in UTC, unless a server picks its own
+pub const RESET_HOUR: u32 = 14;
+pub const RESET_MINUTE: u32 = 30;
+
+/// Odds multiplier while a configured streamer is live
+pub const STREAM_FRENZY_LUCK: f64 = 1.5;
+
+/// Lottery jackpot before any tickets are sold
+pub const LOTTERY_BASE_JACKPOT: u64 = 100;
+/// Coins each ticket adds to the jackpot
+pub const LOTTERY_COINS_PER_TICKET: u64 = 10;
+
+/// Most teams a server can have at once
+pub const MAX_TEAMS: usize = 10;
+
+/// Coin and team point multiplier for landing the Fish of the Day
+pub const FISH_OF_THE_DAY_MULTIPLIER: u64 = 2;
+
+/// Every this-many streak days is a combo day with a bonus roll
+pub const COMBO_INTERVAL: u64 = 5;
+/// Coin multiplier on combo days
+pub const COMBO_COIN_MULTIPLIER: u64 = 2;
+
+/// Days the summary's snooze button mutes a member's reminders for
+pub const REMINDER_SNOOZE_DAYS: i64 = 3;
+
+/// Days without fishing before a tracked member counts as inactive
+pub const INACTIVE_DAYS: i64 = 30;
+
+/// Where boat trips fish
+pub const BOAT_LOCATION: &str = "deep_sea";
+/// Crew beyond this size adds no further bonus
+pub const BOAT_MAX_BONUS_CREW: u64 = 10;
+/// Extra luck per crew member after the first
+pub const BOAT_LUCK_PER_CREW: f64 = 0.1;
+/// Extra coins per crew member after the first, in percent of the base reward
+pub const BOAT_COIN_PERCENT_PER_CREW: u64 = 20;
+
+/// Coins gifted with the first catch on a player's birthday
+pub const BIRTHDAY_GIFT_COINS: u64 = 100;
+/// Item gifted alongside the coins
+pub const BIRTHDAY_GIFT_ITEM: &str = "golden_bait";
+
+/// Streak a referred member must reach before their referrer is paid
+pub const REFERRAL_STREAK: u64 = 7;
+/// Coins paid to the referrer
+pub const REFERRAL_BONUS_COINS: u64 = 200;
+
+/// Days shown in the summary's catch chart, including today
+pub const SUMMARY_CHART_DAYS: usize = 14;
+
+/// Places on the weekly leaderboard
+pub const WEEKLY_LEADERBOARD_SIZE: usize = 10;
+
+/// Longest absence `/vacation` can cover
+pub const MAX_VACATION_DAYS: u64 = 14;
+
+/// Odds multiplier for boosters under the extra luck perk
+pub const BOOSTER_LUCK: f64 = 1.2;
+
+/// Chance that any catch is a shiny variant
+pub const SHINY_CHANCE: f64 = 1.0 / 256.0;
+
+pub struct FishingManager {
+    data_manager: Arc<DataManager>,
+    is_resetting: Arc<AtomicBool>, // FIXED: Atomic for safer RAII drop
+    #[cfg(feature = "redis")]
+    cache: Option<crate::cache::RedisCache>,
+}
+
+/// Everything a catch embed needs to describe a successful cast
+#[derive(Debug, Clone)]
+pub struct CatchOutcome {
+    pub streak: u64,
+    pub total_catches: u64,
+    pub daily_count: u64,
+    pub location: &'static Location,
+    pub species: &'static Species,
+    pub size_cm: u32,
+    /// A rare recolour of the species, tracked separately in the fishpedia
+    pub shiny: bool,
+    /// The species was today's featured fish
+    pub fish_of_the_day: bool,
+    /// Landed on a streak milestone: best of two rolls and bonus coins
+    pub combo: bool,
+    /// The booster perk that applied to this cast
+    pub booster_perk: Option<crate::data::BoosterPerk>,
+    /// The server's pond name, for the catch embed
+    pub pond_name: String,
+    /// The server's language, for the catch embed
+    pub locale: crate::data::Locale,
+    /// First cast on the player's birthday: always shiny, plus a gift
+    pub birthday: bool,
+    pub coins_earned: u64,
+    pub coins: u64,
+    pub stream_frenzy: bool,
+    pub bait_used: Option<&'static Item>,
+    pub pond_event: Option<PondEventKind>,
+    /// The player's equipped title after this cast
+    pub title: Option<&'static Title>,
+    /// Titles earned by this cast
+    pub new_titles: Vec<&'static Title>,
+    /// Streak reward roles the player qualifies for
+    pub streak_roles: Vec<String>,
+    /// The player's lottery tickets for tonight's drawing
+    pub lottery_tickets: u64,
+    /// (team name, points earned) when the player is on a team
+    pub team_points: Option<(String, u64)>,
+    /// Hall of fame records this cast broke
+    pub new_records: Vec<&'static str>,
+    /// The member who referred this player, paid now that they hit the referral streak
+    pub referral_paid: Option<String>,
+}
+
+/// A streak the daily reset broke, with the reward roles it no longer earns
+#[derive(Debug, Clone)]
+pub struct BrokenStreak {
+    pub guild_id: String,
+    pub user_id: String,
+    pub streak: u64,
+    pub roles: Vec<String>,
+}
+
+/// A `/remindme` DM that came due for someone who hasn't fished yet
+#[derive(Debug, Clone)]
+pub struct DueReminder {
+    pub guild_id: String,
+    pub user_id: String,
+    pub streak: u64,
+    pub locale: crate::data::Locale,
+}
+
+/// Coins a player may give away per day
+pub const DAILY_GIFT_COIN_LIMIT: u64 = 250;
+/// Items a player may give away per day
+pub const DAILY_GIFT_ITEM_LIMIT: u64 = 3;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Gift {
+    Coins(u64),
+    Item(&'static Item, u64),
+}
+
+/// One side of a duel: the fish that player reeled in
+#[derive(Debug, Clone, Copy)]
+pub struct DuelRoll {
+    pub species: &'static Species,
+    pub size_cm: u32,
+}
+
+impl DuelRoll {
+    /// Rarity first, size breaks ties
+    fn score(&self) -> (crate::fish::Rarity, u32) {
+        (self.species.rarity, self.size_cm)
+    }
+}
+
+/// How a duel played out; `winner_id` is `None` on a dead heat
+#[derive(Debug, Clone)]
+pub struct DuelResult {
+    pub challenger: DuelRoll,
+    pub opponent: DuelRoll,
+    pub winner_id: Option<String>,
+}
+
+/// Server-wide numbers for one species, for `/fishpedia`
+#[derive(Debug, Clone, Default)]
+pub struct SpeciesStats {
+    /// Lifetime catches across the server
+    pub caught: u64,
+    /// How many of those were shiny
+    pub shiny_caught: u64,
+    /// (username, size in cm) of the biggest one on record
+    pub biggest: Option<(String, u32)>,
+    /// UTC hour in which it has been caught most often
+    pub peak_hour: Option<u32>,
+}
+
+/// A player on the daily best-anglers board
+#[derive(Debug, Clone)]
+pub struct BestAngler {
+    pub user_id: String,
+    pub username: String,
+    pub streak: u64,
+    pub total_catches: u64,
+    pub title: Option<&'static Title>,
+}
+
+/// A player whose streak reached a milestone today
+#[derive(Debug, Clone)]
+pub struct StreakMilestone {
+    pub user_id: String,
+    pub username: String,
+    pub streak: u64,
+    /// The streak role it earned, if it reached one's threshold
+    pub role_id: Option<String>,
+}
+
+/// A tracked member who hasn't fished in [`INACTIVE_DAYS`] days or more
+#[derive(Debug, Clone)]
+pub struct InactivePlayer {
+    pub user_id: String,
+    /// Their saved name; `None` if they never fished here
+    pub username: Option<String>,
+    /// Days since they last fished; `None` if they never have
+    pub days_idle: Option<i64>,
+}
+
+/// What the admin DM digest shows about a server, taken before its reset
+#[derive(Debug, Clone)]
+pub struct AdminDigest {
+    /// The admins who opted in
+    pub recipients: Vec<String>,
+    pub today_date: String,
+    pub daily_count: u64,
+    /// Combo days and streak roles reached today, longest streak first
+    pub milestones: Vec<StreakMilestone>,
+    pub hall_of_fame: crate::data::HallOfFame,
+    /// Players with saved data
+    pub players: usize,
+    /// Catches kept in the live history, before old ones are archived
+    pub history_records: usize,
+    /// Days in the server's catch chart history
+    pub history_days: usize,
+    /// Bot messages still waiting to be cleaned up
+    pub pending_deletions: usize,
+}
+
+/// A player on the weekly catch leaderboard
+#[derive(Debug, Clone)]
+pub struct WeeklyAngler {
+    pub user_id: String,
+    pub username: String,
+    /// Catches in the last seven days
+    pub catches: u64,
+    pub streak: u64,
+    pub title: Option<&'static Title>,
+}
+
+/// One player's side of a `/compare`
+#[derive(Debug, Clone)]
+pub struct PlayerCard {
+    pub username: String,
+    pub title: Option<&'static Title>,
+    pub streak: u64,
+    pub best_streak: u64,
+    pub total_catches: u64,
+    /// Distinct species landed
+    pub fishdex: usize,
+    /// Duels won against the other player
+    pub duel_wins: u64,
+}
+
+/// A player on a `/topstreaks` board
+#[derive(Debug, Clone)]
+pub struct StreakEntry {
+    pub user_id: String,
+    pub username: String,
+    pub streak: u64,
+    pub title: Option<&'static Title>,
+}
+
+/// Snapshot of the day for the end-of-day summary post
+#[derive(Debug, Clone)]
+pub struct DailySummary {
+    pub today_date: String,
+    pub summary_channel_id: Option<String>,
+    pub guild_id: String,
+    pub tracked_role_ids: Vec<String>,
+    /// Where tonight's summary goes and what each channel does with reminders
+    pub channels: Vec<crate::data::SummaryChannel>,
+    pub reminder_threshold: u64,
+    /// Per-role overrides of `reminder_threshold`
+    pub role_reminder_thresholds: HashMap<String, u64>,
+    pub best_angler_streak: u64,
+    pub daily_count: u64,
+    /// Sorted by streak, then total catches
+    pub best_anglers: Vec<BestAngler>,
+    /// Tonight's lottery result, once drawn
+    pub lottery: Option<crate::data::LotteryDraw>,
+    /// (team name, points today, points this month), best month first
+    pub team_standings: Vec<(String, u64, u64)>,
+    /// Set on the day a team month is closed
+    pub team_winner: Option<crate::data::TeamWinner>,
+    pub hall_of_fame: crate::data::HallOfFame,
+    /// Finished days before today, oldest first (today is `daily_count`)
+    pub recent_days: Vec<crate::data::DailyTotal>,
+    /// Catches per spot, busiest first
+    pub spot_counts: Vec<(&'static Location, u64)>,
+    /// Every [`MessageKey`](crate::data::MessageKey)'s text, custom or default
+    pub messages: HashMap<crate::data::MessageKey, String>,
+    pub locale: crate::data::Locale,
+    fished_today: HashSet<String>,
+    on_vacation: HashSet<String>,
+    excluded: HashSet<String>,
+    snoozed: HashSet<String>,
+    last_fished_dates: HashMap<String, String>,
+}
+
+impl DailySummary {
+    /// Whether a tracked member holding the tracked roles `role_ids` has gone
+    /// long enough without fishing to be pinged
+    pub fn needs_reminder(&self, user_id: &str, role_ids: &[String]) -> bool {
+        if self.fished_today.contains(user_id)
+            || self.on_vacation.contains(user_id)
+            || self.excluded.contains(user_id)
+            || self.snoozed.contains(user_id)
+        {
+            return false;
+        }
+        let threshold = role_ids
+            .iter()
+            .map(|id| {
+                self.role_reminder_thresholds
+                    .get(id)
+                    .copied()
+                    .unwrap_or(self.reminder_threshold)
+            })
+            .min()
+            .unwrap_or(self.reminder_threshold) as i64;
+        let days = match self.last_fished_dates.get(user_id) {
+            Some(last) => FishingManager::get_days_difference(last, &self.today_date),
+            None => threshold,
+        };
+        days >= threshold
+    }
+}
+
+/// The shared catch from a boat trip
+#[derive(Debug, Clone)]
+pub struct BoatHaul {
+    pub trip: crate::data::BoatTrip,
+    /// `None` when nobody boarded
+    pub catch: Option<(&'static Species, u32)>,
+    pub luck: f64,
+    pub coins_each: u64,
+}
+
+/// A wild fish landed by the first player to click
+#[derive(Debug, Clone)]
+pub struct WildCatch {
+    pub species: &'static Species,
+    pub size_cm: u32,
+    pub coins_earned: u64,
+    pub total_catches: u64,
+}
+
+/// Why a cast came out the way it did, for `/fishadmin simulatecatch`
+#[derive(Debug, Clone)]
+pub struct CatchBreakdown {
+    pub today_date: String,
+    pub yesterday_date: String,
+    pub last_fished_date: Option<String>,
+    pub previous_streak: u64,
+    pub streak_rule: &'static str,
+    pub fished_today_at: Option<String>,
+    pub luck_modifiers: Vec<(String, f64)>,
+    pub luck: f64,
+}
+
+/// A computed cast that hasn't been written back yet
+struct CatchPlan {
+    outcome: CatchOutcome,
+    p_user: crate::data::PersistentUserData,
+    /// History entries pushed past the cap by this catch
+    archived: Vec<crate::data::CatchRecord>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum FishingError {
+    AlreadyFished,
+    UnknownLocation,
+    LocationLocked,
+    AlreadyUnlocked,
+    NotEnoughCoins {
+        needed: u64,
+        available: u64,
+    },
+    MerchantGone,
+    OutOfStock,
+    CannotGiftSelf,
+    NotEnoughItems {
+        available: u64,
+    },
+    GiftLimitReached {
+        remaining: u64,
+    },
+    CannotDuelSelf,
+    UnknownTitle,
+    TitleNotEarned,
+    NoSpinAvailable,
+    TeamExists,
+    UnknownTeam,
+    TooManyTeams,
+    AlreadyOnTeam,
+    NeverFished,
+    InvalidDate,
+    BoatGone,
+    AlreadyAboard,
+    WildFishGone,
+    BirthdayAlreadySet,
+    UnknownTimezone,
+    PondFrozen,
+    FishBanned {
+        until: Option<u64>,
+        reason: Option<String>,
+    },
+    CannotReferSelf,
+    AlreadyReferred,
+    NotNewMember,
+    Internal(String),
+}
+
+impl std::fmt::Display for FishingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FishingError::AlreadyFished => write!(f, "ALREADY_FISHED"),
+            FishingError::UnknownLocation => write!(f, "UNKNOWN_LOCATION"),
+            FishingError::LocationLocked => write!(f, "LOCATION_LOCKED"),
+            FishingError::AlreadyUnlocked => write!(f, "ALREADY_UNLOCKED"),
+            FishingError::NotEnoughCoins { needed, available } => {
+                write!(
+                    f,
+                    "NOT_ENOUGH_COINS ({} needed, {} available)",
+                    needed, available
+                )
+            }
+            FishingError::MerchantGone => write!(f, "MERCHANT_GONE"),
+            FishingError::OutOfStock => write!(f, "OUT_OF_STOCK"),
+            FishingError::CannotGiftSelf => write!(f, "CANNOT_GIFT_SELF"),
+            FishingError::NotEnoughItems { available } => {
+                write!(f, "NOT_ENOUGH_ITEMS ({} available)", available)
+            }
+            FishingError::GiftLimitReached { remaining } => {
+                write!(f, "GIFT_LIMIT_REACHED ({} remaining today)", remaining)
+            }
+            FishingError::CannotDuelSelf => write!(f, "CANNOT_DUEL_SELF"),
+            FishingError::UnknownTitle => write!(f, "UNKNOWN_TITLE"),
+            FishingError::TitleNotEarned => write!(f, "TITLE_NOT_EARNED"),
+            FishingError::NoSpinAvailable => write!(f, "NO_SPIN_AVAILABLE"),
+            FishingError::TeamExists => write!(f, "TEAM_EXISTS"),
+            FishingError::UnknownTeam => write!(f, "UNKNOWN_TEAM"),
+            FishingError::TooManyTeams => write!(f, "TOO_MANY_TEAMS"),
+            FishingError::AlreadyOnTeam => write!(f, "ALREADY_ON_TEAM"),
+            FishingError::NeverFished => write!(f, "NEVER_FISHED"),
+            FishingError::InvalidDate => write!(f, "INVALID_DATE"),
+            FishingError::BoatGone => write!(f, "BOAT_GONE"),
+            FishingError::AlreadyAboard => write!(f, "ALREADY_ABOARD"),
+            FishingError::WildFishGone => write!(f, "WILD_FISH_GONE"),
+            FishingError::BirthdayAlreadySet => write!(f, "BIRTHDAY_ALREADY_SET"),
+            FishingError::UnknownTimezone => write!(f, "UNKNOWN_TIMEZONE"),
+            FishingError::PondFrozen => write!(f, "POND_FROZEN"),
+            FishingError::FishBanned { .. } => write!(f, "FISH_BANNED"),
+            FishingError::CannotReferSelf => write!(f, "CANNOT_REFER_SELF"),
+            FishingError::AlreadyReferred => write!(f, "ALREADY_REFERRED"),
+            FishingError::NotNewMember => write!(f, "NOT_NEW_MEMBER"),
+            FishingError::Internal(s) => write!(f, "Internal error: {}", s),
+        }
+    }
+}
+
+impl std::error::Error for FishingError {}
+
+/// Look up an IANA timezone by name, ignoring case
+pub fn parse_timezone(name: &str) -> Option<Tz> {
+    chrono_tz::TZ_VARIANTS
+        .iter()
+        .copied()
+        .find(|tz| tz.name().eq_ignore_ascii_case(name.trim()))
+}
+
+/// Quote a CSV field if it contains a separator, quote, or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+impl FishingManager {
+    pub fn new(data_manager: Arc<DataManager>) -> Self {
+        Self {
+            data_manager,
+            is_resetting: Arc::new(AtomicBool::new(false)),
+            #[cfg(feature = "redis")]
+            cache: None,
+        }
+    }
+
+    /// Answer hot lookups from Redis before touching the game state
+    #[cfg(feature = "redis")]
+    pub fn with_cache(mut self, cache: crate::cache::RedisCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Forget cached lookups for a server whose state was replaced wholesale,
+    /// e.g. by a backup restore
+    pub async fn clear_cache(&self, guild_id: &str) {
+        #[cfg(feature = "redis")]
+        if let Some(cache) = &self.cache {
+            cache.clear_fished(&[guild_id.to_string()]).await;
+        }
+        #[cfg(not(feature = "redis"))]
+        let _ = guild_id;
+    }
+
+    /// IDs of the members holding `role_id`, if a cache has a recent list
+    pub async fn cached_role_members(&self, guild_id: &str, role_id: &str) -> Option<Vec<String>> {
+        #[cfg(feature = "redis")]
+        if let Some(cache) = &self.cache {
+            return cache.role_members(guild_id, role_id).await;
+        }
+        #[cfg(not(feature = "redis"))]
+        let _ = (guild_id, role_id);
+        None
+    }
+
+    /// Remember who holds `role_id` for the next `cached_role_members`
+    pub async fn cache_role_members(&self, guild_id: &str, role_id: &str, member_ids: &[String]) {
+        #[cfg(feature = "redis")]
+        if let Some(cache) = &self.cache {
+            cache.set_role_members(guild_id, role_id, member_ids).await;
+        }
+        #[cfg(not(feature = "redis"))]
+        let _ = (guild_id, role_id, member_ids);
+    }
+
+    /// Every server the pond is running in
+    pub async fn guild_ids(&self) -> Vec<String> {
+        self.data_manager.guild_ids().await
+    }
+
+    pub fn get_date_string(timestamp: u64) -> String {
+        DateTime::<Utc>::from(std::time::UNIX_EPOCH + std::time::Duration::from_millis(timestamp))
+            .format("%Y-%m-%d")
+            .to_string()
+    }
+
+    /// When a reset at `at` on `tz`'s clock next happens after `now`. A time
+    /// skipped by a daylight saving change moves to the next day.
+    pub fn next_reset(now: DateTime<Utc>, at: chrono::NaiveTime, tz: Tz) -> DateTime<Utc> {
+        let today = now.with_timezone(&tz).date_naive();
+        (0..=2)
+            .filter_map(|days| Self::scheduled_at(today + chrono::Duration::days(days), at, tz))
+            .find(|reset| *reset > now)
+            .unwrap_or(now + chrono::Duration::days(1))
+    }
+
+    /// The latest reset at or before `now`
+    pub fn last_reset(now: DateTime<Utc>, at: chrono::NaiveTime, tz: Tz) -> DateTime<Utc> {
+        let today = now.with_timezone(&tz).date_naive();
+        (0..=2)
+            .filter_map(|days| Self::scheduled_at(today - chrono::Duration::days(days), at, tz))
+            .find(|reset| *reset <= now)
+            .unwrap_or(now - chrono::Duration::days(1))
+    }
+
+    /// When `at` falls on `day` in `tz`. A time the clocks skip over (a DST
+    /// gap) happens the first minute after it instead, so the day still gets
+    /// its reset.
+    fn scheduled_at(
+        day: chrono::NaiveDate,
+        at: chrono::NaiveTime,
+        tz: Tz,
+    ) -> Option<DateTime<Utc>> {
+        use chrono::TimeZone;
+        let local = day.and_time(at);
+        (0..=180)
+            .find_map(|minutes| {
+                tz.from_local_datetime(&(local + chrono::Duration::minutes(minutes)))
+                    .earliest()
+            })
+            .map(|reset| reset.with_timezone(&Utc))
+    }
+
+    pub fn get_yesterday_date_string() -> String {
+        (Utc::now() - chrono::Duration::days(1))
+            .format("%Y-%m-%d")
+            .to_string()
+    }
+
+    pub fn get_days_difference(date1: &str, date2: &str) -> i64 {
+        let d1 = chrono::NaiveDate::parse_from_str(date1, "%Y-%m-%d")
+            .unwrap_or_else(|_| chrono::NaiveDate::from_ymd_opt(1970, 1, 1).unwrap());
+        let d2 = chrono::NaiveDate::parse_from_str(date2, "%Y-%m-%d")
+            .unwrap_or_else(|_| chrono::NaiveDate::from_ymd_opt(1970, 1, 1).unwrap());
+        d2.signed_duration_since(d1).num_days()
+    }
+
+    fn stream_frenzy_active(data: &GuildData, now_millis: u64) -> bool {
+        data.stream_frenzy_until
+            .is_some_and(|until| until > now_millis)
+    }
+
+    /// Every server-wide odds boost currently running, as (name, multiplier)
+    fn luck_modifiers(data: &GuildData, now_millis: u64) -> Vec<(String, f64)> {
+        let mut modifiers = Vec::new();
+        if Self::stream_frenzy_active(data, now_millis) {
+            modifiers.push(("📺 Stream frenzy".to_string(), STREAM_FRENZY_LUCK));
+        }
+        if let Some(event) = data
+            .active_event
+            .as_ref()
+            .filter(|e| e.ends_at > now_millis)
+        {
+            modifiers.push((event.kind.name().to_string(), event.kind.luck()));
+        }
+        modifiers
+    }
+
+    /// Begin a random pond event unless one is already running.
+    /// Returns the pond channel to announce in.
+    pub async fn start_pond_event(
+        &self,
+        guild_id: &str,
+        kind: PondEventKind,
+        minutes: u64,
+    ) -> Option<Option<String>> {
+        let channel = {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            if data.active_event.is_some() {
+                return None;
+            }
+            data.active_event = Some(ActivePondEvent {
+                kind,
+                ends_at: chrono::Utc::now().timestamp_millis() as u64 + minutes * 60 * 1000,
+            });
+            data.button_channel_id.clone()
+        };
+
+        self.data_manager.save().await;
+        tracing::info!("🌦️ Pond event started: {:?}", kind);
+        Some(channel)
+    }
+
+    /// Clear the pond event if its time is up, returning what ended
+    pub async fn expire_pond_event(
+        &self,
+        guild_id: &str,
+    ) -> Option<(PondEventKind, Option<String>)> {
+        let now_millis = chrono::Utc::now().timestamp_millis() as u64;
+        let ended = {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            match &data.active_event {
+                Some(event) if event.ends_at <= now_millis => {
+                    let kind = event.kind;
+                    data.active_event = None;
+                    (kind, data.button_channel_id.clone())
+                }
+                _ => return None,
+            }
+        };
+
+        self.data_manager.save().await;
+        Some(ended)
+    }
+
+    /// Start a stream frenzy if `streamer` is the configured one.
+    /// Returns the frenzy end (ms) and the pond channel to announce in.
+    pub async fn start_stream_frenzy(
+        &self,
+        guild_id: &str,
+        streamer: &str,
+    ) -> Option<(u64, Option<String>)> {
+        let result = {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            let configured = data.streamer_name.as_deref()?;
+            if !configured.eq_ignore_ascii_case(streamer) {
+                return None;
+            }
+
+            let until = chrono::Utc::now().timestamp_millis() as u64
+                + data.stream_frenzy_minutes * 60 * 1000;
+            data.stream_frenzy_until = Some(until);
+            (until, data.button_channel_id.clone())
+        };
+
+        self.data_manager.save().await;
+        tracing::info!("📺 Stream frenzy started for {}", streamer);
+        Some(result)
+    }
+
+    /// Casts a member gets per day: two for boosters when the server's
+    /// booster perk is an extra cast, else one
+    fn daily_casts(data: &GuildData, booster: bool) -> u32 {
+        if booster && data.booster_perk == Some(crate::data::BoosterPerk::ExtraCast) {
+            2
+        } else {
+            1
+        }
+    }
+
+    /// Whether `user_id` has used every cast they get today, judged the
+    /// same way [`handle_fishing`](Self::handle_fishing) judges it
+    pub async fn out_of_casts(&self, guild_id: &str, user_id: &str, booster: bool) -> bool {
+        let (daily_casts, casts) = {
+            let data = self.data_manager.guild(guild_id).await;
+            (
+                Self::daily_casts(&data, booster),
+                data.users.get(user_id).map(|u| u.casts.max(1)),
+            )
+        };
+        // The cache only knows whether someone cast at all
+        #[cfg(feature = "redis")]
+        if daily_casts == 1 {
+            if let Some(cache) = &self.cache {
+                if cache.fished_today(guild_id, user_id).await {
+                    return true;
+                }
+            }
+        }
+        casts.is_some_and(|casts| casts >= daily_casts)
+    }
+
+    /// Run the catch rules against `data` without changing anything.
+    /// The breakdown is always filled in, even when the cast is rejected.
+    fn compute_catch(
+        data: &GuildData,
+        user_id: &str,
+        username: &str,
+        location_id: &str,
+        booster: bool,
+    ) -> (Result<CatchPlan, FishingError>, CatchBreakdown) {
+        let now_millis = chrono::Utc::now().timestamp_millis() as u64;
+        let today_date = data.date_of(now_millis);
+        let yesterday_date = data.yesterday();
+        let existing = data.persistent_users.get(user_id);
+
+        let (streak, streak_rule) = match existing {
+            None => (1, "first catch ever"),
+            Some(p) if p.last_fished_date == yesterday_date => {
+                (p.streak + 1, "continued from yesterday")
+            }
+            Some(p) if p.last_fished_date == today_date => (p.streak, "already counted today"),
+            Some(p) if p.streak > 0 && p.on_vacation(&yesterday_date) => {
+                (p.streak + 1, "continued after vacation")
+            }
+            Some(p) if p.streak > 0 && data.is_blackout(&yesterday_date) => {
+                (p.streak + 1, "continued over a blackout day")
+            }
+            Some(_) => (1, "reset after a missed day"),
+        };
+
+        let mut luck_modifiers = Self::luck_modifiers(data, now_millis);
+        let bait = existing.and_then(|p| crate::items::best_bait(&p.inventory));
+        if let Some((item, bait_luck)) = bait {
+            luck_modifiers.push((format!("{} {}", item.emoji, item.name), bait_luck));
+        }
+        if let Some(fortune) = data.fortunes.get(user_id) {
+            luck_modifiers.push(("🔮 Fortune".to_string(), fortune.luck));
+        }
+        let booster_perk = data.booster_perk.filter(|_| booster);
+        if booster_perk == Some(crate::data::BoosterPerk::ExtraLuck) {
+            luck_modifiers.push(("💎 Server booster".to_string(), BOOSTER_LUCK));
+        }
+        let luck = luck_modifiers.iter().map(|(_, m)| m).product::<f64>();
+
+        let breakdown = CatchBreakdown {
+            today_date: today_date.clone(),
+            yesterday_date,
+            last_fished_date: existing.map(|p| p.last_fished_date.clone()),
+            previous_streak: existing.map_or(0, |p| p.streak),
+            streak_rule,
+            fished_today_at: data.users.get(user_id).map(|u| u.fished_at.clone()),
+            luck_modifiers,
+            luck,
+        };
+
+        if data.paused_since.is_some() {
+            return (Err(FishingError::PondFrozen), breakdown);
+        }
+        if let Some(ban) = data.fish_ban(user_id, now_millis) {
+            let err = FishingError::FishBanned {
+                until: ban.until,
+                reason: ban.reason.clone(),
+            };
+            return (Err(err), breakdown);
+        }
+        let location = match crate::fish::find_location(location_id) {
+            Some(location) => location,
+            None => return (Err(FishingError::UnknownLocation), breakdown),
+        };
+        let daily_casts = Self::daily_casts(data, booster);
+        if data
+            .users
+            .get(user_id)
+            .is_some_and(|u| u.casts.max(1) >= daily_casts)
+        {
+            return (Err(FishingError::AlreadyFished), breakdown);
+        }
+        let unlocked = match existing {
+            Some(p_user) => p_user.has_unlocked(location),
+            None => location.is_free(),
+        };
+        if !unlocked {
+            return (Err(FishingError::LocationLocked), breakdown);
+        }
+
+        // Only the cast that extends the streak lands on a combo
+        let combo = streak % COMBO_INTERVAL == 0
+            && existing.is_none_or(|p| p.last_fished_date != today_date);
+        let (species, size_cm) = if combo {
+            // Bonus roll: keep the rarer (then bigger) of two
+            let (first, second) = (location.roll(luck), location.roll(luck));
+            std::cmp::max_by_key(first, second, |(species, size)| (species.rarity, *size))
+        } else {
+            location.roll(luck)
+        };
+        // Only the day's first cast celebrates, so a second booster cast can't repeat the gift
+        let birthday = !data.users.contains_key(user_id)
+            && existing.is_some_and(|p| p.birthday.as_deref() == today_date.get(5..));
+        let shiny = birthday || rand::thread_rng().gen_bool(SHINY_CHANCE);
+        let fish_of_the_day = data.fish_of_the_day.as_deref() == Some(species.id);
+        let bonus = if fish_of_the_day {
+            FISH_OF_THE_DAY_MULTIPLIER
+        } else {
+            1
+        };
+        let combo_bonus = if combo { COMBO_COIN_MULTIPLIER } else { 1 };
+        let coins_earned = species.rarity.coins() * bonus * combo_bonus;
+
+        let mut p_user = existing
+            .cloned()
+            .unwrap_or_else(|| crate::data::PersistentUserData::new(username.to_string()));
+        p_user.set_streak(streak);
+        p_user.last_fished_date = today_date;
+        // Casting means they're back
+        p_user.vacation_until = None;
+        p_user.username = username.to_string();
+        p_user.total_catches += 1;
+        p_user.coins += coins_earned;
+        if let Some((item, _)) = bait {
+            if let Some(count) = p_user.inventory.get_mut(item.id) {
+                *count -= 1;
+                if *count == 0 {
+                    p_user.inventory.remove(item.id);
+                }
+            }
+        }
+        if birthday {
+            p_user.coins += BIRTHDAY_GIFT_COINS;
+            *p_user
+                .inventory
+                .entry(BIRTHDAY_GIFT_ITEM.to_string())
+                .or_insert(0) += 1;
+        }
+
+        let archived = p_user.record_catch(crate::data::CatchRecord {
+            at: now_millis,
+            location: location.id.to_string(),
+            species: species.id.to_string(),
+            size_cm,
+            shiny,
+        });
+
+        let facts = crate::titles::CastFacts {
+            daily_position: data.daily_count + 1,
+            rarity: species.rarity,
+            streak: p_user.streak,
+            total_catches: p_user.total_catches,
+            location_id: location.id,
+        };
+        let new_titles: Vec<&'static Title> = crate::titles::qualifying(&facts)
+            .into_iter()
+            .filter(|title| !p_user.titles.iter().any(|id| id == title.id))
+            .collect();
+        p_user
+            .titles
+            .extend(new_titles.iter().map(|title| title.id.to_string()));
+        if p_user.equipped_title.is_none() {
+            p_user.equipped_title = new_titles.first().map(|title| title.id.to_string());
+        }
+
+        let outcome = CatchOutcome {
+            streak: p_user.streak,
+            total_catches: p_user.total_catches,
+            daily_count: data.daily_count + 1,
+            location,
+            species,
+            size_cm,
+            shiny,
+            fish_of_the_day,
+            combo,
+            booster_perk,
+            pond_name: data.branding().pond_name,
+            locale: data.locale,
+            birthday,
+            coins_earned,
+            coins: p_user.coins,
+            stream_frenzy: Self::stream_frenzy_active(data, now_millis),
+            bait_used: bait.map(|(item, _)| item),
+            pond_event: data
+                .active_event
+                .as_ref()
+                .filter(|e| e.ends_at > now_millis)
+                .map(|e| e.kind),
+            title: p_user.title(),
+            new_titles,
+            streak_roles: data.streak_roles_for(p_user.streak),
+            lottery_tickets: data.lottery_tickets.get(user_id).copied().unwrap_or(0) + 1,
+            team_points: p_user
+                .team
+                .as_ref()
+                .and_then(|id| data.teams.get(id))
+                .map(|team| (team.name.clone(), Self::team_points(species.rarity) * bonus)),
+            new_records: Vec::new(),
+            referral_paid: None,
+        };
+
+        (
+            Ok(CatchPlan {
+                outcome,
+                p_user,
+                archived,
+            }),
+            breakdown,
+        )
+    }
+
+    /// Cast for a user. `booster` marks a server booster, who gets the configured perk.
+    pub async fn handle_fishing(
+        &self,
+        guild_id: &str,
+        user_id: String,
+        username: String,
+        location_id: &str,
+        booster: bool,
+    ) -> Result<CatchOutcome, FishingError> {
+        let mut data = self.data_manager.guild_mut(guild_id).await;
+
+        let (plan, _) = Self::compute_catch(&data, &user_id, &username, location_id, booster);
+        let CatchPlan {
+            mut outcome,
+            p_user,
+            archived,
+        } = plan?;
+
+        let today = data.today();
+        outcome.new_records = data.hall_of_fame.record_catch(
+            &user_id,
+            &username,
+            outcome.streak,
+            p_user.history.last().expect("the catch was just recorded"),
+            outcome.daily_count,
+            &today,
+        );
+
+        let journal_entry = JournalEvent::Catch {
+            guild_id: guild_id.to_string(),
+            user_id: user_id.clone(),
+            username: username.clone(),
+            streak: p_user.streak,
+            total_catches: p_user.total_catches,
+            last_fished_date: p_user.last_fished_date.clone(),
+            record: p_user
+                .history
+                .last()
+                .cloned()
+                .expect("the catch was just recorded"),
+        };
+
+        let casts = data.users.get(&user_id).map_or(0, |u| u.casts.max(1)) + 1;
+        data.persistent_users.insert(user_id.clone(), p_user);
+        data.users.insert(
+            user_id.clone(),
+            crate::data::UserData {
+                username,
+                fished_at: Utc::now().to_rfc3339(),
+                location: Some(outcome.location.id.to_string()),
+                species: Some(outcome.species.id.to_string()),
+                spun: false,
+                casts,
+            },
+        );
+        data.daily_count += 1;
+        *data
+            .species_caught
+            .entry(outcome.species.id.to_string())
+            .or_insert(0) += 1;
+        data.lottery_tickets
+            .insert(user_id.clone(), outcome.lottery_tickets);
+        if let Some(team_id) = data.persistent_users[&user_id].team.clone() {
+            if let Some((_, points)) = &outcome.team_points {
+                if let Some(team) = data.teams.get_mut(&team_id) {
+                    team.points += points;
+                    team.points_today += points;
+                }
+            }
+        }
+        if outcome.streak >= REFERRAL_STREAK {
+            outcome.referral_paid = Self::pay_referral(&mut data, &user_id);
+        }
+        if outcome.shiny {
+            *data
+                .shiny_caught
+                .entry(outcome.species.id.to_string())
+                .or_insert(0) += 1;
+        }
+
+        drop(data);
+        self.data_manager.save().await;
+        self.data_manager.journal(journal_entry).await;
+        #[cfg(feature = "redis")]
+        if let Some(cache) = &self.cache {
+            cache.mark_fished(guild_id, &user_id).await;
+        }
+        self.data_manager
+            .archive_catches(guild_id, &user_id, &archived)
+            .await;
+
+        Ok(outcome)
+    }
+
+    /// Dry-run a cast for a user: nothing is saved and nothing is posted
+    pub async fn simulate_catch(
+        &self,
+        guild_id: &str,
+        user_id: &str,
+        username: &str,
+        location_id: &str,
+        booster: bool,
+    ) -> (Result<CatchOutcome, FishingError>, CatchBreakdown) {
+        let data = self.data_manager.guild(guild_id).await;
+        let (plan, breakdown) = Self::compute_catch(&data, user_id, username, location_id, booster);
+        (plan.map(|p| p.outcome), breakdown)
+    }
+
+    /// Buy early access to a location with coins. Returns the remaining balance.
+    pub async fn unlock_location(
+        &self,
+        guild_id: &str,
+        user_id: &str,
+        location_id: &str,
+    ) -> Result<u64, FishingError> {
+        let location =
+            crate::fish::find_location(location_id).ok_or(FishingError::UnknownLocation)?;
+
+        let remaining = {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            let p_user = match data.persistent_users.get_mut(user_id) {
+                Some(p_user) => p_user,
+                None if location.is_free() => return Err(FishingError::AlreadyUnlocked),
+                None => {
+                    return Err(FishingError::NotEnoughCoins {
+                        needed: location.unlock_coins,
+                        available: 0,
+                    })
+                }
+            };
+
+            if p_user.has_unlocked(location) {
+                return Err(FishingError::AlreadyUnlocked);
+            }
+            if p_user.coins < location.unlock_coins {
+                return Err(FishingError::NotEnoughCoins {
+                    needed: location.unlock_coins,
+                    available: p_user.coins,
+                });
+            }
+
+            p_user.coins -= location.unlock_coins;
+            p_user.unlocked_locations.push(location.id.to_string());
+            p_user.coins
+        };
+
+        self.data_manager.save().await;
+        Ok(remaining)
+    }
+
+    /// How much of a gift type the sender has already given away today
+    fn gifted_today(data: &GuildData, sender_id: &str, coins: bool) -> u64 {
+        let today = data.today();
+        data.audit_log
+            .iter()
+            .filter(|e| e.actor_id == sender_id && data.date_of(e.at) == today)
+            .map(|e| match (&e.action, coins) {
+                (AuditAction::GiftCoins { amount, .. }, true) => *amount,
+                (AuditAction::GiftItem { amount, .. }, false) => *amount,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    /// Transfer coins or items between players, within the daily gift limits
+    pub async fn gift(
+        &self,
+        guild_id: &str,
+        sender_id: &str,
+        recipient_id: &str,
+        recipient_name: &str,
+        gift: Gift,
+    ) -> Result<(), FishingError> {
+        if sender_id == recipient_id {
+            return Err(FishingError::CannotGiftSelf);
+        }
+
+        {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            let (amount, is_coins, limit) = match gift {
+                Gift::Coins(amount) => (amount, true, DAILY_GIFT_COIN_LIMIT),
+                Gift::Item(_, amount) => (amount, false, DAILY_GIFT_ITEM_LIMIT),
+            };
+            let remaining = limit.saturating_sub(Self::gifted_today(&data, sender_id, is_coins));
+            if amount > remaining {
+                return Err(FishingError::GiftLimitReached { remaining });
+            }
+
+            let sender = data.persistent_users.get(sender_id);
+            match gift {
+                Gift::Coins(amount) => {
+                    let available = sender.map_or(0, |p| p.coins);
+                    if available < amount {
+                        return Err(FishingError::NotEnoughCoins {
+                            needed: amount,
+                            available,
+                        });
+                    }
+                }
+                Gift::Item(item, amount) => {
+                    let available = sender
+                        .and_then(|p| p.inventory.get(item.id))
+                        .copied()
+                        .unwrap_or(0);
+                    if available < amount {
+                        return Err(FishingError::NotEnoughItems { available });
+                    }
+                }
+            }
+
+            let sender = data
+                .persistent_users
+                .get_mut(sender_id)
+                .expect("balance checked above");
+            match gift {
+                Gift::Coins(amount) => sender.coins -= amount,
+                Gift::Item(item, amount) => {
+                    let count = sender
+                        .inventory
+                        .get_mut(item.id)
+                        .expect("balance checked above");
+                    *count -= amount;
+                    if *count == 0 {
+                        sender.inventory.remove(item.id);
+                    }
+                }
+            }
+
+            let recipient = data
+                .persistent_users
+                .entry(recipient_id.to_string())
+                .or_insert_with(|| {
+                    crate::data::PersistentUserData::new(recipient_name.to_string())
+                });
+            let action = match gift {
+                Gift::Coins(amount) => {
+                    recipient.coins += amount;
+                    AuditAction::GiftCoins {
+                        to: recipient_id.to_string(),
+                        amount,
+                    }
+                }
+                Gift::Item(item, amount) => {
+                    *recipient.inventory.entry(item.id.to_string()).or_insert(0) += amount;
+                    AuditAction::GiftItem {
+                        to: recipient_id.to_string(),
+                        item: item.id.to_string(),
+                        amount,
+                    }
+                }
+            };
+            crate::audit::record(&mut data, sender_id, action);
+        }
+
+        self.data_manager.save().await;
+        Ok(())
+    }
+
+    /// Make sure both players can cover a duel wager
+    pub async fn check_duel_wager(
+        &self,
+        guild_id: &str,
+        challenger_id: &str,
+        opponent_id: &str,
+        wager: u64,
+    ) -> Result<(), FishingError> {
+        if challenger_id == opponent_id {
+            return Err(FishingError::CannotDuelSelf);
+        }
+        let data = self.data_manager.guild(guild_id).await;
+        Self::check_duel_balances(&data, &[challenger_id, opponent_id], wager)
+    }
+
+    fn check_duel_balances(
+        data: &GuildData,
+        user_ids: &[&str],
+        wager: u64,
+    ) -> Result<(), FishingError> {
+        for user_id in user_ids {
+            let available = data.persistent_users.get(*user_id).map_or(0, |p| p.coins);
+            if available < wager {
+                return Err(FishingError::NotEnoughCoins {
+                    needed: wager,
+                    available,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Roll a one-off catch for each player at the pond and pay the wager
+    /// to whoever landed the rarer (then bigger) fish. Duels don't count
+    /// towards streaks or daily catches and ignore luck modifiers.
+    pub async fn resolve_duel(
+        &self,
+        guild_id: &str,
+        challenger_id: &str,
+        opponent_id: &str,
+        wager: u64,
+    ) -> Result<DuelResult, FishingError> {
+        let pond = crate::fish::LOCATIONS
+            .iter()
+            .find(|l| l.is_free())
+            .ok_or_else(|| FishingError::Internal("no free location".to_string()))?;
+
+        let result = {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            // Balances may have changed while the challenge was pending
+            Self::check_duel_balances(&data, &[challenger_id, opponent_id], wager)?;
+
+            let roll = || {
+                let (species, size_cm) = pond.roll(1.0);
+                DuelRoll { species, size_cm }
+            };
+            let challenger = roll();
+            let opponent = roll();
+            let winner = match challenger.score().cmp(&opponent.score()) {
+                std::cmp::Ordering::Greater => Some((challenger_id, opponent_id)),
+                std::cmp::Ordering::Less => Some((opponent_id, challenger_id)),
+                std::cmp::Ordering::Equal => None,
+            };
+
+            if let Some((winner_id, loser_id)) = winner {
+                if let Some(winner) = data.persistent_users.get_mut(winner_id) {
+                    *winner.duel_wins.entry(loser_id.to_string()).or_insert(0) += 1;
+                }
+                if wager > 0 {
+                    if let Some(loser) = data.persistent_users.get_mut(loser_id) {
+                        loser.coins -= wager;
+                    }
+                    if let Some(winner) = data.persistent_users.get_mut(winner_id) {
+                        winner.coins += wager;
+                    }
+                    crate::audit::record(
+                        &mut data,
+                        loser_id,
+                        AuditAction::DuelWager {
+                            to: winner_id.to_string(),
+                            amount: wager,
+                        },
+                    );
+                }
+            }
+
+            DuelResult {
+                challenger,
+                opponent,
+                winner_id: winner.map(|(winner_id, _)| winner_id.to_string()),
+            }
+        };
+
+        self.data_manager.save().await;
+        Ok(result)
+    }
+
+    /// Most recent audit entries, optionally only those involving `user_id`
+    pub async fn audit_entries(
+        &self,
+        guild_id: &str,
+        user_id: Option<&str>,
+        limit: usize,
+    ) -> Vec<crate::audit::AuditEntry> {
+        let data = self.data_manager.guild(guild_id).await;
+        data.audit_log
+            .iter()
+            .rev()
+            .filter(|e| user_id.is_none_or(|id| e.actor_id == id || e.action.subject() == Some(id)))
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+
+    /// Per-player stats as CSV (user ID, name, streak, total catches, last
+    /// fished), busiest anglers first
+    pub async fn player_stats_csv(&self, guild_id: &str) -> String {
+        let data = self.data_manager.guild(guild_id).await;
+        let mut players: Vec<_> = data.persistent_users.iter().collect();
+        players.sort_by(|a, b| {
+            b.1.total_catches
+                .cmp(&a.1.total_catches)
+                .then_with(|| a.0.cmp(b.0))
+        });
+
+        let mut csv = String::from("user_id,username,streak,total_catches,last_fished\n");
+        for (user_id, p_user) in players {
+            csv.push_str(&format!(
+                "{},{},{},{},{}\n",
+                user_id,
+                csv_field(&p_user.username),
+                p_user.streak,
+                p_user.total_catches,
+                p_user.last_fished_date
+            ));
+        }
+        csv
+    }
+
+    /// Coins and item counts for a user
+    pub async fn inventory(
+        &self,
+        guild_id: &str,
+        user_id: &str,
+    ) -> (u64, Vec<(&'static Item, u64)>) {
+        let data = self.data_manager.guild(guild_id).await;
+        match data.persistent_users.get(user_id) {
+            Some(p_user) => {
+                let mut items: Vec<(&'static Item, u64)> = p_user
+                    .inventory
+                    .iter()
+                    .filter_map(|(id, count)| crate::items::find_item(id).map(|i| (i, *count)))
+                    .collect();
+                items.sort_by_key(|(item, _)| item.name);
+                (p_user.coins, items)
+            }
+            None => (0, Vec::new()),
+        }
+    }
+
+    pub async fn merchant_visit(&self, guild_id: &str) -> Option<MerchantVisit> {
+        self.data_manager.guild(guild_id).await.merchant.clone()
+    }
+
+    /// Open a merchant visit in the pond channel unless one is already running
+    pub async fn begin_merchant_visit(
+        &self,
+        guild_id: &str,
+        offers: Vec<MerchantOffer>,
+        leaves_at: u64,
+    ) -> Option<MerchantVisit> {
+        let visit = {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            if data.merchant.is_some() {
+                return None;
+            }
+            let visit = MerchantVisit {
+                channel_id: data.button_channel_id.clone()?,
+                message_id: None,
+                leaves_at,
+                offers,
+            };
+            data.merchant = Some(visit.clone());
+            visit
+        };
+
+        self.data_manager.save().await;
+        tracing::info!("🧳 Travelling merchant arrived");
+        Some(visit)
+    }
+
+    pub async fn set_merchant_message(&self, guild_id: &str, message_id: String) {
+        {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            if let Some(visit) = data.merchant.as_mut() {
+                visit.message_id = Some(message_id);
+            }
+        }
+        self.data_manager.save().await;
+    }
+
+    /// Close the visit if the merchant's time is up, returning it for cleanup
+    pub async fn end_merchant_visit(&self, guild_id: &str) -> Option<MerchantVisit> {
+        let now_millis = chrono::Utc::now().timestamp_millis() as u64;
+        let visit = {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            if data
+                .merchant
+                .as_ref()
+                .is_none_or(|v| v.leaves_at > now_millis)
+            {
+                return None;
+            }
+            data.merchant.take()
+        };
+
+        self.data_manager.save().await;
+        visit
+    }
+
+    pub async fn boat_trip(&self, guild_id: &str) -> Option<crate::data::BoatTrip> {
+        self.data_manager.guild(guild_id).await.boat.clone()
+    }
+
+    /// Start boarding a boat in the pond channel unless one is already out
+    pub async fn begin_boat_trip(
+        &self,
+        guild_id: &str,
+        departs_at: u64,
+    ) -> Option<crate::data::BoatTrip> {
+        let trip = {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            if data.boat.is_some() {
+                return None;
+            }
+            let trip = crate::data::BoatTrip {
+                channel_id: data.button_channel_id.clone()?,
+                message_id: None,
+                departs_at,
+                crew: Vec::new(),
+            };
+            data.boat = Some(trip.clone());
+            trip
+        };
+
+        self.data_manager.save().await;
+        tracing::info!("⛵ A boat is taking on crew");
+        Some(trip)
+    }
+
+    pub async fn set_boat_message(&self, guild_id: &str, message_id: String) {
+        {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            if let Some(trip) = data.boat.as_mut() {
+                trip.message_id = Some(message_id);
+            }
+        }
+        self.data_manager.save().await;
+    }
+
+    /// Join the boat before it sails. Returns the crew size.
+    pub async fn board_boat(
+        &self,
+        guild_id: &str,
+        user_id: &str,
+        username: &str,
+    ) -> Result<usize, FishingError> {
+        let now_millis = chrono::Utc::now().timestamp_millis() as u64;
+        let crew = {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            let trip = data
+                .boat
+                .as_mut()
+                .filter(|trip| trip.departs_at > now_millis)
+                .ok_or(FishingError::BoatGone)?;
+            if trip.crew.iter().any(|c| c.user_id == user_id) {
+                return Err(FishingError::AlreadyAboard);
+            }
+            trip.crew.push(crate::data::CrewMember {
+                user_id: user_id.to_string(),
+                username: username.to_string(),
+            });
+            trip.crew.len()
+        };
+
+        self.data_manager.save().await;
+        Ok(crew)
+    }
+
+    /// Sail the boat once boarding has closed: one roll for the whole crew,
+    /// with luck and coins growing with crew size. Every member is paid.
+    pub async fn sail_boat(&self, guild_id: &str) -> Option<BoatHaul> {
+        let now_millis = chrono::Utc::now().timestamp_millis() as u64;
+        let haul = {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            if data
+                .boat
+                .as_ref()
+                .is_none_or(|trip| trip.departs_at > now_millis)
+            {
+                return None;
+            }
+            let trip = data.boat.take()?;
+
+            let bonus_crew = (trip.crew.len() as u64).clamp(1, BOAT_MAX_BONUS_CREW) - 1;
+            let luck = 1.0 + BOAT_LUCK_PER_CREW * bonus_crew as f64;
+            let catch = (!trip.crew.is_empty()).then(|| {
+                crate::fish::find_location(BOAT_LOCATION)
+                    .expect("the boat location exists")
+                    .roll(luck)
+            });
+            let coins_each = catch.map_or(0, |(species, _)| {
+                species.rarity.coins() * (100 + BOAT_COIN_PERCENT_PER_CREW * bonus_crew) / 100
+            });
+
+            for member in &trip.crew {
+                let p_user = data
+                    .persistent_users
+                    .entry(member.user_id.clone())
+                    .or_insert_with(|| {
+                        crate::data::PersistentUserData::new(member.username.clone())
+                    });
+                p_user.coins += coins_each;
+            }
+            if let Some((species, _)) = catch {
+                *data
+                    .species_caught
+                    .entry(species.id.to_string())
+                    .or_insert(0) += 1;
+            }
+
+            BoatHaul {
+                trip,
+                catch,
+                luck,
+                coins_each,
+            }
+        };
+
+        self.data_manager.save().await;
+        tracing::info!(
+            "⛵ Boat returned with {} crew and {:?}",
+            haul.trip.crew.len(),
+            haul.catch.map(|(species, _)| species.name)
+        );
+        Some(haul)
+    }
+
+    /// Surface a wild fish from the pond's pool unless one is already out
+    pub async fn spawn_wild_fish(
+        &self,
+        guild_id: &str,
+        expires_at: u64,
+    ) -> Option<crate::data::WildFish> {
+        let wild = {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            if data.wild_fish.is_some() {
+                return None;
+            }
+            let (species, size_cm) = crate::fish::LOCATIONS[0].roll(1.0);
+            let wild = crate::data::WildFish {
+                id: chrono::Utc::now().timestamp_millis().to_string(),
+                channel_id: data.button_channel_id.clone()?,
+                message_id: None,
+                species: species.id.to_string(),
+                size_cm,
+                expires_at,
+            };
+            data.wild_fish = Some(wild.clone());
+            wild
+        };
+
+        self.data_manager.save().await;
+        tracing::info!("🐟 A wild {} surfaced", wild.species);
+        Some(wild)
+    }
+
+    pub async fn set_wild_fish_message(&self, guild_id: &str, message_id: String) {
+        {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            if let Some(wild) = data.wild_fish.as_mut() {
+                wild.message_id = Some(message_id);
+            }
+        }
+        self.data_manager.save().await;
+    }
+
+    /// Claim the wild fish with the given spawn ID. The check and the take happen
+    /// under one write lock, so exactly one clicker wins.
+    pub async fn claim_wild_fish(
+        &self,
+        guild_id: &str,
+        spawn_id: &str,
+        user_id: &str,
+        username: &str,
+    ) -> Result<WildCatch, FishingError> {
+        let now_millis = chrono::Utc::now().timestamp_millis() as u64;
+        let catch = {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            if data
+                .wild_fish
+                .as_ref()
+                .is_none_or(|wild| wild.id != spawn_id || wild.expires_at <= now_millis)
+            {
+                return Err(FishingError::WildFishGone);
+            }
+            let wild = data.wild_fish.take().ok_or(FishingError::WildFishGone)?;
+            let species =
+                crate::fish::find_species(&wild.species).ok_or(FishingError::WildFishGone)?;
+
+            let coins_earned = species.rarity.coins();
+            let p_user = data
+                .persistent_users
+                .entry(user_id.to_string())
+                .or_insert_with(|| crate::data::PersistentUserData::new(username.to_string()));
+            p_user.total_catches += 1;
+            p_user.coins += coins_earned;
+            let total_catches = p_user.total_catches;
+            *data
+                .species_caught
+                .entry(species.id.to_string())
+                .or_insert(0) += 1;
+
+            WildCatch {
+                species,
+                size_cm: wild.size_cm,
+                coins_earned,
+                total_catches,
+            }
+        };
+
+        self.data_manager.save().await;
+        tracing::info!("🐟 {} claimed the wild {}", username, catch.species.name);
+        Ok(catch)
+    }
+
+    /// Let an unclaimed wild fish swim off once it expires, returning it for cleanup
+    pub async fn expire_wild_fish(&self, guild_id: &str) -> Option<crate::data::WildFish> {
+        let now_millis = chrono::Utc::now().timestamp_millis() as u64;
+        let wild = {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            if data
+                .wild_fish
+                .as_ref()
+                .is_none_or(|wild| wild.expires_at > now_millis)
+            {
+                return None;
+            }
+            data.wild_fish.take()
+        };
+
+        self.data_manager.save().await;
+        wild
+    }
+
+    /// Buy one of an item from the merchant. Returns the item, price paid,
+    /// the buyer's remaining coins, and the updated visit for re-rendering.
+    pub async fn buy_from_merchant(
+        &self,
+        guild_id: &str,
+        user_id: &str,
+        item_id: &str,
+    ) -> Result<(&'static Item, u64, u64, MerchantVisit), FishingError> {
+        let now_millis = chrono::Utc::now().timestamp_millis() as u64;
+        let item = crate::items::find_item(item_id).ok_or(FishingError::OutOfStock)?;
+
+        let result = {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            let data = &mut *data;
+            let visit = match data.merchant.as_mut() {
+                Some(visit) if visit.leaves_at > now_millis => visit,
+                _ => return Err(FishingError::MerchantGone),
+            };
+            let offer = visit
+                .offers
+                .iter_mut()
+                .find(|o| o.item == item_id)
+                .ok_or(FishingError::OutOfStock)?;
+            if offer.stock == 0 {
+                return Err(FishingError::OutOfStock);
+            }
+
+            let available = data.persistent_users.get(user_id).map_or(0, |p| p.coins);
+            if available < offer.price {
+                return Err(FishingError::NotEnoughCoins {
+                    needed: offer.price,
+                    available,
+                });
+            }
+            let p_user = data
+                .persistent_users
+                .get_mut(user_id)
+                .expect("has enough coins, so the record exists");
+
+            offer.stock -= 1;
+            p_user.coins -= offer.price;
+            *p_user.inventory.entry(item.id.to_string()).or_insert(0) += 1;
+            (item, offer.price, p_user.coins, visit.clone())
+        };
+
+        self.data_manager.save().await;
+        Ok(result)
+    }
+
+    /// Spin today's bonus wheel. Only allowed once per catch; the flag is
+    /// checked and set under the write lock so repeat clicks can't pay twice.
+    pub async fn spin_wheel(
+        &self,
+        guild_id: &str,
+        user_id: &str,
+    ) -> Result<crate::wheel::SpinPrize, FishingError> {
+        use crate::wheel::SpinPrize;
+
+        let prize = {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            match data.users.get_mut(user_id) {
+                Some(today) if !today.spun => today.spun = true,
+                _ => return Err(FishingError::NoSpinAvailable),
+            }
+
+            let prize = crate::wheel::spin();
+            let p_user = data
+                .persistent_users
+                .get_mut(user_id)
+                .ok_or_else(|| FishingError::Internal("catcher has no record".to_string()))?;
+            match prize {
+                SpinPrize::Nothing => {}
+                SpinPrize::Coins(amount) => p_user.coins += amount,
+                SpinPrize::Item(item) => {
+                    *p_user.inventory.entry(item.to_string()).or_insert(0) += 1
+                }
+            }
+            prize
+        };
+
+        self.data_manager.save().await;
+        Ok(prize)
+    }
+
+    /// Today's fortune for a player, drawing one if they haven't yet.
+    /// The flag is true when the fortune was drawn by this call.
+    pub async fn draw_fortune(
+        &self,
+        guild_id: &str,
+        user_id: &str,
+    ) -> (crate::data::DailyFortune, bool) {
+        let fortune = {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            if let Some(fortune) = data.fortunes.get(user_id) {
+                return (fortune.clone(), false);
+            }
+            let (text, luck) = crate::fortune::draw();
+            let fortune = crate::data::DailyFortune {
+                text: text.to_string(),
+                luck,
+            };
+            data.fortunes.insert(user_id.to_string(), fortune.clone());
+            fortune
+        };
+
+        self.data_manager.save().await;
+        (fortune, true)
+    }
+
+    /// A player's recorded catches, newest first
+    pub async fn catch_history(
+        &self,
+        guild_id: &str,
+        user_id: &str,
+    ) -> Vec<crate::data::CatchRecord> {
+        let data = self.data_manager.guild(guild_id).await;
+        data.persistent_users
+            .get(user_id)
+            .map(|p| p.history.iter().rev().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// How often the server has caught a species, from the lifetime counter
+    /// and the recorded catch histories
+    pub async fn species_stats(&self, guild_id: &str, species_id: &str) -> SpeciesStats {
+        let data = self.data_manager.guild(guild_id).await;
+        let mut hours = [0u64; 24];
+        let mut biggest: Option<(String, u32)> = None;
+        for p_user in data.persistent_users.values() {
+            for record in p_user.history.iter().filter(|r| r.species == species_id) {
+                let hour = (record.at / 3_600_000 % 24) as usize;
+                hours[hour] += 1;
+                if biggest
+                    .as_ref()
+                    .is_none_or(|(_, size)| record.size_cm > *size)
+                {
+                    biggest = Some((p_user.username.clone(), record.size_cm));
+                }
+            }
+        }
+
+        SpeciesStats {
+            caught: data.species_caught.get(species_id).copied().unwrap_or(0),
+            shiny_caught: data.shiny_caught.get(species_id).copied().unwrap_or(0),
+            biggest,
+            peak_hour: hours
+                .iter()
+                .enumerate()
+                .filter(|(_, count)| **count > 0)
+                .max_by_key(|(_, count)| **count)
+                .map(|(hour, _)| hour as u32),
+        }
+    }
+
+    /// Mark a missed day (YYYY-MM-DD) as fished and recompute the streak from
+    /// catch history. History is capped and archived, so a run that reaches
+    /// back to the oldest day it holds carries on into the stored streak.
+    /// Returns (old streak, new streak).
+    pub async fn repair_streak(
+        &self,
+        guild_id: &str,
+        admin_id: &str,
+        user_id: &str,
+        date: &str,
+    ) -> Result<(u64, u64), FishingError> {
+        let day = chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map_err(|_| FishingError::InvalidDate)?;
+        let today = self
+            .data_manager
+            .guild(guild_id)
+            .await
+            .local_now()
+            .date_naive();
+        if day > today {
+            return Err(FishingError::InvalidDate);
+        }
+        let date = day.format("%Y-%m-%d").to_string();
+
+        let (old, new) = {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            let tz = data.tz();
+            let p_user = data
+                .persistent_users
+                .get_mut(user_id)
+                .ok_or(FishingError::NeverFished)?;
+            if !p_user.repaired_dates.contains(&date) {
+                p_user.repaired_dates.push(date.clone());
+            }
+
+            let mut days: Vec<chrono::NaiveDate> = p_user
+                .history
+                .iter()
+                .filter_map(|record| DateTime::from_timestamp_millis(record.at as i64))
+                .map(|at| at.with_timezone(&tz).format("%Y-%m-%d").to_string())
+                .chain(p_user.repaired_dates.iter().cloned())
+                .chain(std::iter::once(p_user.last_fished_date.clone()))
+                .filter_map(|d| chrono::NaiveDate::parse_from_str(&d, "%Y-%m-%d").ok())
+                .collect();
+            days.sort_unstable();
+            days.dedup();
+
+            // Count back through consecutive days from the most recent one;
+            // a run that ended before yesterday is already broken
+            let latest = *days.last().expect("the repaired day is always present");
+            let mut run = days
+                .iter()
+                .rev()
+                .zip(0..)
+                .take_while(|(d, i)| latest - **d == chrono::Duration::days(*i))
+                .count() as u64;
+            // History can't see past its oldest day, but the stored streak
+            // can: if its run meets this one, they're the same streak
+            let last_fished =
+                chrono::NaiveDate::parse_from_str(&p_user.last_fished_date, "%Y-%m-%d");
+            if let (true, Ok(last_fished)) = (run == days.len() as u64, last_fished) {
+                let earliest = days[0];
+                let stored_start = last_fished - chrono::Duration::days(p_user.streak as i64 - 1);
+                if p_user.streak > 0
+                    && stored_start < earliest
+                    && last_fished >= earliest - chrono::Duration::days(1)
+                {
+                    run = (latest - stored_start).num_days() as u64 + 1;
+                }
+            }
+            let streak = if latest >= today - chrono::Duration::days(1) {
+                run
+            } else {
+                0
+            };
+
+            let old = p_user.streak;
+            p_user.set_streak(streak);
+            p_user.last_fished_date = latest.format("%Y-%m-%d").to_string();
+            crate::audit::record(
+                &mut data,
+                admin_id,
+                AuditAction::RepairStreak {
+                    to: user_id.to_string(),
+                    date,
+                    streak,
+                },
+            );
+            (old, streak)
+        };
+        self.data_manager.save().await;
+
+        tracing::info!("🩹 Repaired {}'s streak: {} -> {}", user_id, old, new);
+        Ok((old, new))
+    }
+
+    /// Overwrite a user's streak, e.g. to restore one lost to an outage.
+    /// A streak that hasn't been fished today is counted through yesterday,
+    /// so their next catch continues it. Returns the old and new streak.
+    pub async fn set_streak(
+        &self,
+        guild_id: &str,
+        admin_id: &str,
+        user_id: &str,
+        streak: u64,
+    ) -> Result<(u64, u64), FishingError> {
+        let old = {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            let (today, yesterday) = (data.today(), data.yesterday());
+            let p_user = data
+                .persistent_users
+                .get_mut(user_id)
+                .ok_or(FishingError::NeverFished)?;
+            let old = p_user.streak;
+            p_user.set_streak(streak);
+            if streak > 0 && p_user.last_fished_date != today {
+                p_user.last_fished_date = yesterday;
+            }
+            crate::audit::record(
+                &mut data,
+                admin_id,
+                AuditAction::SetStreak {
+                    to: user_id.to_string(),
+                    from: old,
+                    streak,
+                },
+            );
+            old
+        };
+        self.data_manager.save().await;
+
+        tracing::info!("🔥 Set {}'s streak: {} -> {}", user_id, old, streak);
+        Ok((old, streak))
+    }
+
+    /// Fold a sheet of hand-kept streaks into this server (see
+    /// [`StreakSheet::merge_into`](crate::streak_csv::StreakSheet::merge_into))
+    pub async fn import_streaks(
+        &self,
+        guild_id: &str,
+        admin_id: &str,
+        sheet: crate::streak_csv::StreakSheet,
+    ) -> crate::legacy::ImportSummary {
+        let summary = {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            let summary = sheet.merge_into(&mut data);
+            crate::audit::record(
+                &mut data,
+                admin_id,
+                AuditAction::ImportStreaks {
+                    added: summary.added as u64,
+                    updated: summary.merged as u64,
+                },
+            );
+            summary
+        };
+        self.data_manager.save().await;
+
+        tracing::info!(
+            "📋 Imported streaks in {}: {} new, {} updated",
+            guild_id,
+            summary.added,
+            summary.merged
+        );
+        summary
+    }
+
+    /// Add `amount` (negative to subtract) to a user's lifetime catches,
+    /// e.g. to carry counts over from another bot. The total stays at zero
+    /// or above; players new to this server get a record. Returns the old
+    /// and new totals.
+    pub async fn adjust_catches(
+        &self,
+        guild_id: &str,
+        admin_id: &str,
+        user_id: &str,
+        username: &str,
+        amount: i64,
+    ) -> (u64, u64) {
+        let (old, new) = {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            let p_user = data
+                .persistent_users
+                .entry(user_id.to_string())
+                .or_insert_with(|| crate::data::PersistentUserData::new(username.to_string()));
+            let old = p_user.total_catches;
+            p_user.total_catches = old.saturating_add_signed(amount);
+            let new = p_user.total_catches;
+            crate::audit::record(
+                &mut data,
+                admin_id,
+                AuditAction::AdjustCatches {
+                    to: user_id.to_string(),
+                    amount: new as i64 - old as i64,
+                    total: new,
+                },
+            );
+            (old, new)
+        };
+        self.data_manager.save().await;
+
+        tracing::info!("🎣 Adjusted {}'s catches: {} -> {}", user_id, old, new);
+        (old, new)
+    }
+
+    /// Freeze a user's streak for the next `days` days (0 cancels).
+    /// Returns the last day covered.
+    pub async fn set_vacation(
+        &self,
+        guild_id: &str,
+        user_id: &str,
+        days: u64,
+    ) -> Result<Option<String>, FishingError> {
+        let until = {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            let now = data.local_now();
+            let p_user = data
+                .persistent_users
+                .get_mut(user_id)
+                .ok_or(FishingError::NeverFished)?;
+            let days = days.min(MAX_VACATION_DAYS);
+            p_user.vacation_until = (days > 0).then(|| {
+                (now + chrono::Duration::days(days as i64 - 1))
+                    .format("%Y-%m-%d")
+                    .to_string()
+            });
+            p_user.vacation_until.clone()
+        };
+        self.data_manager.save().await;
+
+        match &until {
+            Some(date) => tracing::info!("🏖️ {} is on vacation until {}", user_id, date),
+            None => tracing::info!("🏖️ {} is back from vacation", user_id),
+        }
+        Ok(until)
+    }
+
+    /// Erase everything stored about `user_id` in this server. Returns
+    /// whether there was anything to erase.
+    pub async fn delete_user_data(&self, guild_id: &str, user_id: &str) -> bool {
+        let found = self
+            .data_manager
+            .guild_mut(guild_id)
+            .await
+            .forget_user(user_id);
+        if !found {
+            return false;
+        }
+        self.data_manager.save().await;
+        #[cfg(feature = "redis")]
+        if let Some(cache) = &self.cache {
+            cache.unmark_fished(guild_id, user_id).await;
+        }
+        self.data_manager
+            .journal(JournalEvent::Forget {
+                guild_id: guild_id.to_string(),
+                user_id: user_id.to_string(),
+            })
+            .await;
+
+        tracing::info!("🗑️ Erased {}'s data in {}", user_id, guild_id);
+        true
+    }
+
+    /// Wipe `scope` of a player's data on an admin's say-so, e.g. for test
+    /// accounts or abuse. Returns whether there was anything to wipe.
+    pub async fn reset_user(
+        &self,
+        guild_id: &str,
+        admin_id: &str,
+        user_id: &str,
+        scope: UserReset,
+    ) -> bool {
+        {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            if !data.reset_user(user_id, scope) {
+                return false;
+            }
+            crate::audit::record(
+                &mut data,
+                admin_id,
+                AuditAction::ResetUser {
+                    to: user_id.to_string(),
+                    scope,
+                },
+            );
+        }
+        self.data_manager.save().await;
+        #[cfg(feature = "redis")]
+        if scope != UserReset::Stats {
+            if let Some(cache) = &self.cache {
+                cache.unmark_fished(guild_id, user_id).await;
+            }
+        }
+        self.data_manager
+            .journal(JournalEvent::ResetUser {
+                guild_id: guild_id.to_string(),
+                user_id: user_id.to_string(),
+                scope,
+            })
+            .await;
+
+        tracing::info!(
+            "🧹 {} reset {}'s {} in {}",
+            admin_id,
+            user_id,
+            scope.label(),
+            guild_id
+        );
+        true
+    }
+
+    /// Fold `old_id`'s record into `new_id`'s after an account switch and
+    /// erase `old_id`. Returns false if `old_id` has no record to merge.
+    pub async fn merge_users(
+        &self,
+        guild_id: &str,
+        admin_id: &str,
+        old_id: &str,
+        new_id: &str,
+    ) -> bool {
+        {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            if !data.merge_users(old_id, new_id) {
+                return false;
+            }
+            crate::audit::record(
+                &mut data,
+                admin_id,
+                AuditAction::MergeUsers {
+                    from: old_id.to_string(),
+                    to: new_id.to_string(),
+                },
+            );
+        }
+        self.data_manager.save().await;
+        #[cfg(feature = "redis")]
+        if let Some(cache) = &self.cache {
+            cache.unmark_fished(guild_id, old_id).await;
+        }
+        self.data_manager
+            .journal(JournalEvent::MergeUsers {
+                guild_id: guild_id.to_string(),
+                from: old_id.to_string(),
+                to: new_id.to_string(),
+            })
+            .await;
+
+        tracing::info!("🔀 Merged {} into {} in {}", old_id, new_id, guild_id);
+        true
+    }
+
+    /// Replace stale stored names with members' current ones (user ID ->
+    /// display name). Members who aren't players are ignored. Returns how
+    /// many players were renamed.
+    pub async fn refresh_usernames(
+        &self,
+        guild_id: &str,
+        names: &HashMap<String, String>,
+    ) -> usize {
+        let renamed = self
+            .data_manager
+            .guild_mut(guild_id)
+            .await
+            .rename_users(names);
+        if renamed > 0 {
+            self.data_manager.save().await;
+            tracing::info!("🏷️ Refreshed {} usernames in {}", renamed, guild_id);
+        }
+        renamed
+    }
+
+    /// Record `referrer_id` as the member who invited `referred_id`. The
+    /// referred member must be new to the pond and can only be claimed once;
+    /// the referrer is paid when they reach a `REFERRAL_STREAK` day streak.
+    pub async fn credit_referral(
+        &self,
+        guild_id: &str,
+        referrer_id: &str,
+        referred_id: &str,
+    ) -> Result<(), FishingError> {
+        if referrer_id == referred_id {
+            return Err(FishingError::CannotReferSelf);
+        }
+        {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            if !data.persistent_users.contains_key(referrer_id) {
+                return Err(FishingError::NeverFished);
+            }
+            if data.referrals.contains_key(referred_id) {
+                return Err(FishingError::AlreadyReferred);
+            }
+            if data.persistent_users.contains_key(referred_id) {
+                return Err(FishingError::NotNewMember);
+            }
+            data.referrals.insert(
+                referred_id.to_string(),
+                crate::data::Referral {
+                    referrer_id: referrer_id.to_string(),
+                    at: Utc::now().timestamp_millis() as u64,
+                    rewarded: false,
+                },
+            );
+            crate::audit::record(
+                &mut data,
+                referrer_id,
+                crate::audit::AuditAction::Referral {
+                    to: referred_id.to_string(),
+                },
+            );
+        }
+        self.data_manager.save().await;
+
+        tracing::info!("🤝 {} referred {}", referrer_id, referred_id);
+        Ok(())
+    }
+
+    /// Pay the referrer of `user_id` if they haven't been yet, returning their ID
+    fn pay_referral(data: &mut GuildData, user_id: &str) -> Option<String> {
+        let referral = data.referrals.get_mut(user_id).filter(|r| !r.rewarded)?;
+        let referrer = data.persistent_users.get_mut(&referral.referrer_id)?;
+        referral.rewarded = true;
+        referrer.coins += REFERRAL_BONUS_COINS;
+        tracing::info!(
+            "🤝 Paid {} coins to {} for referring {}",
+            REFERRAL_BONUS_COINS,
+            referral.referrer_id,
+            user_id
+        );
+        Some(referral.referrer_id.clone())
+    }
+
+    /// Register a player's birthday. It can only be set once, so nobody can
+    /// move it around for repeat gifts. Returns it as MM-DD.
+    pub async fn set_birthday(
+        &self,
+        guild_id: &str,
+        user_id: &str,
+        month: u32,
+        day: u32,
+    ) -> Result<String, FishingError> {
+        // 2000 is a leap year, so 29 February is accepted
+        let date =
+            chrono::NaiveDate::from_ymd_opt(2000, month, day).ok_or(FishingError::InvalidDate)?;
+        let birthday = date.format("%m-%d").to_string();
+        {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            let p_user = data
+                .persistent_users
+                .get_mut(user_id)
+                .ok_or(FishingError::NeverFished)?;
+            if p_user.birthday.is_some() {
+                return Err(FishingError::BirthdayAlreadySet);
+            }
+            p_user.birthday = Some(birthday.clone());
+        }
+        self.data_manager.save().await;
+
+        tracing::info!("🎂 {} registered a birthday on {}", user_id, birthday);
+        Ok(birthday)
+    }
+
+    /// A player's registered birthday as MM-DD
+    pub async fn birthday(&self, guild_id: &str, user_id: &str) -> Option<String> {
+        self.data_manager
+            .guild(guild_id)
+            .await
+            .persistent_users
+            .get(user_id)
+            .and_then(|p| p.birthday.clone())
+    }
+
+    /// Set the timezone times are shown to a player in, by IANA name (any
+    /// case), or clear it with `None`
+    pub async fn set_timezone(
+        &self,
+        guild_id: &str,
+        user_id: &str,
+        name: Option<&str>,
+    ) -> Result<Option<Tz>, FishingError> {
+        let tz = match name {
+            Some(name) => Some(parse_timezone(name).ok_or(FishingError::UnknownTimezone)?),
+            None => None,
+        };
+        {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            let p_user = data
+                .persistent_users
+                .get_mut(user_id)
+                .ok_or(FishingError::NeverFished)?;
+            p_user.timezone = tz.map(|tz| tz.name().to_string());
+        }
+        self.data_manager.save().await;
+        Ok(tz)
+    }
+
+    /// The timezone this server's days follow
+    pub async fn guild_timezone(&self, guild_id: &str) -> Tz {
+        self.data_manager.guild(guild_id).await.tz()
+    }
+
+    /// End this server's day at `at` on its own clock. Returns the clock.
+    pub async fn set_reset_time(&self, guild_id: &str, at: chrono::NaiveTime) -> Tz {
+        let mut data = self.data_manager.guild_mut(guild_id).await;
+        data.reset_time = Some(at);
+        Self::settle_reset(&mut data, Utc::now());
+        data.tz()
+    }
+
+    /// Make this server's days follow `tz` (UTC if `None`). Returns the
+    /// reset time on that clock.
+    pub async fn set_guild_timezone(&self, guild_id: &str, tz: Option<Tz>) -> chrono::NaiveTime {
+        let mut data = self.data_manager.guild_mut(guild_id).await;
+        data.timezone = tz.map(|tz| tz.name().to_string());
+        Self::settle_reset(&mut data, Utc::now());
+        data.reset_at()
+    }
+
+    /// Count the latest reset on the server's current schedule as done, so
+    /// moving the schedule (or thawing the pond) doesn't trigger a catch-up
+    /// reset in the middle of the day
+    fn settle_reset(data: &mut GuildData, now: DateTime<Utc>) {
+        let scheduled = Self::last_reset(now, data.reset_at(), data.tz());
+        data.last_reset_timestamp = data
+            .last_reset_timestamp
+            .max(scheduled.timestamp_millis() as u64);
+    }
+
+    /// Turn reminder pings on or off for one member. They keep showing up
+    /// in stats and summaries either way.
+    pub async fn set_reminders(&self, guild_id: &str, user_id: &str, enabled: bool) {
+        {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            if enabled {
+                data.reminder_opt_outs.remove(user_id);
+            } else {
+                data.reminder_opt_outs.insert(user_id.to_string());
+            }
+        }
+        self.data_manager.save().await;
+    }
+
+    /// Mute reminders for one member for [`REMINDER_SNOOZE_DAYS`] days.
+    /// Returns the last day (YYYY-MM-DD) the snooze lasts.
+    pub async fn snooze_reminders(&self, guild_id: &str, user_id: &str) -> String {
+        let until = {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            let until = (data.local_now() + chrono::Duration::days(REMINDER_SNOOZE_DAYS))
+                .format("%Y-%m-%d")
+                .to_string();
+            data.reminder_snoozes
+                .insert(user_id.to_string(), until.clone());
+            until
+        };
+        self.data_manager.save().await;
+        tracing::info!("😴 {} snoozed reminders until {}", user_id, until);
+        until
+    }
+
+    /// Set a player's daily DM reminder to `at` on their clock, or stop it
+    /// with `None`. A time already past today starts tomorrow. Returns
+    /// when it next fires.
+    pub async fn set_dm_reminder(
+        &self,
+        guild_id: &str,
+        user_id: &str,
+        at: Option<chrono::NaiveTime>,
+    ) -> Option<DateTime<Utc>> {
+        let next = {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            match at {
+                Some(at) => {
+                    let tz = data.user_tz(user_id);
+                    let now = Utc::now();
+                    let local = now.with_timezone(&tz);
+                    let last_due =
+                        (local.time() >= at).then(|| local.format("%Y-%m-%d").to_string());
+                    data.dm_reminders.insert(
+                        user_id.to_string(),
+                        crate::data::DmReminder { at, last_due },
+                    );
+                    Some(Self::next_reset(now, at, tz))
+                }
+                None => {
+                    data.dm_reminders.remove(user_id);
+                    None
+                }
+            }
+        };
+        self.data_manager.save().await;
+        next
+    }
+
+    /// The DM reminders whose time has come, marked so they don't fire
+    /// again today. Players who already fished are skipped, as are frozen
+    /// ponds.
+    pub async fn due_dm_reminders(&self, now: DateTime<Utc>) -> Vec<DueReminder> {
+        let mut due = Vec::new();
+        let mut changed = false;
+        {
+            let mut all = self.data_manager.data.write().await;
+            for (guild_id, data) in all.guilds.iter_mut() {
+                if data.paused_since.is_some() {
+                    continue;
+                }
+                let mut fired = Vec::new();
+                for (user_id, reminder) in &data.dm_reminders {
+                    let local = now.with_timezone(&data.user_tz(user_id));
+                    let day = local.format("%Y-%m-%d").to_string();
+                    if local.time() >= reminder.at && reminder.last_due.as_ref() != Some(&day) {
+                        fired.push((user_id.clone(), day));
+                    }
+                }
+                for (user_id, day) in fired {
+                    changed = true;
+                    if let Some(reminder) = data.dm_reminders.get_mut(&user_id) {
+                        reminder.last_due = Some(day);
+                    }
+                    if !data.users.contains_key(&user_id) {
+                        due.push(DueReminder {
+                            guild_id: guild_id.clone(),
+                            streak: data.persistent_users.get(&user_id).map_or(0, |p| p.streak),
+                            locale: data.locale,
+                            user_id,
+                        });
+                    }
+                }
+            }
+        }
+        if changed {
+            self.data_manager.save().await;
+        }
+        due
+    }
+
+    /// When the next DM reminder in any server comes due
+    pub async fn next_dm_reminder(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let all = self.data_manager.data.read().await;
+        all.guilds
+            .values()
+            .filter(|data| data.paused_since.is_none())
+            .flat_map(|data| {
+                data.dm_reminders.iter().map(|(user_id, reminder)| {
+                    Self::next_reset(now, reminder.at, data.user_tz(user_id))
+                })
+            })
+            .min()
+    }
+
+    /// `user_id`'s fishing ban in this server, if one is in force
+    pub async fn fish_ban(&self, guild_id: &str, user_id: &str) -> Option<crate::data::FishBan> {
+        let now_millis = chrono::Utc::now().timestamp_millis() as u64;
+        self.data_manager
+            .guild(guild_id)
+            .await
+            .fish_ban(user_id, now_millis)
+            .cloned()
+    }
+
+    /// Whether `/pausefishing` has frozen this server's pond
+    pub async fn is_paused(&self, guild_id: &str) -> bool {
+        self.data_manager
+            .guild(guild_id)
+            .await
+            .paused_since
+            .is_some()
+    }
+
+    /// Freeze or thaw this server's pond. Thawing moves every running
+    /// streak's last fished day forward by the length of the pause, so
+    /// nobody loses a streak to the days the pond was closed. Returns
+    /// false if the pond was already in that state.
+    pub async fn set_paused(&self, guild_id: &str, paused: bool) -> bool {
+        {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            let today = data.today();
+            match (paused, data.paused_since.take()) {
+                (true, None) => data.paused_since = Some(today),
+                (false, Some(since)) => {
+                    // The resets skipped while frozen aren't owed on thaw
+                    Self::settle_reset(&mut data, Utc::now());
+                    let days = Self::get_days_difference(&since, &today);
+                    for p_user in data.persistent_users.values_mut() {
+                        if p_user.streak == 0 {
+                            continue;
+                        }
+                        if let Ok(last) =
+                            chrono::NaiveDate::parse_from_str(&p_user.last_fished_date, "%Y-%m-%d")
+                        {
+                            p_user.last_fished_date = (last + chrono::Duration::days(days))
+                                .format("%Y-%m-%d")
+                                .to_string();
+                        }
+                    }
+                }
+                (_, since) => {
+                    data.paused_since = since;
+                    return false;
+                }
+            }
+        }
+        self.data_manager.save().await;
+
+        tracing::info!(
+            "🧊 Fishing {} in {}",
+            if paused { "paused" } else { "resumed" },
+            guild_id
+        );
+        true
+    }
+
+    /// A player's chosen timezone, if they've set one
+    pub async fn timezone(&self, guild_id: &str, user_id: &str) -> Option<Tz> {
+        self.data_manager
+            .guild(guild_id)
+            .await
+            .persistent_users
+            .get(user_id)
+            .and_then(|p| p.timezone.as_deref())
+            .and_then(|name| name.parse().ok())
+    }
+
+    /// Rolling participation, streak, and churn metrics for the server
+    pub async fn server_stats(&self, guild_id: &str) -> crate::analytics::ServerStats {
+        let data = self.data_manager.guild(guild_id).await;
+        crate::analytics::server_stats(&data, &data.today())
+    }
+
+    /// Side-by-side stats for two players, each with their duel wins over
+    /// the other. `None` for anyone who hasn't fished here.
+    pub async fn compare_players(
+        &self,
+        guild_id: &str,
+        a: &str,
+        b: &str,
+    ) -> (Option<PlayerCard>, Option<PlayerCard>) {
+        let data = self.data_manager.guild(guild_id).await;
+        let card = |user_id: &str, other_id: &str| {
+            data.persistent_users.get(user_id).map(|p| PlayerCard {
+                username: p.username.clone(),
+                title: p.title(),
+                streak: p.streak,
+                best_streak: p.longest_streak(),
+                total_catches: p.total_catches,
+                fishdex: p.fishdex_count(),
+                duel_wins: p.duel_wins.get(other_id).copied().unwrap_or(0),
+            })
+        };
+        (card(a, b), card(b, a))
+    }
+
+    /// Everyone with a streak, longest first: running streaks, or with
+    /// `all_time` the best each player has ever reached
+    pub async fn streak_board(&self, guild_id: &str, all_time: bool) -> Vec<StreakEntry> {
+        let data = self.data_manager.guild(guild_id).await;
+        let mut board: Vec<StreakEntry> = data
+            .persistent_users
+            .iter()
+            .map(|(user_id, p_user)| StreakEntry {
+                user_id: user_id.clone(),
+                username: p_user.username.clone(),
+                streak: if all_time {
+                    p_user.longest_streak()
+                } else {
+                    p_user.streak
+                },
+                title: p_user.title(),
+            })
+            .filter(|entry| entry.streak > 0)
+            .collect();
+        board.sort_by(|a, b| {
+            b.streak
+                .cmp(&a.streak)
+                .then_with(|| a.username.cmp(&b.username))
+        });
+        board
+    }
+
+    /// The server's all-time records
+    pub async fn hall_of_fame(&self, guild_id: &str) -> crate::data::HallOfFame {
+        self.data_manager.guild(guild_id).await.hall_of_fame.clone()
+    }
+
+    /// Totals and participants of the finished days kept, oldest first
+    pub async fn daily_history(&self, guild_id: &str) -> Vec<crate::data::DailyTotal> {
+        self.data_manager
+            .guild(guild_id)
+            .await
+            .daily_history
+            .clone()
+    }
+
+    /// Who fished on a finished day (YYYY-MM-DD), days repaired by an
+    /// admin included. Defaults to yesterday. `None` if that day isn't in
+    /// the kept history or was recorded before participants were.
+    pub async fn fished_on(
+        &self,
+        guild_id: &str,
+        date: Option<&str>,
+    ) -> Result<(String, Option<HashSet<String>>), FishingError> {
+        let data = self.data_manager.guild(guild_id).await;
+        let date = match date {
+            Some(date) => chrono::NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d")
+                .map_err(|_| FishingError::InvalidDate)?
+                .format("%Y-%m-%d")
+                .to_string(),
+            None => data.yesterday(),
+        };
+        if date >= data.today() {
+            return Err(FishingError::InvalidDate);
+        }
+        let fished = data
+            .daily_history
+            .iter()
+            .find(|day| day.date == date)
+            .filter(|day| day.catches == 0 || !day.participants.is_empty())
+            .map(|day| {
+                let mut fished: HashSet<String> = day.participants.iter().cloned().collect();
+                fished.extend(
+                    data.persistent_users
+                        .iter()
+                        .filter(|(_, p)| p.repaired_dates.contains(&date))
+                        .map(|(id, _)| id.clone()),
+                );
+                fished
+            });
+        Ok((date, fished))
+    }
+
+    /// Titles the user has earned, in table order
+    pub async fn earned_titles(&self, guild_id: &str, user_id: &str) -> Vec<&'static Title> {
+        let data = self.data_manager.guild(guild_id).await;
+        let earned = data
+            .persistent_users
+            .get(user_id)
+            .map(|p| p.titles.clone())
+            .unwrap_or_default();
+        crate::titles::TITLES
+            .iter()
+            .filter(|title| earned.iter().any(|id| id == title.id))
+            .collect()
+    }
+
+    /// Show an earned title next to the user's name, or clear it with `None`
+    pub async fn equip_title(
+        &self,
+        guild_id: &str,
+        user_id: &str,
+        title_id: Option<&str>,
+    ) -> Result<Option<&'static Title>, FishingError> {
+        let title = match title_id {
+            Some(id) => Some(crate::titles::find_title(id).ok_or(FishingError::UnknownTitle)?),
+            None => None,
+        };
+
+        {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            let p_user = data
+                .persistent_users
+                .get_mut(user_id)
+                .ok_or(FishingError::TitleNotEarned)?;
+            if let Some(title) = title {
+                if !p_user.titles.iter().any(|id| id == title.id) {
+                    return Err(FishingError::TitleNotEarned);
+                }
+            }
+            p_user.equipped_title = title.map(|t| t.id.to_string());
+        }
+
+        self.data_manager.save().await;
+        Ok(title)
+    }
+
+    /// Locations paired with whether the user can currently fish there
+    pub async fn location_access(
+        &self,
+        guild_id: &str,
+        user_id: &str,
+    ) -> Vec<(&'static Location, bool)> {
+        let data = self.data_manager.guild(guild_id).await;
+        let p_user = data.persistent_users.get(user_id);
+        crate::fish::LOCATIONS
+            .iter()
+            .map(|location| {
+                let unlocked = match p_user {
+                    Some(p_user) => p_user.has_unlocked(location),
+                    None => location.is_free(),
+                };
+                (location, unlocked)
+            })
+            .collect()
+    }
+
+    /// Top anglers by catches over the seven days before `now`, most first
+    /// (ties go to the longer streak)
+    pub async fn weekly_leaderboard(&self, guild_id: &str, now: u64) -> Vec<WeeklyAngler> {
+        let since = now.saturating_sub(7 * 24 * 60 * 60 * 1000);
+        let data = self.data_manager.guild(guild_id).await;
+
+        let mut anglers: Vec<WeeklyAngler> = data
+            .persistent_users
+            .iter()
+            .map(|(user_id, p_user)| WeeklyAngler {
+                user_id: user_id.clone(),
+                username: p_user.username.clone(),
+                catches: p_user
+                    .history
+                    .iter()
+                    .filter(|record| record.at > since && record.at <= now)
+                    .count() as u64,
+                streak: p_user.streak,
+                title: p_user.title(),
+            })
+            .filter(|angler| angler.catches > 0)
+            .collect();
+        anglers.sort_by(|a, b| b.catches.cmp(&a.catches).then(b.streak.cmp(&a.streak)));
+        anglers.truncate(WEEKLY_LEADERBOARD_SIZE);
+        anglers
+    }
+
+    /// Everything the end-of-day summary shows, except the member roster,
+    /// which only the chat frontend can fetch
+    pub async fn daily_summary(&self, guild_id: &str) -> DailySummary {
+        let data = self.data_manager.guild(guild_id).await;
+
+        let mut best_anglers: Vec<BestAngler> = data
+            .persistent_users
+            .iter()
+            .filter(|(_, p_user)| p_user.streak >= data.best_angler_streak)
+            .map(|(user_id, p_user)| BestAngler {
+                user_id: user_id.clone(),
+                username: p_user.username.clone(),
+                streak: p_user.streak,
+                total_catches: p_user.total_catches,
+                title: p_user.title(),
+            })
+            .collect();
+        // Sort: Streak DESC, then Total CAT DESC
+        best_anglers.sort_by(|a, b| {
+            b.streak
+                .cmp(&a.streak)
+                .then(b.total_catches.cmp(&a.total_catches))
+        });
+
+        // Per-spot counts for every spot that saw a catch or has a biome channel
+        let mut spot_counts: Vec<(&'static Location, u64)> = crate::fish::LOCATIONS
+            .iter()
+            .map(|location| {
+                let count = data
+                    .users
+                    .values()
+                    .filter(|u| u.location.as_deref() == Some(location.id))
+                    .count() as u64;
+                (location, count)
+            })
+            .filter(|(location, count)| {
+                *count > 0
+                    || data
+                        .biome_channels
+                        .values()
+                        .any(|b| b.location == location.id)
+            })
+            .collect();
+        spot_counts.sort_by_key(|s| std::cmp::Reverse(s.1));
+
+        let today_date = data.today();
+        DailySummary {
+            today_date: today_date.clone(),
+            summary_channel_id: data.summary_channel_id.clone(),
+            guild_id: guild_id.to_string(),
+            tracked_role_ids: data.tracked_role_ids.clone(),
+            channels: data
+                .summary_channels()
+                .into_iter()
+                .map(|mut channel| {
+                    // No nagging on a day streaks can't break, or a quiet one
+                    if data.is_blackout(&today_date) || data.is_quiet_weekday(&today_date) {
+                        channel.reminder = crate::data::ReminderMode::Off;
+                    }
+                    channel
+                })
+                .collect(),
+            reminder_threshold: data.reminder_threshold,
+            role_reminder_thresholds: data.role_reminder_thresholds.clone(),
+            best_angler_streak: data.best_angler_streak,
+            daily_count: data.daily_count,
+            best_anglers,
+            team_standings: Self::team_standings_of(&data),
+            hall_of_fame: data.hall_of_fame.clone(),
+            recent_days: data
+                .daily_history
+                .iter()
+                .rev()
+                .take(SUMMARY_CHART_DAYS - 1)
+                .rev()
+                .cloned()
+                .collect(),
+            team_winner: data
+                .last_team_winner
+                .clone()
+                .filter(|winner| winner.date == today_date),
+            lottery: data
+                .last_lottery
+                .clone()
+                .filter(|draw| draw.date == today_date),
+            spot_counts,
+            messages: crate::data::MessageKey::ALL
+                .iter()
+                .map(|key| (*key, data.message(*key).to_string()))
+                .collect(),
+            locale: data.locale,
+            fished_today: data.users.keys().cloned().collect(),
+            on_vacation: data
+                .persistent_users
+                .iter()
+                .filter(|(_, p)| p.on_vacation(&today_date))
+                .map(|(id, _)| id.clone())
+                .collect(),
+            excluded: data
+                .reminder_exclusions
+                .union(&data.reminder_opt_outs)
+                .cloned()
+                .collect(),
+            snoozed: data
+                .reminder_snoozes
+                .iter()
+                .filter(|(_, until)| **until >= today_date)
+                .map(|(id, _)| id.clone())
+                .collect(),
+            last_fished_dates: data
+                .persistent_users
+                .iter()
+                .map(|(id, p)| (id.clone(), p.last_fished_date.clone()))
+                .collect(),
+        }
+    }
+
+    /// Of `user_ids` (the tracked members), those who still get reminders
+    /// but haven't fished in [`INACTIVE_DAYS`] days or more. Longest idle
+    /// first; members who never fished come last.
+    pub async fn inactive_players(
+        &self,
+        guild_id: &str,
+        user_ids: &[String],
+    ) -> Vec<InactivePlayer> {
+        let data = self.data_manager.guild(guild_id).await;
+        let today = data.today();
+        let mut inactive: Vec<InactivePlayer> = user_ids
+            .iter()
+            .filter(|id| {
+                !data.reminder_exclusions.contains(*id) && !data.reminder_opt_outs.contains(*id)
+            })
+            .filter_map(|id| {
+                let p_user = data.persistent_users.get(id);
+                if p_user.is_some_and(|p_user| p_user.on_vacation(&today)) {
+                    return None;
+                }
+                let days_idle = p_user
+                    .filter(|p_user| !p_user.last_fished_date.is_empty())
+                    .map(|p_user| Self::get_days_difference(&p_user.last_fished_date, &today));
+                if days_idle.is_some_and(|days| days < INACTIVE_DAYS) {
+                    return None;
+                }
+                Some(InactivePlayer {
+                    user_id: id.clone(),
+                    username: p_user.map(|p_user| p_user.username.clone()),
+                    days_idle,
+                })
+            })
+            .collect();
+        inactive.sort_by_key(|player| std::cmp::Reverse(player.days_idle.unwrap_or(-1)));
+        inactive
+    }
+
+    /// The admin digest for `guild_id`, or `None` if no admin opted in
+    pub async fn admin_digest(&self, guild_id: &str) -> Option<AdminDigest> {
+        let data = self.data_manager.guild(guild_id).await;
+        if data.admin_digest_ids.is_empty() {
+            return None;
+        }
+        let today_date = data.today();
+
+        let mut milestones: Vec<StreakMilestone> = data
+            .persistent_users
+            .iter()
+            .filter(|(_, p_user)| p_user.streak > 0 && p_user.last_fished_date == today_date)
+            .filter_map(|(user_id, p_user)| {
+                let role_id = data
+                    .streak_roles
+                    .iter()
+                    .find(|role| role.streak == p_user.streak)
+                    .map(|role| role.role_id.clone());
+                (p_user.streak % COMBO_INTERVAL == 0 || role_id.is_some()).then(|| {
+                    StreakMilestone {
+                        user_id: user_id.clone(),
+                        username: p_user.username.clone(),
+                        streak: p_user.streak,
+                        role_id,
+                    }
+                })
+            })
+            .collect();
+        milestones.sort_by(|a, b| b.streak.cmp(&a.streak).then(a.username.cmp(&b.username)));
+
+        Some(AdminDigest {
+            recipients: data.admin_digest_ids.iter().cloned().collect(),
+            today_date,
+            daily_count: data.daily_count,
+            milestones,
+            hall_of_fame: data.hall_of_fame.clone(),
+            players: data.persistent_users.len(),
+            history_records: data
+                .persistent_users
+                .values()
+                .map(|p_user| p_user.history.len())
+                .sum(),
+            history_days: data.daily_history.len(),
+            pending_deletions: data.pending_deletions.len(),
+        })
+    }
+
+    /// Any species short of legendary, so the feature stays within reach
+    fn pick_fish_of_the_day() -> Option<String> {
+        crate::fish::all_species()
+            .filter(|species| species.rarity < crate::fish::Rarity::Legendary)
+            .collect::<Vec<_>>()
+            .choose(&mut rand::thread_rng())
+            .map(|species| species.id.to_string())
+    }
+
+    /// Team points for a catch: one for a common, up to five for a legendary
+    fn team_points(rarity: crate::fish::Rarity) -> u64 {
+        rarity as u64 + 1
+    }
+
+    fn team_standings_of(data: &GuildData) -> Vec<(String, u64, u64)> {
+        let mut standings: Vec<(String, u64, u64)> = data
+            .teams
+            .values()
+            .map(|team| (team.name.clone(), team.points_today, team.points))
+            .collect();
+        standings.sort_by(|a, b| b.2.cmp(&a.2).then(a.0.cmp(&b.0)));
+        standings
+    }
+
+    /// Teams as (name, points today, points this month), best month first
+    pub async fn team_standings(&self, guild_id: &str) -> Vec<(String, u64, u64)> {
+        Self::team_standings_of(&*self.data_manager.guild(guild_id).await)
+    }
+
+    /// Start a new team and put its creator on it
+    pub async fn create_team(
+        &self,
+        guild_id: &str,
+        user_id: &str,
+        username: &str,
+        name: &str,
+    ) -> Result<(), FishingError> {
+        let id = name.trim().to_lowercase();
+        {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            if data.teams.contains_key(&id) {
+                return Err(FishingError::TeamExists);
+            }
+            if data.teams.len() >= MAX_TEAMS {
+                return Err(FishingError::TooManyTeams);
+            }
+            data.teams.insert(
+                id.clone(),
+                crate::data::Team {
+                    name: name.trim().to_string(),
+                    created_by: user_id.to_string(),
+                    points: 0,
+                    points_today: 0,
+                },
+            );
+            data.persistent_users
+                .entry(user_id.to_string())
+                .or_insert_with(|| crate::data::PersistentUserData::new(username.to_string()))
+                .team = Some(id);
+        }
+
+        self.data_manager.save().await;
+        Ok(())
+    }
+
+    /// Switch to another team. Points already earned stay with the old team.
+    /// Returns the team's display name.
+    pub async fn join_team(
+        &self,
+        guild_id: &str,
+        user_id: &str,
+        username: &str,
+        team_id: &str,
+    ) -> Result<String, FishingError> {
+        let id = team_id.trim().to_lowercase();
+        let name = {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            let name = data
+                .teams
+                .get(&id)
+                .map(|team| team.name.clone())
+                .ok_or(FishingError::UnknownTeam)?;
+            let p_user = data
+                .persistent_users
+                .entry(user_id.to_string())
+                .or_insert_with(|| crate::data::PersistentUserData::new(username.to_string()));
+            if p_user.team.as_deref() == Some(id.as_str()) {
+                return Err(FishingError::AlreadyOnTeam);
+            }
+            p_user.team = Some(id);
+            name
+        };
+
+        self.data_manager.save().await;
+        Ok(name)
+    }
+
+    /// Close the team month if today is its last day: record the winner and
+    /// zero everyone's monthly points. Call before the daily summary.
+    pub async fn finish_team_month_if_due(
+        &self,
+        guild_id: &str,
+    ) -> Option<crate::data::TeamWinner> {
+        let now = self.data_manager.guild(guild_id).await.local_now();
+        if (now + chrono::Duration::days(1))
+            .format("%Y-%m")
+            .to_string()
+            == now.format("%Y-%m").to_string()
+        {
+            return None;
+        }
+
+        let winner = {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            let best = data
+                .teams
+                .values()
+                .filter(|team| team.points > 0)
+                .max_by_key(|team| team.points)
+                .map(|team| (team.name.clone(), team.points));
+            for team in data.teams.values_mut() {
+                team.points = 0;
+            }
+            let (team, points) = best?;
+            let winner = crate::data::TeamWinner {
+                date: now.format("%Y-%m-%d").to_string(),
+                month: now.format("%Y-%m").to_string(),
+                team,
+                points,
+            };
+            data.last_team_winner = Some(winner.clone());
+            winner
+        };
+
+        self.data_manager.save().await;
+        tracing::info!("🏁 {} won the team month", winner.team);
+        Some(winner)
+    }
+
+    /// Draw tonight's lottery: one winner, weighted by tickets, takes the
+    /// jackpot. Tickets are used up either way. `None` if nobody entered.
+    pub async fn draw_lottery(&self, guild_id: &str) -> Option<crate::data::LotteryDraw> {
+        use rand::distributions::{Distribution, WeightedIndex};
+
+        let draw = {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            let entries: Vec<(String, u64)> = data.lottery_tickets.drain().collect();
+            let tickets: u64 = entries.iter().map(|(_, count)| count).sum();
+            let dist = WeightedIndex::new(entries.iter().map(|(_, count)| *count)).ok()?;
+            let winner_id = entries[dist.sample(&mut rand::thread_rng())].0.clone();
+
+            let jackpot = LOTTERY_BASE_JACKPOT + tickets * LOTTERY_COINS_PER_TICKET;
+            let date = data.today();
+            let winner = data.persistent_users.get_mut(&winner_id)?;
+            winner.coins += jackpot;
+            let draw = crate::data::LotteryDraw {
+                date,
+                winner_id,
+                winner_name: winner.username.clone(),
+                jackpot,
+                tickets,
+            };
+            data.last_lottery = Some(draw.clone());
+            draw
+        };
+
+        self.data_manager.save().await;
+        tracing::info!(
+            "🎟️ Lottery drawn: {} won {} coins",
+            draw.winner_name,
+            draw.jackpot
+        );
+        Some(draw)
+    }
+
+    /// The name and colors a server's embeds use
+    pub async fn branding(&self, guild_id: &str) -> crate::data::Branding {
+        self.data_manager.guild(guild_id).await.branding()
+    }
+
+    /// When this server's next daily reset happens
+    pub async fn next_guild_reset(&self, guild_id: &str) -> DateTime<Utc> {
+        let data = self.data_manager.guild(guild_id).await;
+        Self::next_reset(Utc::now(), data.reset_at(), data.tz())
+    }
+
+    /// This server's next reset on a player's own clock, if they've set a
+    /// timezone
+    pub async fn local_next_reset(&self, guild_id: &str, user_id: &str) -> Option<DateTime<Tz>> {
+        let tz = self.timezone(guild_id, user_id).await?;
+        Some(self.next_guild_reset(guild_id).await.with_timezone(&tz))
+    }
+
+    /// Servers that haven't been reset since their latest reset time on their
+    /// own clock, so a missed tick or a DST gap only delays a reset
+    pub async fn guilds_due_for_reset(&self, now: DateTime<Utc>) -> Vec<String> {
+        let data = self.data_manager.data.read().await;
+        data.guilds
+            .iter()
+            .filter(|(guild_id, _)| guild_id.as_str() != crate::migrations::UNASSIGNED_GUILD)
+            .filter(|(_, guild)| guild.paused_since.is_none())
+            .filter(|(_, guild)| {
+                let scheduled = Self::last_reset(now, guild.reset_at(), guild.tz());
+                guild.last_reset_timestamp < scheduled.timestamp_millis() as u64
+            })
+            .map(|(guild_id, _)| guild_id.clone())
+            .collect()
+    }
+
+    /// When this server's daily summary next posts, if it has its own time
+    pub async fn next_guild_summary(&self, guild_id: &str) -> Option<DateTime<Utc>> {
+        let data = self.data_manager.guild(guild_id).await;
+        let at = data.summary_time?;
+        Some(Self::next_reset(Utc::now(), at, data.tz()))
+    }
+
+    /// Servers with their own summary time whose time, on their own clock, is
+    /// the minute of `now`. Each is marked as posted, so a second call in the
+    /// same minute returns nothing.
+    pub async fn guilds_due_for_summary(&self, now: DateTime<Utc>) -> Vec<String> {
+        use chrono::Timelike;
+        let now_millis = now.timestamp_millis() as u64;
+        let mut data = self.data_manager.data.write().await;
+        let due: Vec<String> = data
+            .guilds
+            .iter_mut()
+            .filter(|(guild_id, _)| guild_id.as_str() != crate::migrations::UNASSIGNED_GUILD)
+            .filter(|(_, guild)| guild.paused_since.is_none())
+            .filter_map(|(guild_id, guild)| {
+                let at = guild.summary_time?;
+                let local = now.with_timezone(&guild.tz());
+                (at.hour() == local.hour()
+                    && at.minute() == local.minute()
+                    && now_millis.saturating_sub(guild.last_summary_timestamp) >= 60_000)
+                    .then(|| {
+                        guild.last_summary_timestamp = now_millis;
+                        guild_id.clone()
+                    })
+            })
+            .collect();
+        drop(data);
+        if !due.is_empty() {
+            self.data_manager.save().await;
+        }
+        due
+    }
+
+    /// End the fishing day in the given servers: break streaks of anyone who
+    /// didn't cast and clear today's catches. Concurrent calls are ignored.
+    /// Returns the streaks that were broken.
+    pub async fn reset_daily_data(&self, guild_ids: &[String]) -> Vec<BrokenStreak> {
+        // Attempt to "lock" using AtomicBool
+        if self
+            .is_resetting
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .is_err()
+        {
+            tracing::warn!("⚠️ Reset already in progress, skipping duplicate call");
+            return Vec::new();
+        }
+
+        // RAII Guard using AtomicBool
+        struct ResetGuard {
+            flag: Arc<AtomicBool>,
+        }
+        impl Drop for ResetGuard {
+            fn drop(&mut self) {
+                self.flag.store(false, Ordering::SeqCst);
+            }
+        }
+        let _guard = ResetGuard {
+            flag: self.is_resetting.clone(),
+        };
+
+        tracing::info!("🔄 Resetting daily data...");
+        // Removed redundant post_daily_summary_http call as main.rs handles the order now.
+
+        let now_millis = chrono::Utc::now().timestamp_millis() as u64;
+
+        // Each server's closed day, for the journal
+        let mut closed_days = Vec::new();
+        let broken = {
+            let mut all = self.data_manager.data.write().await;
+            let mut broken = Vec::new();
+            for (guild_id, data) in all
+                .guilds
+                .iter_mut()
+                .filter(|(guild_id, _)| guild_ids.contains(guild_id))
+            {
+                // The day being closed on the server's calendar; a reset at
+                // midnight runs just after that day has ended
+                let today = data.date_of(now_millis.saturating_sub(60_000));
+                let blackout = data.is_blackout(&today);
+                if blackout {
+                    tracing::info!("🕯️ {} is a blackout day in {}", today, guild_id);
+                }
+                for (user_id, p_user) in data.persistent_users.iter_mut() {
+                    // Streaks are frozen, not broken, while on vacation or
+                    // on a blackout day
+                    let kept = blackout || p_user.on_vacation(&today);
+                    if !data.users.contains_key(user_id) && !kept {
+                        if p_user.streak > 0 {
+                            broken.push(BrokenStreak {
+                                guild_id: guild_id.clone(),
+                                user_id: user_id.clone(),
+                                streak: p_user.streak,
+                                roles: data
+                                    .streak_roles
+                                    .iter()
+                                    .filter(|r| r.streak <= p_user.streak)
+                                    .map(|r| r.role_id.clone())
+                                    .collect(),
+                            });
+                        }
+                        p_user.streak = 0;
+                    }
+                }
+                // Snoozes that ran through the day being closed are over
+                data.reminder_snoozes.retain(|_, until| *until > today);
+                closed_days.push((guild_id.clone(), today.clone()));
+                let mut participants: Vec<String> = data.users.keys().cloned().collect();
+                participants.sort_unstable();
+                data.daily_history.push(crate::data::DailyTotal {
+                    date: today,
+                    catches: data.daily_count,
+                    participants,
+                });
+                let excess = data
+                    .daily_history
+                    .len()
+                    .saturating_sub(crate::data::MAX_DAILY_HISTORY);
+                data.daily_history.drain(..excess);
+                data.daily_count = 0;
+                data.last_reset_timestamp = now_millis;
+                data.users.clear();
+                data.fortunes.clear();
+                // Unused tickets (e.g. the drawing was skipped) don't roll over
+                data.lottery_tickets.clear();
+                for team in data.teams.values_mut() {
+                    team.points_today = 0;
+                }
+                data.fish_of_the_day = Self::pick_fish_of_the_day();
+                if let Some(species) = data.featured_species() {
+                    tracing::info!("🌟 Fish of the Day in {}: {}", guild_id, species.name);
+                }
+            }
+            broken
+        };
+
+        self.data_manager.save().await;
+        #[cfg(feature = "redis")]
+        if let Some(cache) = &self.cache {
+            cache.clear_fished(guild_ids).await;
+        }
+        for (guild_id, date) in closed_days {
+            let users = broken
+                .iter()
+                .filter(|streak| streak.guild_id == guild_id)
+                .map(|streak| streak.user_id.clone())
+                .collect();
+            self.data_manager
+                .journal(JournalEvent::Reset {
+                    date,
+                    broken: HashMap::from([(guild_id, users)]),
+                })
+                .await;
+        }
+        self.data_manager.backup().await;
+
+        tracing::info!("✅ Daily data reset complete.");
+        broken
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::MemoryStorage;
+
+    async fn manager() -> FishingManager {
+        FishingManager::new(Arc::new(DataManager::open(MemoryStorage::default()).await))
+    }
+
+    #[tokio::test]
+    async fn booster_with_extra_cast_gets_two_casts() {
+        let fishing = manager().await;
+        fishing.data_manager.guild_mut("1").await.booster_perk =
+            Some(crate::data::BoosterPerk::ExtraCast);
+
+        let cast = || fishing.handle_fishing("1", "42".into(), "alice".into(), "pond", true);
+        assert!(cast().await.is_ok());
+        assert!(!fishing.out_of_casts("1", "42", true).await);
+        assert!(cast().await.is_ok());
+        assert!(fishing.out_of_casts("1", "42", true).await);
+        assert!(matches!(cast().await, Err(FishingError::AlreadyFished)));
+    }
+
+    #[tokio::test]
+    async fn non_booster_gets_one_cast() {
+        let fishing = manager().await;
+        fishing.data_manager.guild_mut("1").await.booster_perk =
+            Some(crate::data::BoosterPerk::ExtraCast);
+
+        assert!(fishing
+            .handle_fishing("1", "42".into(), "alice".into(), "pond", false)
+            .await
+            .is_ok());
+        assert!(fishing.out_of_casts("1", "42", false).await);
+    }
+
+    #[tokio::test]
+    async fn repair_keeps_a_streak_longer_than_history() {
+        let fishing = manager().await;
+        let (two_days_ago, yesterday) = {
+            let mut data = fishing.data_manager.guild_mut("1").await;
+            let today = data.local_now().date_naive();
+            let day = |n: i64| {
+                (today - chrono::Duration::days(n))
+                    .format("%Y-%m-%d")
+                    .to_string()
+            };
+            let mut p_user = crate::data::PersistentUserData::new("alice".to_string());
+            // From before history was kept: nothing in it proves the 400 days
+            p_user.set_streak(400);
+            p_user.last_fished_date = day(1);
+            data.persistent_users.insert("42".to_string(), p_user);
+            (day(2), day(1))
+        };
+
+        let (old, new) = fishing
+            .repair_streak("1", "7", "42", &two_days_ago)
+            .await
+            .unwrap();
+        assert_eq!((old, new), (400, 400));
+        let data = fishing.data_manager.guild("1").await;
+        assert_eq!(data.persistent_users["42"].last_fished_date, yesterday);
+    }
+
+    #[tokio::test]
+    async fn missed_tick_still_resets() {
+        let fishing = manager().await;
+        let at = |h, m| chrono::NaiveTime::from_hms_opt(h, m, 0).unwrap();
+        let utc = |s: &str| s.parse::<DateTime<Utc>>().unwrap();
+        {
+            let mut data = fishing.data_manager.guild_mut("1").await;
+            data.reset_time = Some(at(0, 0));
+            data.last_reset_timestamp = utc("2026-03-01T00:00:00Z").timestamp_millis() as u64;
+        }
+
+        // The 00:00 tick was missed; the next one still resets
+        let late = utc("2026-03-02T00:01:00Z");
+        assert_eq!(
+            fishing.guilds_due_for_reset(late).await,
+            vec!["1".to_string()]
+        );
+
+        fishing
+            .data_manager
+            .guild_mut("1")
+            .await
+            .last_reset_timestamp = late.timestamp_millis() as u64;
+        assert!(fishing
+            .guilds_due_for_reset(utc("2026-03-02T00:02:00Z"))
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn reset_in_a_dst_gap_happens_after_it() {
+        let fishing = manager().await;
+        let utc = |s: &str| s.parse::<DateTime<Utc>>().unwrap();
+        {
+            let mut data = fishing.data_manager.guild_mut("1").await;
+            // 02:30 doesn't exist in New York on 2026-03-08
+            data.reset_time = chrono::NaiveTime::from_hms_opt(2, 30, 0);
+            data.timezone = Some("America/New_York".to_string());
+            data.last_reset_timestamp = utc("2026-03-07T07:30:00Z").timestamp_millis() as u64;
+        }
+
+        // 01:59 EST, just before the clocks jump to 03:00 EDT
+        assert!(fishing
+            .guilds_due_for_reset(utc("2026-03-08T06:59:00Z"))
+            .await
+            .is_empty());
+        // 03:00 EDT
+        assert_eq!(
+            fishing
+                .guilds_due_for_reset(utc("2026-03-08T07:00:00Z"))
+                .await,
+            vec!["1".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn moving_the_reset_earlier_does_not_reset_again() {
+        let fishing = manager().await;
+        let now = Utc::now();
+        let later = (now + chrono::Duration::hours(1)).time();
+        let earlier = (now - chrono::Duration::hours(1)).time();
+        {
+            let mut data = fishing.data_manager.guild_mut("1").await;
+            data.reset_time = Some(later);
+            // Today's reset under the old time already ran
+            data.last_reset_timestamp =
+                FishingManager::last_reset(now, later, data.tz()).timestamp_millis() as u64;
+        }
+        assert!(fishing.guilds_due_for_reset(now).await.is_empty());
+
+        fishing.set_reset_time("1", earlier).await;
+        assert!(fishing.guilds_due_for_reset(Utc::now()).await.is_empty());
+
+        fishing
+            .set_guild_timezone("1", Some(chrono_tz::Tz::Pacific__Kiritimati))
+            .await;
+        assert!(fishing.guilds_due_for_reset(Utc::now()).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn thawing_the_pond_does_not_reset_at_once() {
+        let fishing = manager().await;
+        let now = Utc::now();
+        let last_fished = {
+            let mut data = fishing.data_manager.guild_mut("1").await;
+            let today = data.local_now().date_naive();
+            let day = |n: i64| {
+                (today - chrono::Duration::days(n))
+                    .format("%Y-%m-%d")
+                    .to_string()
+            };
+            // Frozen three days ago, the day after the last reset and catch
+            data.paused_since = Some(day(3));
+            data.last_reset_timestamp = (now - chrono::Duration::days(4)).timestamp_millis() as u64;
+            let mut p_user = crate::data::PersistentUserData::new("alice".to_string());
+            p_user.set_streak(5);
+            p_user.last_fished_date = day(4);
+            data.persistent_users.insert("42".to_string(), p_user);
+            day(1)
+        };
+
+        assert!(fishing.set_paused("1", false).await);
+        assert!(fishing.guilds_due_for_reset(Utc::now()).await.is_empty());
+        let data = fishing.data_manager.guild("1").await;
+        assert_eq!(data.persistent_users["42"].last_fished_date, last_fished);
+    }
+}
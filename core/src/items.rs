@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ItemKind {
+    /// Used up automatically on the next cast for better odds
+    Bait { luck: f64 },
+    /// Kept for bragging rights
+    Collectible,
+}
+
+#[derive(Debug)]
+pub struct Item {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub emoji: &'static str,
+    pub description: &'static str,
+    pub kind: ItemKind,
+}
+
+pub static ITEMS: &[Item] = &[
+    Item {
+        id: "worm_bait",
+        name: "Worm Bait",
+        emoji: "🪱",
+        description: "A little extra luck on your next cast",
+        kind: ItemKind::Bait { luck: 1.2 },
+    },
+    Item {
+        id: "golden_bait",
+        name: "Golden Bait",
+        emoji: "🪙",
+        description: "Rare fish can't resist it",
+        kind: ItemKind::Bait { luck: 1.5 },
+    },
+    Item {
+        id: "cosmic_lure",
+        name: "Cosmic Lure",
+        emoji: "🌠",
+        description: "Glows with stardust; doubles your luck for one cast",
+        kind: ItemKind::Bait { luck: 2.0 },
+    },
+    Item {
+        id: "coral_trinket",
+        name: "Coral Trinket",
+        emoji: "🪸",
+        description: "A pretty keepsake from distant reefs",
+        kind: ItemKind::Collectible,
+    },
+    Item {
+        id: "message_in_a_bottle",
+        name: "Message in a Bottle",
+        emoji: "🍾",
+        description: "Nobody can read the handwriting",
+        kind: ItemKind::Collectible,
+    },
+];
+
+pub fn find_item(id: &str) -> Option<&'static Item> {
+    ITEMS.iter().find(|i| i.id == id)
+}
+
+/// The strongest bait the user is carrying, if any
+pub fn best_bait(
+    inventory: &std::collections::HashMap<String, u64>,
+) -> Option<(&'static Item, f64)> {
+    inventory
+        .iter()
+        .filter(|(_, count)| **count > 0)
+        .filter_map(|(id, _)| find_item(id))
+        .filter_map(|item| match item.kind {
+            ItemKind::Bait { luck } => Some((item, luck)),
+            ItemKind::Collectible => None,
+        })
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+}
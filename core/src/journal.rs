@@ -0,0 +1,178 @@
+//! Append-only record of catches, resets, and settings changes.
+//!
+//! Every event is one JSON line, written as it happens and never rewritten,
+//! so a damaged or lost data file can be rebuilt with [`replay`] and every
+//! change can be audited after the fact. Replaying restores players'
+//! streaks, totals, and catch history plus the journaled settings; coins,
+//! items, and events are not journaled.
+
+use crate::data::{CatchRecord, FishingData, GuildData, PersistentUserData, UserReset};
+use crate::storage::StorageError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum JournalEvent {
+    /// A landed fish and the player's record right after it
+    #[serde(rename_all = "camelCase")]
+    Catch {
+        guild_id: String,
+        user_id: String,
+        username: String,
+        streak: u64,
+        total_catches: u64,
+        last_fished_date: String,
+        record: CatchRecord,
+    },
+    /// A server's daily reset, with the day it closed on the server's own
+    /// calendar and the players whose streaks broke (guild ID -> user IDs)
+    #[serde(rename_all = "camelCase")]
+    Reset {
+        date: String,
+        broken: HashMap<String, Vec<String>>,
+    },
+    /// New values of server settings, keyed by their saved field names
+    #[serde(rename_all = "camelCase")]
+    Config {
+        guild_id: String,
+        settings: serde_json::Map<String, serde_json::Value>,
+    },
+    /// A member's data was erased at their or an admin's request
+    #[serde(rename_all = "camelCase")]
+    Forget { guild_id: String, user_id: String },
+    /// An admin wiped part of a member's data with `/resetuser`
+    #[serde(rename_all = "camelCase")]
+    ResetUser {
+        guild_id: String,
+        user_id: String,
+        scope: UserReset,
+    },
+    /// An admin folded one member's record into another's with `/mergeusers`
+    #[serde(rename_all = "camelCase")]
+    MergeUsers {
+        guild_id: String,
+        from: String,
+        to: String,
+    },
+}
+
+/// One line of the journal
+#[derive(Debug, Serialize, Deserialize)]
+struct JournalEntry {
+    at: u64,
+    #[serde(flatten)]
+    event: JournalEvent,
+}
+
+/// Appends events to a JSON lines file
+pub struct Journal {
+    path: PathBuf,
+}
+
+impl Journal {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    pub async fn append(&self, event: JournalEvent) -> Result<(), StorageError> {
+        let mut line = serde_json::to_string(&JournalEntry {
+            at: chrono::Utc::now().timestamp_millis() as u64,
+            event,
+        })?;
+        line.push('\n');
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await?;
+        file.write_all(line.as_bytes()).await?;
+        Ok(())
+    }
+}
+
+/// Apply one event to `data`
+fn apply(data: &mut FishingData, event: JournalEvent) -> Result<(), StorageError> {
+    match event {
+        JournalEvent::Catch {
+            guild_id,
+            user_id,
+            username,
+            streak,
+            total_catches,
+            last_fished_date,
+            record,
+        } => {
+            let guild = data.guilds.entry(guild_id).or_default();
+            let p_user = guild
+                .persistent_users
+                .entry(user_id)
+                .or_insert_with(|| PersistentUserData::new(username.clone()));
+            p_user.username = username;
+            p_user.set_streak(streak);
+            p_user.total_catches = total_catches;
+            p_user.last_fished_date = last_fished_date;
+            p_user.record_catch(record);
+        }
+        JournalEvent::Reset { broken, .. } => {
+            for (guild_id, users) in broken {
+                if let Some(guild) = data.guilds.get_mut(&guild_id) {
+                    for user_id in users {
+                        if let Some(p_user) = guild.persistent_users.get_mut(&user_id) {
+                            p_user.streak = 0;
+                        }
+                    }
+                }
+            }
+        }
+        JournalEvent::Config { guild_id, settings } => {
+            let guild = data.guilds.entry(guild_id).or_default();
+            let mut value = serde_json::to_value(&*guild)?;
+            if let Some(fields) = value.as_object_mut() {
+                fields.extend(settings);
+            }
+            *guild = serde_json::from_value::<GuildData>(value)?;
+        }
+        JournalEvent::Forget { guild_id, user_id } => {
+            if let Some(guild) = data.guilds.get_mut(&guild_id) {
+                guild.forget_user(&user_id);
+            }
+        }
+        JournalEvent::ResetUser {
+            guild_id,
+            user_id,
+            scope,
+        } => {
+            if let Some(guild) = data.guilds.get_mut(&guild_id) {
+                guild.reset_user(&user_id, scope);
+            }
+        }
+        JournalEvent::MergeUsers { guild_id, from, to } => {
+            if let Some(guild) = data.guilds.get_mut(&guild_id) {
+                guild.merge_users(&from, &to);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Rebuild state from the journal at `path`. Lines that can't be read or
+/// applied (e.g. a torn final write) are skipped and counted.
+pub async fn replay(path: impl Into<PathBuf>) -> Result<(FishingData, usize), StorageError> {
+    let content = fs::read_to_string(path.into()).await?;
+    let mut data = FishingData::default();
+    let mut skipped = 0;
+    for line in content.lines().filter(|line| !line.trim().is_empty()) {
+        let applied = serde_json::from_str::<JournalEntry>(line)
+            .map_err(StorageError::from)
+            .and_then(|entry| apply(&mut data, entry.event));
+        if let Err(e) = applied {
+            tracing::warn!("⚠️ Skipping journal line: {}", e);
+            skipped += 1;
+        }
+    }
+    Ok((data, skipped))
+}
@@ -0,0 +1,148 @@
+//! Import of `fishing_data.json` files written by the original Node.js bot.
+//!
+//! The JavaScript version saved a single server's state with looser types:
+//! counters could come out as floats or strings, and the last fished day was
+//! sometimes a full ISO timestamp or a `Date.toDateString()` value. This
+//! reads those leniently and folds the players' streaks and totals into a
+//! [`GuildData`], leaving anything already there in place.
+
+use crate::data::{GuildData, PersistentUserData};
+use serde::{Deserialize, Deserializer};
+use std::collections::HashMap;
+
+/// A save file from the JavaScript bot
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LegacyData {
+    /// Server the file belonged to, if it was ever set up
+    pub guild_id: Option<String>,
+    #[serde(default)]
+    persistent_users: HashMap<String, LegacyUser>,
+    button_message_id: Option<String>,
+    button_channel_id: Option<String>,
+    tracked_role_id: Option<String>,
+    summary_channel_id: Option<String>,
+    ping_reminder_enabled: Option<bool>,
+    #[serde(default, deserialize_with = "lenient_u64")]
+    best_angler_streak: Option<u64>,
+    #[serde(default, deserialize_with = "lenient_u64")]
+    reminder_threshold: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LegacyUser {
+    #[serde(default)]
+    username: String,
+    #[serde(default, deserialize_with = "lenient_u64")]
+    streak: Option<u64>,
+    #[serde(default)]
+    last_fished_date: Option<String>,
+    #[serde(default, deserialize_with = "lenient_u64")]
+    total_catches: Option<u64>,
+}
+
+/// What an import changed
+#[derive(Debug, Default)]
+pub struct ImportSummary {
+    pub added: usize,
+    pub merged: usize,
+}
+
+/// Accept `3`, `3.0`, or `"3"`
+fn lenient_u64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<u64>, D::Error> {
+    let value = Option::<serde_json::Value>::deserialize(deserializer)?;
+    Ok(match value {
+        Some(serde_json::Value::Number(n)) => n
+            .as_u64()
+            .or_else(|| n.as_f64().filter(|f| *f >= 0.0).map(|f| f as u64)),
+        Some(serde_json::Value::String(s)) => s.trim().parse().ok(),
+        _ => None,
+    })
+}
+
+/// Bring a stored day to `YYYY-MM-DD`, or `None` if it can't be read
+fn normalize_date(raw: &str) -> Option<String> {
+    let raw = raw.trim();
+    if raw.is_empty() {
+        return None;
+    }
+    if let Ok(date) = chrono::NaiveDate::parse_from_str(raw, "%Y-%m-%d") {
+        return Some(date.format("%Y-%m-%d").to_string());
+    }
+    if let Ok(at) = chrono::DateTime::parse_from_rfc3339(raw) {
+        return Some(at.format("%Y-%m-%d").to_string());
+    }
+    chrono::NaiveDate::parse_from_str(raw, "%a %b %d %Y")
+        .ok()
+        .map(|date| date.format("%Y-%m-%d").to_string())
+}
+
+impl LegacyData {
+    pub fn parse(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Fold this file into `guild`. New players are added as they were; for
+    /// players already present the higher catch total is kept, and the streak
+    /// comes from whichever record fished more recently. Channels and roles
+    /// are only filled in where `guild` has none, and reminder settings are
+    /// only taken over by a server with no players yet.
+    pub fn merge_into(self, guild: &mut GuildData) -> ImportSummary {
+        let mut summary = ImportSummary::default();
+        let fresh = guild.persistent_users.is_empty();
+
+        for (user_id, legacy) in self.persistent_users {
+            let last_fished = legacy
+                .last_fished_date
+                .as_deref()
+                .and_then(normalize_date)
+                .unwrap_or_default();
+            let streak = legacy.streak.unwrap_or(0);
+            let total = legacy.total_catches.unwrap_or(0);
+
+            match guild.persistent_users.get_mut(&user_id) {
+                Some(p_user) => {
+                    p_user.total_catches = p_user.total_catches.max(total);
+                    if last_fished > p_user.last_fished_date {
+                        p_user.streak = streak;
+                        p_user.last_fished_date = last_fished;
+                    }
+                    summary.merged += 1;
+                }
+                None => {
+                    let mut p_user = PersistentUserData::new(legacy.username);
+                    p_user.streak = streak;
+                    p_user.last_fished_date = last_fished;
+                    p_user.total_catches = total;
+                    guild.persistent_users.insert(user_id, p_user);
+                    summary.added += 1;
+                }
+            }
+        }
+
+        if guild.button_channel_id.is_none() {
+            guild.button_channel_id = self.button_channel_id;
+            guild.button_message_id = self.button_message_id;
+        }
+        if guild.tracked_role_ids.is_empty() {
+            guild.tracked_role_ids.extend(self.tracked_role_id);
+        }
+        if guild.summary_channel_id.is_none() {
+            guild.summary_channel_id = self.summary_channel_id;
+        }
+        if fresh {
+            if let Some(enabled) = self.ping_reminder_enabled {
+                guild.ping_reminder_enabled = enabled;
+            }
+            if let Some(streak) = self.best_angler_streak {
+                guild.best_angler_streak = streak;
+            }
+            if let Some(days) = self.reminder_threshold.filter(|days| *days > 0) {
+                guild.reminder_threshold = days;
+            }
+        }
+
+        summary
+    }
+}
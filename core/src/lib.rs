@@ -0,0 +1,55 @@
+//! The game engine behind Stardust Pond: daily casts, streaks, coins,
+//! locations, items, titles, gifts, duels, and the travelling merchant.
+//!
+//! Nothing in here talks to Discord. A frontend owns a [`FishingManager`],
+//! calls it in response to user input, and renders the returned outcomes
+//! however it likes. Every call names the server (guild) it's for, and each
+//! server gets its own [`GuildData`]. State lives in a [`DataManager`]
+//! backed by any [`Storage`] implementation; [`JsonFileStorage`] is the default and
+//! [`MemoryStorage`] keeps everything in memory for tests.
+//!
+//! ```no_run
+//! use nightmare_fishing_core::{DataManager, FishingManager, JsonFileStorage};
+//! use std::sync::Arc;
+//!
+//! # async fn run() {
+//! let data_manager = Arc::new(DataManager::open(JsonFileStorage::new("fishing_data.json")).await);
+//! let fishing = FishingManager::new(data_manager);
+//!
+//! match fishing.handle_fishing("1234", "42".into(), "alice".into(), "pond", false).await {
+//!     Ok(outcome) => println!("caught a {} ({} day streak)", outcome.species.name, outcome.streak),
+//!     Err(e) => println!("no cast: {}", e),
+//! }
+//! # }
+//! ```
+
+pub mod analytics;
+pub mod archive;
+pub mod audit;
+#[cfg(feature = "redis")]
+pub mod cache;
+#[cfg(feature = "encryption")]
+pub mod crypt;
+pub mod data;
+pub mod diff;
+pub mod fish;
+pub mod fortune;
+pub mod game;
+pub mod items;
+pub mod journal;
+pub mod legacy;
+pub mod migrations;
+#[cfg(feature = "s3")]
+pub mod s3;
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
+pub mod sql;
+pub mod storage;
+pub mod streak_csv;
+pub mod titles;
+pub mod wheel;
+
+pub use data::{DataManager, FishingData, GuildData};
+pub use game::{CatchOutcome, FishingError, FishingManager};
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
+pub use sql::SqlStorage;
+pub use storage::{BackupInfo, JsonFileStorage, MemoryStorage, Storage};
@@ -0,0 +1,109 @@
+//! Upgrades saved data from older layouts before it's deserialized.
+//!
+//! Every save records the [`SCHEMA_VERSION`] it was written with. On load the
+//! raw JSON is walked through each migration between the saved version and the
+//! current one, so a layout change rewrites old fields instead of letting serde
+//! quietly drop them. When the data model changes shape, bump
+//! [`SCHEMA_VERSION`] and append a step to `MIGRATIONS`.
+
+use serde_json::{Map, Value};
+
+/// Layout version written by this build
+pub const SCHEMA_VERSION: u32 = 3;
+
+/// Key for state saved before the bot knew its server; the first server to
+/// use the bot adopts it
+pub(crate) const UNASSIGNED_GUILD: &str = "";
+
+/// One step that upgrades saved state by a single version
+type Migration = fn(Map<String, Value>) -> Map<String, Value>;
+
+/// `MIGRATIONS[i]` upgrades version `i + 1` to `i + 2`
+const MIGRATIONS: &[Migration] = &[single_to_multi_guild, tracked_role_list];
+
+#[derive(Debug)]
+pub enum MigrationError {
+    /// Saved by a newer build; loading it here could lose fields on the next save
+    NewerVersion(u32),
+    NotAnObject,
+}
+
+impl std::fmt::Display for MigrationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MigrationError::NewerVersion(version) => write!(
+                f,
+                "data has schema version {} but this build only understands up to {}",
+                version, SCHEMA_VERSION
+            ),
+            MigrationError::NotAnObject => write!(f, "saved data is not a JSON object"),
+        }
+    }
+}
+
+impl std::error::Error for MigrationError {}
+
+/// The version `data` was saved with. Files from before versioning are told
+/// apart by their layout.
+fn saved_version(data: &Map<String, Value>) -> u32 {
+    match data.get("schemaVersion").and_then(Value::as_u64) {
+        Some(version) => version as u32,
+        None if data.contains_key("guilds") => 2,
+        None => 1,
+    }
+}
+
+/// Upgrade raw saved data to [`SCHEMA_VERSION`]
+pub fn migrate(data: Value) -> Result<Value, MigrationError> {
+    let Value::Object(mut data) = data else {
+        return Err(MigrationError::NotAnObject);
+    };
+    let from = saved_version(&data);
+    if from > SCHEMA_VERSION {
+        return Err(MigrationError::NewerVersion(from));
+    }
+
+    for (i, step) in MIGRATIONS
+        .iter()
+        .enumerate()
+        .skip((from as usize).saturating_sub(1))
+    {
+        data = step(data);
+        tracing::info!(
+            "⬆️ Migrated saved data from schema v{} to v{}",
+            i + 1,
+            i + 2
+        );
+    }
+    data.insert("schemaVersion".to_string(), Value::from(SCHEMA_VERSION));
+    Ok(Value::Object(data))
+}
+
+/// v1 → v2: one server's state at the top level becomes one entry in `guilds`
+fn single_to_multi_guild(mut data: Map<String, Value>) -> Map<String, Value> {
+    let guild_id = match data.remove("guildId") {
+        Some(Value::String(id)) => id,
+        _ => UNASSIGNED_GUILD.to_string(),
+    };
+    data.remove("schemaVersion");
+    let mut guilds = Map::new();
+    guilds.insert(guild_id, Value::Object(data));
+
+    let mut upgraded = Map::new();
+    upgraded.insert("guilds".to_string(), Value::Object(guilds));
+    upgraded
+}
+
+/// v2 → v3: each server's single `trackedRoleId` becomes a `trackedRoleIds` list
+fn tracked_role_list(mut data: Map<String, Value>) -> Map<String, Value> {
+    if let Some(Value::Object(guilds)) = data.get_mut("guilds") {
+        for guild in guilds.values_mut().filter_map(Value::as_object_mut) {
+            let roles = match guild.remove("trackedRoleId") {
+                Some(Value::String(id)) => vec![Value::String(id)],
+                _ => Vec::new(),
+            };
+            guild.entry("trackedRoleIds").or_insert(Value::Array(roles));
+        }
+    }
+    data
+}
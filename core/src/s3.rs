@@ -0,0 +1,132 @@
+//! Off-host backups to any S3-compatible object store (AWS S3, MinIO,
+//! Cloudflare R2, Backblaze B2, ...), so a dead disk doesn't take every
+//! streak with it. Requests are signed with AWS Signature Version 4 and use
+//! path-style URLs, which every provider accepts.
+
+use crate::storage::{sha256_hex, StorageError};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// Where backups are uploaded, read from `BACKUP_S3_*` environment variables
+pub struct S3Backup {
+    client: reqwest::Client,
+    endpoint: reqwest::Url,
+    bucket: String,
+    region: String,
+    access_key: String,
+    secret_key: String,
+    /// Prepended to every object key, e.g. `fishing-backups/`
+    prefix: String,
+    #[cfg(feature = "encryption")]
+    key: Option<crate::crypt::DataKey>,
+}
+
+fn hmac(key: &[u8], message: &str) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts any key length");
+    mac.update(message.as_bytes());
+    mac.finalize().into_bytes().to_vec()
+}
+
+impl S3Backup {
+    /// Configured when `BACKUP_S3_ENDPOINT`, `BACKUP_S3_BUCKET`,
+    /// `BACKUP_S3_ACCESS_KEY`, and `BACKUP_S3_SECRET_KEY` are all set.
+    /// `BACKUP_S3_REGION` defaults to `us-east-1` and `BACKUP_S3_PREFIX` to
+    /// `fishing-backups/`.
+    pub fn from_env() -> Result<Option<Self>, StorageError> {
+        let var = |name: &str| std::env::var(name).ok().filter(|v| !v.is_empty());
+        let (Some(endpoint), Some(bucket), Some(access_key), Some(secret_key)) = (
+            var("BACKUP_S3_ENDPOINT"),
+            var("BACKUP_S3_BUCKET"),
+            var("BACKUP_S3_ACCESS_KEY"),
+            var("BACKUP_S3_SECRET_KEY"),
+        ) else {
+            return Ok(None);
+        };
+
+        Ok(Some(Self {
+            client: reqwest::Client::new(),
+            endpoint: reqwest::Url::parse(&endpoint)?,
+            bucket,
+            region: var("BACKUP_S3_REGION").unwrap_or_else(|| "us-east-1".to_string()),
+            access_key,
+            secret_key,
+            prefix: var("BACKUP_S3_PREFIX").unwrap_or_else(|| "fishing-backups/".to_string()),
+            #[cfg(feature = "encryption")]
+            key: None,
+        }))
+    }
+
+    /// Encrypt uploads with `key`, like the local data file
+    #[cfg(feature = "encryption")]
+    pub fn with_encryption(mut self, key: crate::crypt::DataKey) -> Self {
+        self.key = Some(key);
+        self
+    }
+
+    /// Upload `body` as `<prefix><name>`
+    pub async fn upload(&self, name: &str, body: Vec<u8>) -> Result<(), StorageError> {
+        #[cfg(feature = "encryption")]
+        let body = match &self.key {
+            Some(key) => key.encrypt(&body)?,
+            None => body,
+        };
+        let host = match (self.endpoint.host_str(), self.endpoint.port()) {
+            (Some(host), Some(port)) => format!("{}:{}", host, port),
+            (Some(host), None) => host.to_string(),
+            (None, _) => return Err("BACKUP_S3_ENDPOINT has no host".into()),
+        };
+        let path = format!(
+            "{}/{}/{}{}",
+            self.endpoint.path().trim_end_matches('/'),
+            self.bucket,
+            self.prefix,
+            name
+        );
+
+        let now = chrono::Utc::now();
+        let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+        let date = now.format("%Y%m%d").to_string();
+        let payload_hash = sha256_hex(&body);
+
+        let signed_headers = "host;x-amz-content-sha256;x-amz-date";
+        let canonical_request = format!(
+            "PUT\n{}\n\nhost:{}\nx-amz-content-sha256:{}\nx-amz-date:{}\n\n{}\n{}",
+            path, host, payload_hash, amz_date, signed_headers, payload_hash
+        );
+        let scope = format!("{}/{}/s3/aws4_request", date, self.region);
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+            amz_date,
+            scope,
+            sha256_hex(canonical_request.as_bytes())
+        );
+        let key = hmac(format!("AWS4{}", self.secret_key).as_bytes(), &date);
+        let key = hmac(&key, &self.region);
+        let key = hmac(&key, "s3");
+        let key = hmac(&key, "aws4_request");
+        let signature = hex::encode(hmac(&key, &string_to_sign));
+
+        let mut url = self.endpoint.clone();
+        url.set_path(&path);
+        let response = self
+            .client
+            .put(url)
+            .header("x-amz-date", amz_date)
+            .header("x-amz-content-sha256", payload_hash)
+            .header(
+                "authorization",
+                format!(
+                    "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+                    self.access_key, scope, signed_headers, signature
+                ),
+            )
+            .body(body)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(format!("S3 upload failed with {}", response.status()).into());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,639 @@
+use crate::data::{CatchRecord, FishingData};
+use async_trait::async_trait;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+
+pub type StorageError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Backups kept unless a storage is told otherwise
+pub const DEFAULT_KEEP_BACKUPS: usize = 5;
+
+/// A backup that was just written
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackupInfo {
+    /// The name [`list_backups`](Storage::list_backups) reports it under
+    pub name: String,
+    /// Size on disk, in bytes
+    pub bytes: u64,
+}
+
+/// Where [`DataManager`](crate::data::DataManager) persists game state.
+/// Implement this to keep data somewhere other than a JSON file.
+#[async_trait]
+pub trait Storage: Send + Sync {
+    /// Read the saved state, or `None` if nothing has been saved yet
+    async fn load(&self) -> Result<Option<FishingData>, StorageError>;
+
+    /// Persist the full state
+    async fn save(&self, data: &FishingData) -> Result<(), StorageError>;
+
+    /// Size of the saved state in bytes, or `None` if it can't be told
+    async fn size(&self) -> Result<Option<u64>, StorageError> {
+        Ok(None)
+    }
+
+    /// Snapshot the saved state before the daily reset, returning the new
+    /// backup, or `None` if nothing was written. Older backups are left in
+    /// place until [`prune_backups`](Storage::prune_backups).
+    async fn backup(&self) -> Result<Option<BackupInfo>, StorageError> {
+        Ok(None)
+    }
+
+    /// Delete a new backup that failed its checks, so it's never restored
+    async fn discard_backup(&self, _name: &str) -> Result<(), StorageError> {
+        Ok(())
+    }
+
+    /// Drop the oldest backups beyond the number kept, once a new one has
+    /// been verified
+    async fn prune_backups(&self) -> Result<(), StorageError> {
+        Ok(())
+    }
+
+    /// Names of the backups [`load_backup`](Storage::load_backup) can read,
+    /// newest first
+    async fn list_backups(&self) -> Result<Vec<String>, StorageError> {
+        Ok(Vec::new())
+    }
+
+    /// Read the state saved in one backup
+    async fn load_backup(&self, _name: &str) -> Result<FishingData, StorageError> {
+        Err("this storage can't restore backups".into())
+    }
+
+    /// Keep a copy of saved state that failed to load, before it's replaced
+    /// by a recovered backup. By default nothing is kept.
+    async fn set_aside_unreadable(&self) -> Result<(), StorageError> {
+        Ok(())
+    }
+
+    /// Keep catches that no longer fit in a player's history.
+    /// By default they are simply dropped.
+    async fn archive_catches(
+        &self,
+        _guild_id: &str,
+        _user_id: &str,
+        _records: &[CatchRecord],
+    ) -> Result<(), StorageError> {
+        Ok(())
+    }
+}
+
+/// The original storage: one pretty-printed JSON file plus rotating backups.
+/// Archived catches are appended as JSON lines to `catch_archive.jsonl`.
+/// The data file and each backup get a `.sha256` file next to them, and a
+/// file that doesn't match its checksum is refused on load. With the
+/// `encryption` feature both can be encrypted at rest.
+///
+/// With [`with_diffs`](JsonFileStorage::with_diffs), every save between two
+/// full backups also appends what changed to the newer backup's
+/// `.diffs.jsonl` log, so any save since can be restored.
+///
+/// With the `msgpack` feature, [`with_messagepack`](JsonFileStorage::with_messagepack)
+/// writes the data file and backups as MessagePack instead, which is smaller
+/// and quicker to save on big servers. File names stay the same, and either
+/// format is read back whichever one is being written.
+pub struct JsonFileStorage {
+    file_path: PathBuf,
+    backup_dir: PathBuf,
+    keep_backups: usize,
+    archive_path: PathBuf,
+    #[cfg(feature = "encryption")]
+    key: Option<crate::crypt::DataKey>,
+    /// `Some` when saves are logged; holds `None` until the first full backup
+    diffs: Option<Mutex<Option<DiffChain>>>,
+    #[cfg(feature = "msgpack")]
+    messagepack: bool,
+}
+
+/// Where incremental backups are being written
+struct DiffChain {
+    /// The full backup the current diff log builds on
+    base: PathBuf,
+    /// State as of the last logged save
+    last: serde_json::Value,
+}
+
+/// One line of a diff log
+#[derive(serde::Serialize, serde::Deserialize)]
+struct DiffEntry {
+    at: u64,
+    patch: serde_json::Value,
+}
+
+/// One line of the catch archive
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ArchivedCatch<'a> {
+    guild_id: &'a str,
+    user_id: &'a str,
+    #[serde(flatten)]
+    record: &'a CatchRecord,
+}
+
+pub(crate) fn sha256_hex(bytes: &[u8]) -> String {
+    use sha2::{Digest, Sha256};
+    hex::encode(Sha256::digest(bytes))
+}
+
+/// Where the checksum of the JSON file at `path` is kept
+fn checksum_path(path: &Path) -> PathBuf {
+    path.with_extension("json.sha256")
+}
+
+/// Where the saves made after the full backup at `path` are logged
+fn diff_log_path(path: &Path) -> PathBuf {
+    path.with_extension("json.diffs.jsonl")
+}
+
+/// Read a JSON file, refusing it if it doesn't match its checksum. Files
+/// saved before checksums were written have none to check.
+async fn read_verified(path: &Path) -> Result<Vec<u8>, StorageError> {
+    let content = fs::read(path).await?;
+    match fs::read_to_string(checksum_path(path)).await {
+        Ok(expected) if sha256_hex(&content) != expected.trim() => Err(format!(
+            "{} doesn't match its checksum; it may be truncated or corrupt",
+            path.display()
+        )
+        .into()),
+        _ => Ok(content),
+    }
+}
+
+/// Parse a saved state in either format. JSON always starts with an object
+/// (maybe after whitespace), which MessagePack never does.
+fn decode<T: serde::de::DeserializeOwned>(bytes: &[u8]) -> Result<T, StorageError> {
+    match bytes.iter().find(|b| !b.is_ascii_whitespace()) {
+        Some(b'{') | None => Ok(serde_json::from_slice(bytes)?),
+        #[cfg(feature = "msgpack")]
+        Some(_) => Ok(rmp_serde::from_slice(bytes)?),
+        #[cfg(not(feature = "msgpack"))]
+        Some(_) => Err("saved data isn't JSON; was it written with the msgpack feature?".into()),
+    }
+}
+
+impl JsonFileStorage {
+    /// Backups go in a `backups` directory next to the working directory
+    pub fn new(file_path: impl Into<PathBuf>) -> Self {
+        let file_path = file_path.into();
+        Self {
+            archive_path: file_path.with_file_name("catch_archive.jsonl"),
+            file_path,
+            backup_dir: PathBuf::from("backups"),
+            keep_backups: DEFAULT_KEEP_BACKUPS,
+            #[cfg(feature = "encryption")]
+            key: None,
+            diffs: None,
+            #[cfg(feature = "msgpack")]
+            messagepack: false,
+        }
+    }
+
+    /// Log every save as a diff against the newest full backup
+    pub fn with_diffs(mut self) -> Self {
+        self.diffs = Some(Mutex::new(None));
+        self
+    }
+
+    /// Put backups in `dir` and keep only the newest `keep` (at least one)
+    pub fn with_backups(mut self, dir: impl Into<PathBuf>, keep: usize) -> Self {
+        self.backup_dir = dir.into();
+        self.keep_backups = keep.max(1);
+        self
+    }
+
+    /// Encrypt the data file and backups with `key`
+    #[cfg(feature = "encryption")]
+    pub fn with_encryption(mut self, key: crate::crypt::DataKey) -> Self {
+        self.key = Some(key);
+        self
+    }
+
+    /// Save as MessagePack rather than pretty-printed JSON
+    #[cfg(feature = "msgpack")]
+    pub fn with_messagepack(mut self) -> Self {
+        self.messagepack = true;
+        self
+    }
+
+    /// `data` in the format being written
+    fn encode(&self, data: &FishingData) -> Result<Vec<u8>, StorageError> {
+        #[cfg(feature = "msgpack")]
+        if self.messagepack {
+            // Field names are kept so migrations can read the saved layout
+            return Ok(rmp_serde::to_vec_named(data)?);
+        }
+        Ok(serde_json::to_vec_pretty(data)?)
+    }
+
+    /// What gets written to disk for `encoded`
+    fn seal(&self, encoded: Vec<u8>) -> Result<Vec<u8>, StorageError> {
+        #[cfg(feature = "encryption")]
+        if let Some(key) = &self.key {
+            return key.encrypt(&encoded);
+        }
+        Ok(encoded)
+    }
+
+    /// Decrypt bytes read from disk, if they were encrypted
+    fn unseal_bytes(&self, bytes: Vec<u8>) -> Result<Vec<u8>, StorageError> {
+        #[cfg(feature = "encryption")]
+        if let Some(key) = &self.key {
+            return key.decrypt(bytes);
+        }
+        Ok(bytes)
+    }
+
+    /// Parse bytes read from disk
+    fn unseal(&self, bytes: Vec<u8>) -> Result<FishingData, StorageError> {
+        decode(&self.unseal_bytes(bytes)?)
+    }
+
+    /// Append the change from the last logged save to `data`, starting a
+    /// chain with a full backup if there is none yet
+    async fn log_diff(
+        &self,
+        chain: &Mutex<Option<DiffChain>>,
+        data: &FishingData,
+    ) -> Result<(), StorageError> {
+        let value = serde_json::to_value(data)?;
+        let mut chain = chain.lock().await;
+        let Some(current) = chain.as_mut() else {
+            drop(chain);
+            // The file was just saved, so the new backup is the chain's start
+            return self.backup().await.map(|_| ());
+        };
+        let Some(patch) = crate::diff::diff(&current.last, &value) else {
+            return Ok(());
+        };
+
+        let entry = serde_json::to_vec(&DiffEntry {
+            at: chrono::Utc::now().timestamp_millis() as u64,
+            patch,
+        })?;
+        let mut line = self.encode_line(entry)?;
+        line.push('\n');
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(diff_log_path(&current.base))
+            .await?;
+        file.write_all(line.as_bytes()).await?;
+        current.last = value;
+        Ok(())
+    }
+
+    /// A diff log line: the JSON itself, or hex of it sealed when encrypting
+    fn encode_line(&self, entry: Vec<u8>) -> Result<String, StorageError> {
+        #[cfg(feature = "encryption")]
+        if self.key.is_some() {
+            return Ok(hex::encode(self.seal(entry)?));
+        }
+        Ok(String::from_utf8(entry)?)
+    }
+
+    fn decode_line(&self, line: &str) -> Result<DiffEntry, StorageError> {
+        if line.starts_with('{') {
+            return Ok(serde_json::from_str(line)?);
+        }
+        Ok(serde_json::from_slice(
+            &self.unseal_bytes(hex::decode(line)?)?,
+        )?)
+    }
+
+    /// The saves logged after the full backup at `path`, oldest first
+    async fn read_diffs(&self, path: &Path) -> Result<Vec<DiffEntry>, StorageError> {
+        let content = match fs::read_to_string(diff_log_path(path)).await {
+            Ok(content) => content,
+            Err(_) => return Ok(Vec::new()),
+        };
+        let mut entries = Vec::new();
+        for line in content.lines().filter(|line| !line.trim().is_empty()) {
+            match self.decode_line(line) {
+                Ok(entry) => entries.push(entry),
+                // Later saves build on this one, so they can't be used either
+                Err(e) => {
+                    tracing::warn!("⚠️ Diff log of {} ends early: {}", path.display(), e);
+                    break;
+                }
+            }
+        }
+        Ok(entries)
+    }
+}
+
+#[async_trait]
+impl Storage for JsonFileStorage {
+    async fn load(&self) -> Result<Option<FishingData>, StorageError> {
+        if fs::metadata(&self.file_path).await.is_err() {
+            return Ok(None);
+        }
+        let error = match read_verified(&self.file_path).await {
+            Ok(content) => return Ok(Some(self.unseal(content)?)),
+            Err(e) => e,
+        };
+
+        // Killed between writing the checksum and renaming the data into place
+        let temp_path = self.file_path.with_extension("json.tmp");
+        if let (Ok(pending), Ok(expected)) = (
+            fs::read(&temp_path).await,
+            fs::read_to_string(checksum_path(&self.file_path)).await,
+        ) {
+            if sha256_hex(&pending) == expected.trim() {
+                tracing::warn!("♻️ Finishing a save that was interrupted");
+                let data = self.unseal(pending)?;
+                fs::rename(&temp_path, &self.file_path).await?;
+                return Ok(Some(data));
+            }
+        }
+        Err(error)
+    }
+
+    /// Atomic Save: Write to a temp file then rename it.
+    /// This prevents corruption if the process is killed mid-write.
+    /// The checksum is swapped in first, so a crash in between leaves the
+    /// temp file to finish the save on the next load.
+    async fn save(&self, data: &FishingData) -> Result<(), StorageError> {
+        let bytes = self.seal(self.encode(data)?)?;
+        let checksum = sha256_hex(&bytes);
+        let temp_path = self.file_path.with_extension("json.tmp");
+        fs::write(&temp_path, bytes).await?;
+        let checksum_file = checksum_path(&self.file_path);
+        let checksum_temp = checksum_file.with_extension("sha256.tmp");
+        fs::write(&checksum_temp, checksum).await?;
+        fs::rename(&checksum_temp, &checksum_file).await?;
+        fs::rename(&temp_path, &self.file_path).await?;
+
+        if let Some(chain) = &self.diffs {
+            // The save itself went through; a missed diff only thins the history
+            if let Err(e) = self.log_diff(chain, data).await {
+                tracing::error!("❌ Error logging incremental backup: {}", e);
+            }
+        }
+        Ok(())
+    }
+
+    async fn size(&self) -> Result<Option<u64>, StorageError> {
+        match fs::metadata(&self.file_path).await {
+            Ok(metadata) => Ok(Some(metadata.len())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn backup(&self) -> Result<Option<BackupInfo>, StorageError> {
+        if fs::metadata(&self.file_path).await.is_err() {
+            return Ok(None);
+        }
+        // A corrupt file must not push a good backup out of rotation
+        let content = read_verified(&self.file_path).await?;
+        // Data saved before encryption was switched on
+        #[cfg(feature = "encryption")]
+        let content = match &self.key {
+            Some(key) if !crate::crypt::is_encrypted(&content) => key.encrypt(&content)?,
+            _ => content,
+        };
+        fs::create_dir_all(&self.backup_dir).await?;
+
+        // Never reuse a name, so a retry can't land on the attempt before it
+        let timestamp = chrono::Local::now().format("%Y-%m-%dT%H-%M-%S-%3f");
+        let mut name = format!("fishing_data_{}.json", timestamp);
+        for retry in 1.. {
+            if fs::metadata(self.backup_dir.join(&name)).await.is_err() {
+                break;
+            }
+            name = format!("fishing_data_{}_{}.json", timestamp, retry);
+        }
+        let backup_path = self.backup_dir.join(&name);
+
+        // Written under a temporary name and read back before it counts, so
+        // a half-written backup never sits in the rotation
+        let temp_path = backup_path.with_extension("json.tmp");
+        let written = async {
+            fs::write(&temp_path, &content).await?;
+            fs::write(checksum_path(&temp_path), sha256_hex(&content)).await?;
+            let read_back = read_verified(&temp_path).await?;
+            decode::<serde_json::Value>(&self.unseal_bytes(read_back)?)
+        }
+        .await;
+        let state = match written {
+            Ok(state) => state,
+            Err(e) => {
+                let _ = fs::remove_file(&temp_path).await;
+                let _ = fs::remove_file(checksum_path(&temp_path)).await;
+                return Err(e);
+            }
+        };
+        fs::rename(checksum_path(&temp_path), checksum_path(&backup_path)).await?;
+        fs::rename(&temp_path, &backup_path).await?;
+        let info = BackupInfo {
+            name,
+            bytes: content.len() as u64,
+        };
+
+        // Saves from now on are logged against this backup
+        if let Some(chain) = &self.diffs {
+            *chain.lock().await = Some(DiffChain {
+                base: backup_path,
+                last: state,
+            });
+        }
+        Ok(Some(info))
+    }
+
+    async fn discard_backup(&self, name: &str) -> Result<(), StorageError> {
+        if name.contains(['/', '\\', '@']) || name.starts_with('.') || !name.ends_with(".json") {
+            return Err(format!("not a backup name: {}", name).into());
+        }
+        let path = self.backup_dir.join(name);
+        // Saves must not be logged against a backup that's gone
+        if let Some(chain) = &self.diffs {
+            let mut chain = chain.lock().await;
+            if chain.as_ref().is_some_and(|chain| chain.base == path) {
+                *chain = None;
+            }
+        }
+        fs::remove_file(&path).await?;
+        let _ = fs::remove_file(checksum_path(&path)).await;
+        let _ = fs::remove_file(diff_log_path(&path)).await;
+        tracing::warn!("🗑️ Discarded backup {}", name);
+        Ok(())
+    }
+
+    async fn prune_backups(&self) -> Result<(), StorageError> {
+        let mut backups = Vec::new();
+        let mut entries = fs::read_dir(&self.backup_dir).await?;
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            if entry.path().extension().is_some_and(|ext| ext == "json") {
+                if let Ok(metadata) = entry.metadata().await {
+                    if let Ok(modified) = metadata.modified() {
+                        backups.push((entry.path(), modified));
+                    }
+                }
+            }
+        }
+
+        // Oldest first, so only the newest `keep_backups` remain
+        backups.sort_by_key(|b| b.1);
+        let excess = backups.len().saturating_sub(self.keep_backups);
+        for (oldest, _) in backups.drain(..excess) {
+            let _ = fs::remove_file(&oldest).await;
+            let _ = fs::remove_file(checksum_path(&oldest)).await;
+            let _ = fs::remove_file(diff_log_path(&oldest)).await;
+        }
+        Ok(())
+    }
+
+    async fn list_backups(&self) -> Result<Vec<String>, StorageError> {
+        let mut backups = Vec::new();
+        let mut entries = match fs::read_dir(&self.backup_dir).await {
+            Ok(entries) => entries,
+            Err(_) => return Ok(backups),
+        };
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            if entry.path().extension().is_some_and(|ext| ext == "json") {
+                let name = entry.file_name().to_string_lossy().into_owned();
+                // The latest logged save restores as `<backup>@<ms>`
+                if let Some(last) = self.read_diffs(&entry.path()).await?.last() {
+                    backups.push(format!("{}@{}", name, last.at));
+                }
+                backups.push(name);
+            }
+        }
+        // Names carry their timestamp, so they sort by age
+        backups.sort_unstable_by(|a, b| b.cmp(a));
+        Ok(backups)
+    }
+
+    /// `name` is a full backup, or `<backup>@<ms>` for that backup with the
+    /// logged saves up to that time applied
+    async fn load_backup(&self, name: &str) -> Result<FishingData, StorageError> {
+        let (file, until) = match name.split_once('@') {
+            Some((file, at)) => (file, Some(at.parse::<u64>()?)),
+            None => (name, None),
+        };
+        // Only plain file names inside the backup directory
+        if file.contains(['/', '\\']) || file.starts_with('.') || !file.ends_with(".json") {
+            return Err(format!("not a backup name: {}", name).into());
+        }
+        let path = self.backup_dir.join(file);
+        let content = read_verified(&path).await?;
+        let Some(until) = until else {
+            return self.unseal(content);
+        };
+
+        let mut state: serde_json::Value = decode(&self.unseal_bytes(content)?)?;
+        for entry in self.read_diffs(&path).await? {
+            if entry.at > until {
+                break;
+            }
+            crate::diff::apply(&mut state, &entry.patch);
+        }
+        Ok(serde_json::from_value(state)?)
+    }
+
+    async fn set_aside_unreadable(&self) -> Result<(), StorageError> {
+        let timestamp = chrono::Local::now().format("%Y-%m-%dT%H-%M-%S");
+        let kept = self
+            .file_path
+            .with_extension(format!("corrupt-{}.json", timestamp));
+        fs::copy(&self.file_path, &kept).await?;
+        tracing::warn!("📦 Kept the unreadable data file as {}", kept.display());
+        Ok(())
+    }
+
+    async fn archive_catches(
+        &self,
+        guild_id: &str,
+        user_id: &str,
+        records: &[CatchRecord],
+    ) -> Result<(), StorageError> {
+        let mut lines = String::new();
+        for record in records {
+            lines.push_str(&serde_json::to_string(&ArchivedCatch {
+                guild_id,
+                user_id,
+                record,
+            })?);
+            lines.push('\n');
+        }
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.archive_path)
+            .await?;
+        file.write_all(lines.as_bytes()).await?;
+        Ok(())
+    }
+}
+
+/// Keeps the state in memory only, for tests and throwaway instances
+#[derive(Default)]
+pub struct MemoryStorage {
+    saved: Mutex<Option<FishingData>>,
+}
+
+impl MemoryStorage {
+    /// Start from `data` as if it had been saved earlier
+    pub fn with_data(data: FishingData) -> Self {
+        Self {
+            saved: Mutex::new(Some(data)),
+        }
+    }
+}
+
+#[async_trait]
+impl Storage for MemoryStorage {
+    async fn load(&self) -> Result<Option<FishingData>, StorageError> {
+        Ok(self.saved.lock().await.clone())
+    }
+
+    async fn save(&self, data: &FishingData) -> Result<(), StorageError> {
+        *self.saved.lock().await = Some(data.clone());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A fresh directory under the system temp dir
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "fishing_{}_{}_{}",
+            name,
+            std::process::id(),
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[tokio::test]
+    async fn discarded_backup_leaves_the_rotation_alone() {
+        let dir = scratch_dir("backups");
+        let storage = JsonFileStorage::new(dir.join("fishing_data.json"))
+            .with_backups(dir.join("backups"), 2);
+        storage.save(&FishingData::default()).await.unwrap();
+
+        let mut kept = Vec::new();
+        for _ in 0..2 {
+            kept.push(storage.backup().await.unwrap().unwrap().name);
+            storage.prune_backups().await.unwrap();
+        }
+        // Retries in the same second still get their own file
+        let failed = storage.backup().await.unwrap().unwrap().name;
+        assert!(!kept.contains(&failed));
+        storage.discard_backup(&failed).await.unwrap();
+
+        let mut listed = storage.list_backups().await.unwrap();
+        listed.sort();
+        kept.sort();
+        assert_eq!(listed, kept);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,229 @@
+//! Import of streaks kept by hand before a community adopted the bot.
+//!
+//! The sheet is a CSV with one player per line: user ID, streak, and
+//! optionally their catch total and a name. A header line naming the columns
+//! (`user_id`, `streak`, `total_catches`, `username`, in any order) is
+//! optional; without one the columns are read in that order, and other
+//! columns are ignored, so a `/exportdata` CSV reads back as well. Fields may
+//! be quoted, and blank lines are skipped.
+
+use crate::data::{GuildData, PersistentUserData};
+use crate::legacy::ImportSummary;
+use std::collections::HashMap;
+
+/// Most lines one sheet may hold
+pub const MAX_ROWS: usize = 10_000;
+
+/// One player's line
+#[derive(Debug, Clone, PartialEq)]
+pub struct StreakRow {
+    pub user_id: String,
+    pub streak: u64,
+    pub total_catches: Option<u64>,
+    pub username: Option<String>,
+}
+
+/// A parsed sheet
+#[derive(Debug, Default)]
+pub struct StreakSheet {
+    pub rows: Vec<StreakRow>,
+}
+
+/// Why a sheet couldn't be read, with the 1-based line it failed on
+#[derive(Debug, PartialEq)]
+pub struct CsvError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl std::fmt::Display for CsvError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for CsvError {}
+
+/// Column positions, from the header or the default order
+struct Columns {
+    user_id: usize,
+    streak: usize,
+    total_catches: Option<usize>,
+    username: Option<usize>,
+}
+
+impl Columns {
+    const DEFAULT: Columns = Columns {
+        user_id: 0,
+        streak: 1,
+        total_catches: Some(2),
+        username: Some(3),
+    };
+
+    /// Read a header line, or `None` if `fields` look like data
+    fn from_header(fields: &[String]) -> Option<Result<Columns, String>> {
+        if parse_user_id(fields.first()?).is_some() {
+            return None;
+        }
+        let find = |names: &[&str]| {
+            fields.iter().position(|field| {
+                let field = field.to_lowercase().replace([' ', '-'], "_");
+                names.contains(&field.as_str())
+            })
+        };
+        let (Some(user_id), Some(streak)) = (
+            find(&["user_id", "userid", "id", "user"]),
+            find(&["streak", "streaks"]),
+        ) else {
+            return Some(Err(
+                "the header needs a `user_id` and a `streak` column".to_string()
+            ));
+        };
+        Some(Ok(Columns {
+            user_id,
+            streak,
+            total_catches: find(&["total_catches", "totalcatches", "total", "catches"]),
+            username: find(&["username", "name"]),
+        }))
+    }
+}
+
+/// Split a line on commas, honouring double quotes (`""` is a literal quote)
+fn split_fields(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field).trim().to_string()),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field.trim().to_string());
+    fields
+}
+
+/// `<@123>` and `<@!123>` mentions pasted from Discord are accepted too
+fn parse_user_id(raw: &str) -> Option<String> {
+    let id = raw
+        .trim_start_matches("<@")
+        .trim_start_matches('!')
+        .trim_end_matches('>');
+    id.parse::<u64>().ok().map(|id| id.to_string())
+}
+
+impl StreakSheet {
+    pub fn parse(text: &str) -> Result<Self, CsvError> {
+        let mut columns = None;
+        let mut rows: Vec<StreakRow> = Vec::new();
+        // Row index per user ID, so a player listed twice keeps their last line
+        let mut seen = HashMap::new();
+        for (index, line) in text.trim_start_matches('\u{feff}').lines().enumerate() {
+            let line_number = index + 1;
+            let error = |message: String| CsvError {
+                line: line_number,
+                message,
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+            let fields = split_fields(line);
+            if columns.is_none() {
+                if let Some(header) = Columns::from_header(&fields) {
+                    columns = Some(header.map_err(error)?);
+                    continue;
+                }
+                columns = Some(Columns::DEFAULT);
+            }
+            let columns = columns.as_ref().expect("set above");
+            let field = |index: usize| fields.get(index).map(String::as_str).unwrap_or("");
+
+            let user_id = parse_user_id(field(columns.user_id))
+                .ok_or_else(|| error(format!("`{}` isn't a user ID", field(columns.user_id))))?;
+            let streak = field(columns.streak)
+                .parse::<u64>()
+                .map_err(|_| error(format!("`{}` isn't a streak", field(columns.streak))))?;
+            let total_catches = match columns.total_catches.map(field) {
+                None | Some("") => None,
+                Some(raw) => Some(
+                    raw.parse::<u64>()
+                        .map_err(|_| error(format!("`{}` isn't a catch total", raw)))?,
+                ),
+            };
+            let username = columns
+                .username
+                .map(field)
+                .filter(|name| !name.is_empty())
+                .map(str::to_string);
+
+            let row = StreakRow {
+                user_id,
+                streak,
+                total_catches,
+                username,
+            };
+            match seen.get(&row.user_id) {
+                Some(&index) => rows[index] = row,
+                None => {
+                    seen.insert(row.user_id.clone(), rows.len());
+                    rows.push(row);
+                }
+            }
+            if rows.len() > MAX_ROWS {
+                return Err(error(format!(
+                    "a sheet may hold at most {} players",
+                    MAX_ROWS
+                )));
+            }
+        }
+        if rows.is_empty() {
+            return Err(CsvError {
+                line: 1,
+                message: "the sheet has no players".to_string(),
+            });
+        }
+        Ok(StreakSheet { rows })
+    }
+
+    /// Fold the sheet into `guild`. Each listed player's streak becomes the
+    /// sheet's, running through yesterday so it carries on with their next
+    /// cast; catch totals only ever go up. Players new to the server are
+    /// added, named by the sheet or else by their ID until the names are
+    /// refreshed.
+    pub fn merge_into(self, guild: &mut GuildData) -> ImportSummary {
+        let mut summary = ImportSummary::default();
+        let (today, yesterday) = (guild.today(), guild.yesterday());
+
+        for row in self.rows {
+            if guild.persistent_users.contains_key(&row.user_id) {
+                summary.merged += 1;
+            } else {
+                summary.added += 1;
+            }
+            let p_user = guild
+                .persistent_users
+                .entry(row.user_id.clone())
+                .or_insert_with(|| {
+                    PersistentUserData::new(row.username.clone().unwrap_or(row.user_id.clone()))
+                });
+            p_user.set_streak(row.streak);
+            if row.streak > 0 && p_user.last_fished_date != today {
+                p_user.last_fished_date = yesterday.clone();
+            }
+            if let Some(total) = row.total_catches {
+                p_user.total_catches = p_user.total_catches.max(total);
+            }
+            if let Some(name) = row.username {
+                p_user.username = name;
+            }
+        }
+
+        summary
+    }
+}
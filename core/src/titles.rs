@@ -0,0 +1,97 @@
+use crate::fish::Rarity;
+
+/// What a player has to do to earn a title
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TitleRequirement {
+    /// Be among the first N casts after the daily reset
+    EarlyCast(u64),
+    /// Land a fish of at least this rarity
+    CatchRarity(Rarity),
+    /// Reach a streak of this many days
+    Streak(u64),
+    /// Reach this many catches in total
+    TotalCatches(u64),
+    /// Cast at this location
+    FishAt(&'static str),
+}
+
+#[derive(Debug)]
+pub struct Title {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub emoji: &'static str,
+    pub description: &'static str,
+    pub requirement: TitleRequirement,
+}
+
+impl Title {
+    /// Shown next to a player's name, e.g. "🐉 Pond Legend"
+    pub fn label(&self) -> String {
+        format!("{} {}", self.emoji, self.name)
+    }
+}
+
+pub static TITLES: &[Title] = &[
+    Title {
+        id: "early_bird",
+        name: "Early Bird",
+        emoji: "🌅",
+        description: "One of the first three casts of the day",
+        requirement: TitleRequirement::EarlyCast(3),
+    },
+    Title {
+        id: "pond_legend",
+        name: "Pond Legend",
+        emoji: "🐉",
+        description: "Landed a legendary fish",
+        requirement: TitleRequirement::CatchRarity(Rarity::Legendary),
+    },
+    Title {
+        id: "devoted",
+        name: "Devoted Angler",
+        emoji: "🔥",
+        description: "Kept a 30 day streak",
+        requirement: TitleRequirement::Streak(30),
+    },
+    Title {
+        id: "seasoned",
+        name: "Seasoned Angler",
+        emoji: "🎣",
+        description: "Made 100 catches",
+        requirement: TitleRequirement::TotalCatches(100),
+    },
+    Title {
+        id: "deep_diver",
+        name: "Deep Diver",
+        emoji: "🌊",
+        description: "Cast a line in the Deep Sea",
+        requirement: TitleRequirement::FishAt("deep_sea"),
+    },
+];
+
+pub fn find_title(id: &str) -> Option<&'static Title> {
+    TITLES.iter().find(|t| t.id == id)
+}
+
+/// The facts about a single cast that titles are judged on
+pub(crate) struct CastFacts<'a> {
+    pub daily_position: u64,
+    pub rarity: Rarity,
+    pub streak: u64,
+    pub total_catches: u64,
+    pub location_id: &'a str,
+}
+
+/// Titles this cast qualifies for
+pub(crate) fn qualifying(facts: &CastFacts) -> Vec<&'static Title> {
+    TITLES
+        .iter()
+        .filter(|title| match title.requirement {
+            TitleRequirement::EarlyCast(n) => facts.daily_position <= n,
+            TitleRequirement::CatchRarity(rarity) => facts.rarity >= rarity,
+            TitleRequirement::Streak(days) => facts.streak >= days,
+            TitleRequirement::TotalCatches(count) => facts.total_catches >= count,
+            TitleRequirement::FishAt(location) => facts.location_id == location,
+        })
+        .collect()
+}
@@ -0,0 +1,27 @@
+use rand::distributions::{Distribution, WeightedIndex};
+
+/// One slice of the bonus wheel
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SpinPrize {
+    Nothing,
+    Coins(u64),
+    /// An item ID from `items::ITEMS`
+    Item(&'static str),
+}
+
+/// Prizes and their relative weights
+pub static WHEEL: &[(SpinPrize, u32)] = &[
+    (SpinPrize::Nothing, 30),
+    (SpinPrize::Coins(10), 30),
+    (SpinPrize::Coins(25), 15),
+    (SpinPrize::Coins(50), 5),
+    (SpinPrize::Item("worm_bait"), 15),
+    (SpinPrize::Item("golden_bait"), 5),
+];
+
+/// Spin once
+pub fn spin() -> SpinPrize {
+    let dist = WeightedIndex::new(WHEEL.iter().map(|(_, weight)| *weight))
+        .expect("the wheel is never empty");
+    WHEEL[dist.sample(&mut rand::thread_rng())].0
+}
@@ -0,0 +1,168 @@
+use crate::data::BoatTrip;
+use crate::game::{BoatHaul, FishingManager};
+use poise::serenity_prelude as serenity;
+use rand::Rng;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Custom ID of the "board the boat" button
+pub const BOARD_BUTTON: &str = "board_boat";
+/// How often the harbour checks for a departure and rolls for a new boat
+const CHECK_INTERVAL: Duration = Duration::from_secs(20 * 60);
+/// Chance per check that a boat arrives (roughly one a day)
+const BOAT_CHANCE: f64 = 0.014;
+/// How long boarding stays open
+const BOARDING_MINUTES: u64 = 5;
+
+/// Boarding embed and button, showing who's aboard so far
+pub fn boarding_message(
+    trip: &BoatTrip,
+    pond_name: &str,
+) -> (serenity::CreateEmbed, serenity::CreateActionRow) {
+    let crew = if trip.crew.is_empty() {
+        "Nobody yet — be the first!".to_string()
+    } else {
+        trip.crew
+            .iter()
+            .map(|c| c.username.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let embed = serenity::CreateEmbed::new()
+        .color(0x1E90FF)
+        .title("⛵ A fishing boat is in the harbour!")
+        .description(format!(
+            "The boat heads for the Deep Sea <t:{}:R>. Everyone aboard shares one catch, and every extra crew member improves the odds and the pay.",
+            trip.departs_at / 1000
+        ))
+        .field(format!("🧑‍✈️ Crew ({})", trip.crew.len()), crew, false)
+        .footer(serenity::CreateEmbedFooter::new(pond_name));
+    let row = serenity::CreateActionRow::Buttons(vec![serenity::CreateButton::new(BOARD_BUTTON)
+        .label("⛵ Board the boat!")
+        .style(serenity::ButtonStyle::Primary)]);
+    (embed, row)
+}
+
+fn haul_embed(haul: &BoatHaul, pond_name: &str) -> serenity::CreateEmbed {
+    let embed = serenity::CreateEmbed::new()
+        .footer(serenity::CreateEmbedFooter::new(pond_name))
+        .timestamp(chrono::Utc::now());
+    let (species, size_cm) = match haul.catch {
+        Some(catch) => catch,
+        None => {
+            return embed
+                .color(0x808080)
+                .title("⛵ The boat sailed empty")
+                .description("Nobody came aboard this time.");
+        }
+    };
+    let style = crate::ui::rarity_style(species.rarity);
+    let crew = haul
+        .trip
+        .crew
+        .iter()
+        .map(|c| format!("<@{}>", c.user_id))
+        .collect::<Vec<_>>()
+        .join(" ");
+    embed
+        .color(style.color)
+        .title(format!(
+            "⛵ The boat is back! {}",
+            crate::i18n::EN.rarity_title(species.rarity)
+        ))
+        .description(format!(
+            "The crew hauled in a {} **{}** ({} cm, {} {})!",
+            species.emoji,
+            species.name,
+            size_cm,
+            style.emoji,
+            species.rarity.label()
+        ))
+        .field(format!("🧑‍✈️ Crew ({})", haul.trip.crew.len()), crew, false)
+        .field("🍀 Crew Luck", format!("{:.1}x", haul.luck), true)
+        .field("🪙 Each", format!("+{} coins", haul.coins_each), true)
+}
+
+fn parse_ids(trip: &BoatTrip) -> Option<(serenity::ChannelId, serenity::MessageId)> {
+    let channel = trip.channel_id.parse::<u64>().ok()?;
+    let message = trip.message_id.as_ref()?.parse::<u64>().ok()?;
+    Some((
+        serenity::ChannelId::new(channel),
+        serenity::MessageId::new(message),
+    ))
+}
+
+/// Sail the boat and replace the boarding message with the haul
+async fn sail(fishing_manager: &FishingManager, http: &serenity::Http, guild_id: &str) {
+    let haul = match fishing_manager.sail_boat(guild_id).await {
+        Some(haul) => haul,
+        None => return,
+    };
+    if let Some((channel, message)) = parse_ids(&haul.trip) {
+        let branding = fishing_manager.branding(guild_id).await;
+        let edit = serenity::EditMessage::new()
+            .embed(haul_embed(&haul, &branding.pond_name))
+            .components(vec![]);
+        if let Err(e) = channel.edit_message(http, message, edit).await {
+            tracing::error!("❌ Error posting boat haul: {}", e);
+        }
+    }
+}
+
+/// Send boats out on a fixed interval and sail them once boarding closes
+pub async fn run(fishing_manager: Arc<FishingManager>, http: Arc<serenity::Http>) {
+    let mut interval = tokio::time::interval(CHECK_INTERVAL);
+    loop {
+        interval.tick().await;
+        for guild_id in fishing_manager.guild_ids().await {
+            // Each server's boarding window runs on its own so one harbour doesn't hold up the rest
+            let fishing_manager = fishing_manager.clone();
+            let http = http.clone();
+            tokio::spawn(async move { check(&fishing_manager, &http, &guild_id).await });
+        }
+    }
+}
+
+/// Sail one server's pending trip, or maybe bring a new boat in
+async fn check(fishing_manager: &FishingManager, http: &serenity::Http, guild_id: &str) {
+    // A trip left over from before a restart sails as soon as it's due
+    if fishing_manager.boat_trip(guild_id).await.is_some() {
+        sail(fishing_manager, http, guild_id).await;
+        return;
+    }
+
+    if !rand::thread_rng().gen_bool(BOAT_CHANCE) {
+        return;
+    }
+    let departs_at = chrono::Utc::now().timestamp_millis() as u64 + BOARDING_MINUTES * 60 * 1000;
+    let trip = match fishing_manager.begin_boat_trip(guild_id, departs_at).await {
+        Some(trip) => trip,
+        None => return,
+    };
+
+    let channel = match trip.channel_id.parse::<u64>() {
+        Ok(id) => serenity::ChannelId::new(id),
+        Err(_) => return,
+    };
+    let branding = fishing_manager.branding(guild_id).await;
+    let (embed, row) = boarding_message(&trip, &branding.pond_name);
+    match channel
+        .send_message(
+            http,
+            serenity::CreateMessage::new()
+                .embed(embed)
+                .components(vec![row]),
+        )
+        .await
+    {
+        Ok(msg) => {
+            fishing_manager
+                .set_boat_message(guild_id, msg.id.to_string())
+                .await
+        }
+        Err(e) => tracing::error!("❌ Error posting boat: {}", e),
+    }
+
+    tokio::time::sleep(Duration::from_secs(BOARDING_MINUTES * 60)).await;
+    sail(fishing_manager, http, guild_id).await;
+}
@@ -1,17 +1,30 @@
+use super::guild_key;
 use crate::{Context, Error};
 use poise::serenity_prelude as serenity;
 
 /// Set up the fishing pond (creates the fish button)
 #[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
-pub async fn fishsetup(ctx: Context<'_>) -> Result<(), Error> {
-    let row = serenity::CreateActionRow::Buttons(vec![serenity::CreateButton::new("fish_button")
-        .label("🎣 Fish!")
-        .style(serenity::ButtonStyle::Primary)]);
+pub async fn fishsetup(
+    ctx: Context<'_>,
+    #[description = "Make this channel a fixed fishing spot with its own fish pool"]
+    #[autocomplete = "super::autocomplete_location"]
+    biome: Option<String>,
+) -> Result<(), Error> {
+    if let Some(biome) = biome {
+        return setup_biome_channel(ctx, &biome).await;
+    }
+
+    let paused = ctx.data().fishing_manager.is_paused(&guild_key(ctx)?).await;
+    let row = crate::ui::fish_button_row(None, paused);
 
+    let content = {
+        let data = ctx.data().data_manager.guild(&guild_key(ctx)?).await;
+        crate::ui::fish_button_content(&data.branding().pond_name, None, data.featured_species())
+    };
     let reply = ctx
         .send(
             poise::CreateReply::default()
-                .content("🎣 Welcome to Stardust Pond — click to fish!")
+                .content(content)
                 .components(vec![row])
                 .ephemeral(true),
         )
@@ -19,35 +32,84 @@ pub async fn fishsetup(ctx: Context<'_>) -> Result<(), Error> {
 
     // Save button info to data
     {
-        let mut data = ctx.data().data_manager.data.write().await;
+        let mut data = ctx.data().data_manager.guild_mut(&guild_key(ctx)?).await;
         data.button_message_id = Some(reply.message().await?.id.to_string());
         data.button_channel_id = Some(ctx.channel_id().to_string());
-        data.guild_id = ctx.guild_id().map(|id| id.to_string());
     }
     ctx.data().data_manager.save().await;
+    ctx.data()
+        .data_manager
+        .journal_settings(&guild_key(ctx)?, &["buttonMessageId", "buttonChannelId"])
+        .await;
 
     Ok(())
 }
 
-/// Set the minimum streak for the Best Anglers list
-#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
-pub async fn setbestanglerstreak(
-    ctx: Context<'_>,
-    #[description = "The minimum streak required (e.g., 5)"]
-    #[min = 1]
-    streak: u64,
-) -> Result<(), Error> {
+/// Post a biome button in the current channel and remember it
+async fn setup_biome_channel(ctx: Context<'_>, biome: &str) -> Result<(), Error> {
+    let location = match crate::fish::find_location(biome) {
+        Some(location) => location,
+        None => {
+            ctx.send(
+                poise::CreateReply::default()
+                    .content("❌ Unknown biome. Pick one from the suggestions.")
+                    .ephemeral(true),
+            )
+            .await?;
+            return Ok(());
+        }
+    };
+
+    let paused = ctx.data().fishing_manager.is_paused(&guild_key(ctx)?).await;
+    let channel_id = ctx.channel_id();
+    let content = {
+        let data = ctx.data().data_manager.guild(&guild_key(ctx)?).await;
+        crate::ui::fish_button_content(
+            &data.branding().pond_name,
+            Some(location),
+            data.featured_species(),
+        )
+    };
+    let button_msg = channel_id
+        .send_message(
+            &ctx.http(),
+            serenity::CreateMessage::new()
+                .content(content)
+                .components(vec![crate::ui::fish_button_row(Some(location), paused)]),
+        )
+        .await?;
+
+    let previous = {
+        let mut data = ctx.data().data_manager.guild_mut(&guild_key(ctx)?).await;
+        data.biome_channels.insert(
+            channel_id.to_string(),
+            crate::data::BiomeChannel {
+                location: location.id.to_string(),
+                button_message_id: Some(button_msg.id.to_string()),
+            },
+        )
+    };
+    ctx.data().data_manager.save().await;
+    ctx.data()
+        .data_manager
+        .journal_settings(&guild_key(ctx)?, &["biomeChannels"])
+        .await;
+
+    // Replace any button left over from an earlier setup in this channel
+    if let Some(old_msg) = previous
+        .and_then(|b| b.button_message_id)
+        .and_then(|id| id.parse::<u64>().ok())
     {
-        let mut data = ctx.data().data_manager.data.write().await;
-        data.best_angler_streak = streak;
+        let _ = channel_id
+            .delete_message(&ctx.http(), serenity::MessageId::new(old_msg))
+            .await;
     }
-    ctx.data().data_manager.save().await;
 
     ctx.send(
         poise::CreateReply::default()
             .content(format!(
-                "✅ Best Angler minimum streak set to **{}** days.",
-                streak
+                "✅ This channel is now the {} **{}**!",
+                location.emoji, location.name
             ))
             .ephemeral(true),
     )
@@ -56,46 +118,319 @@ pub async fn setbestanglerstreak(
     Ok(())
 }
 
-/// Set the number of days of inactivity before pinging a member
+/// Choose the channels where `/fish` works
+#[poise::command(
+    slash_command,
+    subcommands("fishchannels_add", "fishchannels_remove"),
+    subcommand_required,
+    guild_only,
+    default_member_permissions = "ADMINISTRATOR"
+)]
+pub async fn fishchannels(_ctx: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+/// Let `/fish` work in a channel (once one is added, only listed channels work)
+#[poise::command(slash_command, rename = "add")]
+pub async fn fishchannels_add(
+    ctx: Context<'_>,
+    #[description = "The channel (defaults to this one)"]
+    #[channel_types("Text")]
+    channel: Option<serenity::GuildChannel>,
+) -> Result<(), Error> {
+    let channel_id = channel.map_or(ctx.channel_id(), |channel| channel.id);
+    let added = {
+        let mut data = ctx.data().data_manager.guild_mut(&guild_key(ctx)?).await;
+        data.fish_channels.insert(channel_id.to_string())
+    };
+    let content = if added {
+        ctx.data().data_manager.save().await;
+        ctx.data()
+            .data_manager
+            .journal_settings(&guild_key(ctx)?, &["fishChannels"])
+            .await;
+        format!("✅ `/fish` now works in <#{}>.", channel_id)
+    } else {
+        format!("ℹ️ `/fish` already works in <#{}>.", channel_id)
+    };
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Stop `/fish` working in a channel
+#[poise::command(slash_command, rename = "remove")]
+pub async fn fishchannels_remove(
+    ctx: Context<'_>,
+    #[description = "The channel (defaults to this one)"]
+    #[channel_types("Text")]
+    channel: Option<serenity::GuildChannel>,
+) -> Result<(), Error> {
+    let channel_id = channel.map_or(ctx.channel_id(), |channel| channel.id);
+    let (removed, anywhere) = {
+        let mut data = ctx.data().data_manager.guild_mut(&guild_key(ctx)?).await;
+        let removed = data.fish_channels.remove(&channel_id.to_string());
+        (removed, data.fish_channels.is_empty())
+    };
+    let content = if !removed {
+        format!("ℹ️ <#{}> isn't on the `/fish` channel list.", channel_id)
+    } else {
+        ctx.data().data_manager.save().await;
+        ctx.data()
+            .data_manager
+            .journal_settings(&guild_key(ctx)?, &["fishChannels"])
+            .await;
+        if anywhere {
+            format!(
+                "✅ Removed <#{}>. No channels are listed any more, so `/fish` works everywhere again.",
+                channel_id
+            )
+        } else {
+            format!("✅ `/fish` no longer works in <#{}>.", channel_id)
+        }
+    };
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Post a fresh fish button, e.g. after the old one was deleted by hand
 #[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
-pub async fn setreminderthreshold(
+pub async fn refreshbutton(
     ctx: Context<'_>,
-    #[description = "Number of days (e.g., 1 for daily, 3 for every 3 days)"]
-    #[min = 1]
-    days: u64,
+    #[description = "Where to post it (defaults to this channel)"]
+    #[channel_types("Text")]
+    channel: Option<serenity::GuildChannel>,
 ) -> Result<(), Error> {
+    let guild_id = guild_key(ctx)?;
+    let channel_id = channel.map_or(ctx.channel_id(), |channel| channel.id);
+    let content = match crate::events::repost_fish_button(
+        ctx.serenity_context(),
+        &guild_id,
+        channel_id,
+        None,
+        &ctx.data().data_manager,
+    )
+    .await
     {
-        let mut data = ctx.data().data_manager.data.write().await;
-        data.reminder_threshold = days;
-    }
-    ctx.data().data_manager.save().await;
+        Ok(()) => {
+            ctx.data()
+                .data_manager
+                .journal_settings(&guild_id, &["buttonMessageId", "buttonChannelId"])
+                .await;
+            format!("✅ Posted a fresh fish button in <#{}>.", channel_id)
+        }
+        Err(e) => format!(
+            "❌ Could not post the fish button in <#{}>: {}",
+            channel_id, e
+        ),
+    };
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
 
-    ctx.send(poise::CreateReply::default()
-        .content(format!("✅ Inactivity threshold set to **{} days**. Members will be pinged if they haven't fished for {} days or more.", days, days))
-        .ephemeral(true))
-        .await?;
+    Ok(())
+}
+
+/// Show every setting for this server in one place
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn fishconfig(ctx: Context<'_>) -> Result<(), Error> {
+    let data = ctx.data().data_manager.guild(&guild_key(ctx)?).await;
+    let channel = |id: &Option<String>| {
+        id.as_ref()
+            .map_or("*not set*".to_string(), |id| format!("<#{}>", id))
+    };
+    let on_off = |enabled: bool| if enabled { "✅ On" } else { "❌ Off" };
+
+    let biomes = if data.biome_channels.is_empty() {
+        "*none*".to_string()
+    } else {
+        let mut biomes: Vec<String> = data
+            .biome_channels
+            .iter()
+            .map(|(channel_id, biome)| {
+                let name = crate::fish::find_location(&biome.location)
+                    .map_or(biome.location.clone(), |l| {
+                        format!("{} {}", l.emoji, l.name)
+                    });
+                format!("<#{}>: {}", channel_id, name)
+            })
+            .collect();
+        biomes.sort();
+        biomes.join("\n")
+    };
+    let streak_roles = if data.streak_roles.is_empty() {
+        "*none*".to_string()
+    } else {
+        data.streak_roles
+            .iter()
+            .map(|r| format!("{} days: <@&{}>", r.streak, r.role_id))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    let streamer = match &data.streamer_name {
+        Some(name) => format!("**{}** ({} min frenzy)", name, data.stream_frenzy_minutes),
+        None => "*not set*".to_string(),
+    };
+
+    let branding = data.branding();
+    let embed = serenity::CreateEmbed::new()
+        .color(0x3498DB)
+        .title("⚙️ Fishing Configuration")
+        .field(
+            "📍 Channels",
+            format!(
+                "Fish button: {}\nSummary: {}{}\n/fish: {}",
+                channel(&data.button_channel_id),
+                channel(&data.summary_channel_id),
+                data.extra_summary_channels
+                    .iter()
+                    .map(|extra| format!(
+                        "\n• also <#{}> ({})",
+                        extra.channel_id,
+                        extra.reminder.label()
+                    ))
+                    .collect::<String>(),
+                if data.fish_channels.is_empty() {
+                    "*anywhere*".to_string()
+                } else {
+                    data.fish_channels
+                        .iter()
+                        .map(|id| format!("<#{}>", id))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                }
+            ),
+            false,
+        )
+        .field("🗺️ Biome Channels", biomes, false)
+        .field(
+            "👥 Roles",
+            format!(
+                "Tracked roles: {}",
+                if data.tracked_role_ids.is_empty() {
+                    "*not set*".to_string()
+                } else {
+                    data.tracked_role_ids
+                        .iter()
+                        .map(|id| format!("<@&{}>", id))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                }
+            ),
+            false,
+        )
+        .field("🔥 Streak Roles", streak_roles, false)
+        .field(
+            "🔢 Thresholds",
+            format!(
+                "Best Anglers streak: **{}** days\nReminder after: **{}** days without fishing{}\nLeft out of reminders: **{}** by admins, **{}** by choice",
+                data.best_angler_streak,
+                data.reminder_threshold,
+                data.role_reminder_thresholds
+                    .iter()
+                    .map(|(id, days)| format!("\n• <@&{}>: **{}** days", id, days))
+                    .collect::<String>(),
+                data.reminder_exclusions.len(),
+                data.reminder_opt_outs.len()
+            ),
+            false,
+        )
+        .field(
+            "🔀 Toggles",
+            format!(
+                "Reminder pings: {}\nUntrack inactive members: {}\nCast verification: {}\nPond frozen: {}",
+                on_off(data.ping_reminder_enabled),
+                on_off(data.auto_untrack_inactive),
+                on_off(data.verification_enabled),
+                match &data.paused_since {
+                    Some(since) => format!("🧊 Since {}", since),
+                    None => "❌ No".to_string(),
+                }
+            ),
+            false,
+        )
+        .field(
+            "🎁 Extras",
+            format!(
+                "Booster perk: {}\nFireworks: {}\nStreamer: {}",
+                data.booster_perk.map_or("*off*", |perk| perk.label()),
+                match data.fireworks_intensity {
+                    0 => "*off*".to_string(),
+                    intensity => format!("intensity **{}**", intensity),
+                },
+                streamer
+            ),
+            false,
+        )
+        .field(
+            "🎨 Branding",
+            format!(
+                "Name: **{}**\nMain color: #{:06X}\nHighlight color: #{:06X}\nLanguage: {}",
+                branding.pond_name,
+                branding.color,
+                branding.highlight,
+                data.locale.label()
+            ),
+            false,
+        )
+        .field(
+            "🕒 Daily Reset",
+            format!(
+                "{} {}\nSummary: {}\nBlackout days: {}\nQuiet weekdays: {}",
+                data.reset_at().format("%H:%M"),
+                data.tz().name(),
+                data.summary_time.map_or("*with the reset*".to_string(), |at| {
+                    at.format("%H:%M").to_string()
+                }),
+                data.blackout_dates.len(),
+                weekday_list(&data.quiet_weekdays)
+            ),
+            false,
+        );
+    drop(data);
 
+    ctx.send(poise::CreateReply::default().embed(embed).ephemeral(true))
+        .await?;
     Ok(())
 }
 
-/// Set the role to track for fishing statistics
+/// Set the minimum streak for the Best Anglers list
 #[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
-pub async fn setrole(
+pub async fn setbestanglerstreak(
     ctx: Context<'_>,
-    #[description = "The role to track"] role: serenity::Role,
+    #[description = "The minimum streak required (e.g., 5)"]
+    #[min = 1]
+    streak: u64,
 ) -> Result<(), Error> {
     {
-        let mut data = ctx.data().data_manager.data.write().await;
-        data.tracked_role_id = Some(role.id.to_string());
-        data.guild_id = ctx.guild_id().map(|id| id.to_string());
+        let mut data = ctx.data().data_manager.guild_mut(&guild_key(ctx)?).await;
+        data.best_angler_streak = streak;
     }
     ctx.data().data_manager.save().await;
+    ctx.data()
+        .data_manager
+        .journal_settings(&guild_key(ctx)?, &["bestAnglerStreak"])
+        .await;
 
     ctx.send(
         poise::CreateReply::default()
             .content(format!(
-                "✅ Now tracking the **{}** role for fishing statistics!",
-                role.name
+                "✅ Best Angler minimum streak set to **{}** days.",
+                streak
             ))
             .ephemeral(true),
     )
@@ -104,19 +439,37 @@ pub async fn setrole(
     Ok(())
 }
 
-/// Set the channel for daily summaries
+/// Mark a missed day as fished (e.g. the bot was down) and recompute the streak
 #[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
-pub async fn setsummarychannel(ctx: Context<'_>) -> Result<(), Error> {
-    {
-        let mut data = ctx.data().data_manager.data.write().await;
-        data.summary_channel_id = Some(ctx.channel_id().to_string());
-        data.guild_id = ctx.guild_id().map(|id| id.to_string());
-    }
-    ctx.data().data_manager.save().await;
+pub async fn repairstreak(
+    ctx: Context<'_>,
+    #[description = "The member whose streak to repair"] user: serenity::User,
+    #[description = "The missed day, as YYYY-MM-DD (server time)"] date: String,
+) -> Result<(), Error> {
+    let result = ctx
+        .data()
+        .fishing_manager
+        .repair_streak(
+            &guild_key(ctx)?,
+            &ctx.author().id.to_string(),
+            &user.id.to_string(),
+            &date,
+        )
+        .await;
 
+    let content = match result {
+        Ok((old, new)) => format!(
+            "🩹 Marked **{}** as fished for **{}**. Streak: {} → **{}** days.",
+            user.name, date, old, new
+        ),
+        Err(e) => match crate::ui::fishing_error_message(&e) {
+            Some(content) => content,
+            None => return Err(e.into()),
+        },
+    };
     ctx.send(
         poise::CreateReply::default()
-            .content("✅ Daily summaries will be posted in this channel!")
+            .content(content)
             .ephemeral(true),
     )
     .await?;
@@ -124,24 +477,68 @@ pub async fn setsummarychannel(ctx: Context<'_>) -> Result<(), Error> {
     Ok(())
 }
 
-/// Enable or disable pinging members in the daily fishing reminder
+/// Set a member's streak to an exact number of days
 #[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
-pub async fn togglereminder(
+pub async fn setstreak(
     ctx: Context<'_>,
-    #[description = "Set to true to enable pings, false to disable (shows nicknames instead)"]
-    enabled: bool,
+    #[description = "The member whose streak to set"] user: serenity::User,
+    #[description = "The streak, in days"] days: u64,
 ) -> Result<(), Error> {
-    {
-        let mut data = ctx.data().data_manager.data.write().await;
-        data.ping_reminder_enabled = enabled;
-    }
-    ctx.data().data_manager.save().await;
+    let result = ctx
+        .data()
+        .fishing_manager
+        .set_streak(
+            &guild_key(ctx)?,
+            &ctx.author().id.to_string(),
+            &user.id.to_string(),
+            days,
+        )
+        .await;
+
+    let content = match result {
+        Ok((old, new)) => format!(
+            "🔥 Set **{}**'s streak: {} → **{}** days.",
+            user.name, old, new
+        ),
+        Err(e) => match crate::ui::fishing_error_message(&e) {
+            Some(content) => content,
+            None => return Err(e.into()),
+        },
+    };
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Add to or subtract from a member's lifetime catches
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn adjustcatches(
+    ctx: Context<'_>,
+    #[description = "The member whose catches to adjust"] user: serenity::User,
+    #[description = "Catches to add (negative to subtract)"] amount: i64,
+) -> Result<(), Error> {
+    let (old, new) = ctx
+        .data()
+        .fishing_manager
+        .adjust_catches(
+            &guild_key(ctx)?,
+            &ctx.author().id.to_string(),
+            &user.id.to_string(),
+            &user.name,
+            amount,
+        )
+        .await;
 
     ctx.send(
         poise::CreateReply::default()
             .content(format!(
-                "✅ Daily reminder pings have been {}.",
-                if enabled { "ENABLED" } else { "DISABLED" }
+                "🎣 Adjusted **{}**'s catches: {} → **{}**.",
+                user.name, old, new
             ))
             .ephemeral(true),
     )
@@ -150,134 +547,2651 @@ pub async fn togglereminder(
     Ok(())
 }
 
-/// Get a summary of who has not fished today (for the tracked role)
-#[poise::command(
-    slash_command,
-    rename = "fishsummary",
-    default_member_permissions = "ADMINISTRATOR"
-)]
-pub async fn fishsummary(ctx: Context<'_>) -> Result<(), Error> {
-    let guild_id = match ctx.guild_id() {
-        Some(id) => id,
-        None => {
-            ctx.send(
-                poise::CreateReply::default()
-                    .content("❌ This command can only be used in a server.")
-                    .ephemeral(true),
-            )
-            .await?;
-            return Ok(());
+#[derive(poise::ChoiceParameter)]
+pub enum UserResetChoice {
+    #[name = "Today's cast (lets them fish again)"]
+    Today,
+    #[name = "Stats (streak, catches, history)"]
+    Stats,
+    #[name = "Everything"]
+    Everything,
+}
+
+/// Wipe a member's cast today, their stats, or all their data
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn resetuser(
+    ctx: Context<'_>,
+    #[description = "The member to reset"] user: serenity::User,
+    #[description = "What to wipe"] scope: UserResetChoice,
+) -> Result<(), Error> {
+    use crate::data::UserReset;
+    let scope = match scope {
+        UserResetChoice::Today => UserReset::Today,
+        UserResetChoice::Stats => UserReset::Stats,
+        UserResetChoice::Everything => UserReset::Everything,
+    };
+    let warning = match scope {
+        UserReset::Today => "Their cast and fortune for today are wiped, so they can fish again.",
+        UserReset::Stats => {
+            "Their streak, catch total, and catch history go back to zero. Coins and items stay."
         }
+        UserReset::Everything => "Everything stored about them in this server is erased.",
     };
 
-    let (tracked_role_id, _role_name_str, fished_ids) = {
-        let data = ctx.data().data_manager.data.read().await;
-
-        let role_id =
-            match &data.tracked_role_id {
-                Some(id) => match id.parse::<u64>() {
-                    Ok(parsed) => serenity::RoleId::new(parsed),
-                    Err(_) => {
-                        drop(data);
-                        ctx.send(poise::CreateReply::default()
-                        .content("❌ Invalid tracked role ID. Please set it again with `/setrole`.")
-                        .ephemeral(true))
-                        .await?;
-                        return Ok(());
-                    }
-                },
-                None => {
-                    drop(data);
-                    ctx.send(
-                        poise::CreateReply::default()
-                            .content("❌ No role is being tracked. Use `/setrole` to set one.")
-                            .ephemeral(true),
-                    )
-                    .await?;
-                    return Ok(());
-                }
-            };
+    let prefix = ctx.id();
+    let reply = ctx
+        .send(
+            poise::CreateReply::default()
+                .content(format!("⚠️ Reset **{}**? {}", user.name, warning))
+                .components(confirm_buttons(prefix, "🧹 Reset", false))
+                .ephemeral(true),
+        )
+        .await?;
+    let message = reply.message().await?;
 
-        let fished: Vec<String> = data.users.keys().cloned().collect();
-        drop(data);
-        (role_id, format!("{}", role_id), fished)
-    };
+    let author = ctx.author().id;
+    let answer = serenity::ComponentInteractionCollector::new(ctx)
+        .message_id(message.id)
+        .filter(move |i| i.user.id == author)
+        .timeout(RESTORE_CONFIRM_TIMEOUT)
+        .await;
 
-    // Fetch guild via HTTP to get role info
-    let guild = guild_id.to_partial_guild(&ctx.http()).await?;
-    let role = match guild.roles.get(&tracked_role_id) {
-        Some(r) => r,
-        None => {
-            ctx.send(poise::CreateReply::default()
-                .content("❌ The tracked role could not be found. Please set it again with `/setrole`.")
-                .ephemeral(true))
+    let content = match answer {
+        None => "⌛ Reset cancelled: no answer in time.".to_string(),
+        Some(interaction) => {
+            interaction
+                .create_response(ctx, serenity::CreateInteractionResponse::Acknowledge)
                 .await?;
-            return Ok(());
+            if interaction.data.custom_id != format!("{}confirm", prefix) {
+                "Reset cancelled.".to_string()
+            } else if ctx
+                .data()
+                .fishing_manager
+                .reset_user(
+                    &guild_key(ctx)?,
+                    &ctx.author().id.to_string(),
+                    &user.id.to_string(),
+                    scope,
+                )
+                .await
+            {
+                format!("🧹 Reset **{}**'s {}.", user.name, scope.label())
+            } else {
+                format!("**{}** has nothing to reset.", user.name)
+            }
         }
     };
 
-    // Fetch all guild members reliably via pagination
-    let mut members = Vec::new();
-    let mut after = None;
-    loop {
-        let page = guild_id.members(&ctx.http(), Some(1000), after).await?;
-        if page.is_empty() {
-            break;
-        }
-        after = Some(page.last().unwrap().user.id);
-        members.extend(page);
-    }
+    reply
+        .edit(
+            ctx,
+            poise::CreateReply::default()
+                .content(content)
+                .components(confirm_buttons(prefix, "🧹 Reset", true)),
+        )
+        .await?;
 
-    let non_fishers: Vec<serenity::UserId> = members
-        .iter()
-        .filter(|member| member.roles.contains(&tracked_role_id))
-        .filter(|member| !fished_ids.contains(&member.user.id.to_string()))
-        .map(|member| member.user.id)
-        .collect();
+    Ok(())
+}
 
-    if non_fishers.is_empty() {
+/// Merge a member's old account into their new one
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn mergeusers(
+    ctx: Context<'_>,
+    #[description = "The account to merge and delete"] old: serenity::User,
+    #[description = "The account to keep"] new: serenity::User,
+) -> Result<(), Error> {
+    if old.id == new.id {
         ctx.send(
             poise::CreateReply::default()
-                .content(format!(
-                    "🎉 All members of the **{}** role have fished today!",
-                    role.name
-                ))
+                .content("❌ Pick two different accounts.")
                 .ephemeral(true),
         )
         .await?;
         return Ok(());
     }
 
-    let mentions = non_fishers
-        .iter()
-        .map(|id| format!("<@{}>", id))
-        .collect::<Vec<_>>()
-        .join("\n");
-
-    // Handle Discord's 2000 character limit for messages
-    if mentions.len() > 1850 {
-        let truncated = &mentions[..1800];
-        let last_newline = truncated.rfind('\n').unwrap_or(1800);
-        ctx.send(
+    let prefix = ctx.id();
+    let reply = ctx
+        .send(
             poise::CreateReply::default()
                 .content(format!(
-                    "**Members of the {} role who have not fished today:**\n{} \n*...and more (too many to display)*",
-                    role.name, &truncated[..last_newline]
+                    "⚠️ Merge **{}** into **{}**? Catches, coins, items, and history are added to **{}**, the newer streak is kept, and **{}**'s record is deleted.",
+                    old.name, new.name, new.name, old.name
                 ))
+                .components(confirm_buttons(prefix, "🔀 Merge", false))
                 .ephemeral(true),
         )
         .await?;
-    } else {
-        ctx.send(
+    let message = reply.message().await?;
+
+    let author = ctx.author().id;
+    let answer = serenity::ComponentInteractionCollector::new(ctx)
+        .message_id(message.id)
+        .filter(move |i| i.user.id == author)
+        .timeout(RESTORE_CONFIRM_TIMEOUT)
+        .await;
+
+    let content = match answer {
+        None => "⌛ Merge cancelled: no answer in time.".to_string(),
+        Some(interaction) => {
+            interaction
+                .create_response(ctx, serenity::CreateInteractionResponse::Acknowledge)
+                .await?;
+            if interaction.data.custom_id != format!("{}confirm", prefix) {
+                "Merge cancelled.".to_string()
+            } else if ctx
+                .data()
+                .fishing_manager
+                .merge_users(
+                    &guild_key(ctx)?,
+                    &ctx.author().id.to_string(),
+                    &old.id.to_string(),
+                    &new.id.to_string(),
+                )
+                .await
+            {
+                format!("🔀 Merged **{}** into **{}**.", old.name, new.name)
+            } else {
+                format!("**{}** has no record to merge.", old.name)
+            }
+        }
+    };
+
+    reply
+        .edit(
+            ctx,
             poise::CreateReply::default()
-                .content(format!(
-                    "**Members of the {} role who have not fished today:**\n{}",
-                    role.name, mentions
-                ))
-                .ephemeral(true),
+                .content(content)
+                .components(confirm_buttons(prefix, "🔀 Merge", true)),
         )
         .await?;
+
+    Ok(())
+}
+
+#[derive(poise::ChoiceParameter)]
+pub enum BoosterPerkChoice {
+    #[name = "Off"]
+    Off,
+    #[name = "Extra luck"]
+    ExtraLuck,
+    #[name = "Extra daily cast"]
+    ExtraCast,
+    #[name = "Crystal fish (cosmetic)"]
+    CrystalFish,
+}
+
+/// Choose the perk server boosters get, or switch it off
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn setboosterperk(
+    ctx: Context<'_>,
+    #[description = "The perk boosters get"] perk: BoosterPerkChoice,
+) -> Result<(), Error> {
+    use crate::data::BoosterPerk;
+    let perk = match perk {
+        BoosterPerkChoice::Off => None,
+        BoosterPerkChoice::ExtraLuck => Some(BoosterPerk::ExtraLuck),
+        BoosterPerkChoice::ExtraCast => Some(BoosterPerk::ExtraCast),
+        BoosterPerkChoice::CrystalFish => Some(BoosterPerk::CrystalFish),
+    };
+    {
+        let mut data = ctx.data().data_manager.guild_mut(&guild_key(ctx)?).await;
+        data.booster_perk = perk;
     }
+    ctx.data().data_manager.save().await;
+    ctx.data()
+        .data_manager
+        .journal_settings(&guild_key(ctx)?, &["boosterPerk"])
+        .await;
+
+    let content = match perk {
+        Some(perk) => format!("✅ Server boosters now get **{}**!", perk.label()),
+        None => "✅ Booster perks have been turned off.".to_string(),
+    };
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
 
     Ok(())
 }
+
+/// Rename the pond on embeds and the fish button
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn setpondname(
+    ctx: Context<'_>,
+    #[description = "The new name (leave empty for Stardust Pond)"]
+    #[max_length = 40]
+    name: Option<String>,
+) -> Result<(), Error> {
+    let name = name
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty());
+    let guild_id = guild_key(ctx)?;
+    let pond_name = {
+        let mut data = ctx.data().data_manager.guild_mut(&guild_id).await;
+        data.pond_name = name;
+        data.branding().pond_name
+    };
+    ctx.data().data_manager.save().await;
+    ctx.data()
+        .data_manager
+        .journal_settings(&guild_id, &["pondName"])
+        .await;
+    crate::events::refresh_fish_buttons(ctx.http(), &ctx.data().data_manager, &guild_id).await;
+
+    ctx.send(
+        poise::CreateReply::default()
+            .content(format!("✅ The pond is now called **{}**.", pond_name))
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[derive(poise::ChoiceParameter)]
+pub enum EmbedColorChoice {
+    #[name = "Main (everyday embeds)"]
+    Main,
+    #[name = "Highlight (summaries and records)"]
+    Highlight,
+}
+
+/// Set the colors of the bot's embeds
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn setembedcolor(
+    ctx: Context<'_>,
+    #[description = "Which color to change"] which: EmbedColorChoice,
+    #[description = "Hex color, e.g. #1ABC9C (leave empty for the default)"] color: Option<String>,
+) -> Result<(), Error> {
+    let color = match color.as_deref() {
+        Some(hex) => match parse_hex_color(hex) {
+            Some(color) => Some(color),
+            None => {
+                ctx.send(
+                    poise::CreateReply::default()
+                        .content("❌ Use a hex color like `#1ABC9C`.")
+                        .ephemeral(true),
+                )
+                .await?;
+                return Ok(());
+            }
+        },
+        None => None,
+    };
+    let guild_id = guild_key(ctx)?;
+    let (field, label, color) = {
+        let mut data = ctx.data().data_manager.guild_mut(&guild_id).await;
+        match which {
+            EmbedColorChoice::Main => {
+                data.embed_color = color;
+                ("embedColor", "Main", data.branding().color)
+            }
+            EmbedColorChoice::Highlight => {
+                data.highlight_color = color;
+                ("highlightColor", "Highlight", data.branding().highlight)
+            }
+        }
+    };
+    ctx.data().data_manager.save().await;
+    ctx.data()
+        .data_manager
+        .journal_settings(&guild_id, &[field])
+        .await;
+
+    let embed = serenity::CreateEmbed::new()
+        .color(color)
+        .title(format!("✅ {} color set to #{:06X}", label, color));
+    ctx.send(poise::CreateReply::default().embed(embed).ephemeral(true))
+        .await?;
+
+    Ok(())
+}
+
+/// `#RRGGBB` or `RRGGBB` as a color
+fn parse_hex_color(hex: &str) -> Option<u32> {
+    let hex = hex.trim().trim_start_matches('#');
+    if hex.len() != 6 {
+        return None;
+    }
+    u32::from_str_radix(hex, 16).ok()
+}
+
+#[derive(poise::ChoiceParameter)]
+pub enum MessageKeyChoice {
+    #[name = "Reminder (above the summary's pings)"]
+    Reminder,
+    #[name = "Summary description"]
+    Summary,
+    #[name = "\"We miss you\" note"]
+    MissYou,
+}
+
+/// Reword one of the summary's messages
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn setmessage(
+    ctx: Context<'_>,
+    #[description = "Which message to change"] key: MessageKeyChoice,
+    #[description = "New text; {count}, {catches}, {role}, and {pond} are filled in (leave empty for the default)"]
+    #[max_length = 150]
+    template: Option<String>,
+) -> Result<(), Error> {
+    use crate::data::MessageKey;
+    let key = match key {
+        MessageKeyChoice::Reminder => MessageKey::Reminder,
+        MessageKeyChoice::Summary => MessageKey::Summary,
+        MessageKeyChoice::MissYou => MessageKey::MissYou,
+    };
+    let template = template
+        .map(|text| text.trim().replace("\\n", "\n"))
+        .filter(|text| !text.is_empty());
+    let text = {
+        let mut data = ctx.data().data_manager.guild_mut(&guild_key(ctx)?).await;
+        match template {
+            Some(text) => data.messages.insert(key, text),
+            None => data.messages.remove(&key),
+        };
+        data.message(key).to_string()
+    };
+    ctx.data().data_manager.save().await;
+    ctx.data()
+        .data_manager
+        .journal_settings(&guild_key(ctx)?, &["messages"])
+        .await;
+
+    ctx.send(
+        poise::CreateReply::default()
+            .content(format!("✅ The {} now reads:\n>>> {}", key.label(), text))
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[derive(poise::ChoiceParameter)]
+pub enum LocaleChoice {
+    #[name = "English"]
+    En,
+    #[name = "Español"]
+    Es,
+}
+
+/// Pick the language of catch posts, reminders, and summaries
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn setlocale(
+    ctx: Context<'_>,
+    #[description = "Language to use"] language: LocaleChoice,
+) -> Result<(), Error> {
+    use crate::data::Locale;
+    let locale = match language {
+        LocaleChoice::En => Locale::En,
+        LocaleChoice::Es => Locale::Es,
+    };
+    {
+        let mut data = ctx.data().data_manager.guild_mut(&guild_key(ctx)?).await;
+        data.locale = locale;
+    }
+    ctx.data().data_manager.save().await;
+    ctx.data()
+        .data_manager
+        .journal_settings(&guild_key(ctx)?, &["locale"])
+        .await;
+
+    ctx.send(
+        poise::CreateReply::default()
+            .content(format!(
+                "✅ Catch posts, reminders, and summaries are now in **{}**. Custom `/setmessage` texts stay as written.",
+                locale.label()
+            ))
+            .ephemeral(true),
+    )
+    .await?;
+    Ok(())
+}
+
+/// Set how big the legendary catch celebration is (0 turns it off)
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn setfireworks(
+    ctx: Context<'_>,
+    #[description = "0 = off, 1 = subtle, 3 = over the top"]
+    #[min = 0]
+    #[max = 3]
+    intensity: u8,
+) -> Result<(), Error> {
+    {
+        let mut data = ctx.data().data_manager.guild_mut(&guild_key(ctx)?).await;
+        data.fireworks_intensity = intensity;
+    }
+    ctx.data().data_manager.save().await;
+    ctx.data()
+        .data_manager
+        .journal_settings(&guild_key(ctx)?, &["fireworksIntensity"])
+        .await;
+
+    let content = if intensity == 0 {
+        "✅ Legendary fireworks have been turned off.".to_string()
+    } else {
+        format!(
+            "✅ Legendary catches will now set off intensity **{}** fireworks! 🎆",
+            intensity
+        )
+    };
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Set the time of day (server time) this server's fishing day ends
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn setresettime(
+    ctx: Context<'_>,
+    #[description = "24-hour time in the server's timezone as HH:MM, e.g. 14:30"] time: String,
+) -> Result<(), Error> {
+    let Ok(reset_time) = chrono::NaiveTime::parse_from_str(time.trim(), "%H:%M") else {
+        ctx.send(
+            poise::CreateReply::default()
+                .content("❌ Use 24-hour HH:MM, e.g. `14:30`.")
+                .ephemeral(true),
+        )
+        .await?;
+        return Ok(());
+    };
+    let tz = ctx
+        .data()
+        .fishing_manager
+        .set_reset_time(&guild_key(ctx)?, reset_time)
+        .await;
+    ctx.data().data_manager.save().await;
+    ctx.data()
+        .data_manager
+        .journal_settings(&guild_key(ctx)?, &["resetTime", "lastResetTimestamp"])
+        .await;
+
+    let next = ctx
+        .data()
+        .fishing_manager
+        .next_guild_reset(&guild_key(ctx)?)
+        .await;
+    ctx.send(
+        poise::CreateReply::default()
+            .content(format!(
+                "✅ The daily reset now runs at **{} {}**. The next one is <t:{}:F> (<t:{}:R>).",
+                reset_time.format("%H:%M"),
+                tz.name(),
+                next.timestamp(),
+                next.timestamp()
+            ))
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Set the time of day (server time) the daily summary posts
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn setsummarytime(
+    ctx: Context<'_>,
+    #[description = "24-hour time in the server's timezone as HH:MM (leave empty to post with the reset)"]
+    time: Option<String>,
+) -> Result<(), Error> {
+    let summary_time = match time.as_deref() {
+        Some(time) => match chrono::NaiveTime::parse_from_str(time.trim(), "%H:%M") {
+            Ok(at) => Some(at),
+            Err(_) => {
+                ctx.send(
+                    poise::CreateReply::default()
+                        .content("❌ Use 24-hour HH:MM, e.g. `18:00`.")
+                        .ephemeral(true),
+                )
+                .await?;
+                return Ok(());
+            }
+        },
+        None => None,
+    };
+    let tz = {
+        let mut data = ctx.data().data_manager.guild_mut(&guild_key(ctx)?).await;
+        data.summary_time = summary_time;
+        data.tz()
+    };
+    ctx.data().data_manager.save().await;
+    ctx.data()
+        .data_manager
+        .journal_settings(&guild_key(ctx)?, &["summaryTime"])
+        .await;
+
+    let content = match ctx
+        .data()
+        .fishing_manager
+        .next_guild_summary(&guild_key(ctx)?)
+        .await
+        .zip(summary_time)
+    {
+        Some((next, at)) => format!(
+            "✅ The daily summary now posts at **{} {}**, apart from the reset. The next one is <t:{}:F> (<t:{}:R>).",
+            at.format("%H:%M"),
+            tz.name(),
+            next.timestamp(),
+            next.timestamp()
+        ),
+        None => "✅ The daily summary posts just before the reset again.".to_string(),
+    };
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Set the timezone this server's days follow
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn setguildtimezone(
+    ctx: Context<'_>,
+    #[description = "IANA timezone, e.g. Europe/Berlin (leave empty for UTC)"]
+    #[autocomplete = "super::autocomplete_timezone"]
+    timezone: Option<String>,
+) -> Result<(), Error> {
+    let tz = match timezone.as_deref() {
+        Some(name) => {
+            match crate::game::parse_timezone(name) {
+                Some(tz) => Some(tz),
+                None => {
+                    ctx.send(
+                    poise::CreateReply::default()
+                        .content("❌ Unknown timezone. Pick one from the list, e.g. `Europe/Berlin`.")
+                        .ephemeral(true),
+                )
+                .await?;
+                    return Ok(());
+                }
+            }
+        }
+        None => None,
+    };
+    let reset_at = ctx
+        .data()
+        .fishing_manager
+        .set_guild_timezone(&guild_key(ctx)?, tz)
+        .await;
+    ctx.data().data_manager.save().await;
+    ctx.data()
+        .data_manager
+        .journal_settings(&guild_key(ctx)?, &["timezone", "lastResetTimestamp"])
+        .await;
+
+    let next = ctx
+        .data()
+        .fishing_manager
+        .next_guild_reset(&guild_key(ctx)?)
+        .await;
+    ctx.send(
+        poise::CreateReply::default()
+            .content(format!(
+                "✅ Days now follow **{}**; the reset runs at {} local time. The next one is <t:{}:F> (<t:{}:R>).",
+                tz.map_or("UTC", |tz| tz.name()),
+                reset_at.format("%H:%M"),
+                next.timestamp(),
+                next.timestamp()
+            ))
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Set the number of days of inactivity before pinging a member, for
+/// everyone or for one tracked role
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn setreminderthreshold(
+    ctx: Context<'_>,
+    #[description = "Number of days (e.g., 1 for daily, 3 for every 3 days)"]
+    #[min = 1]
+    days: Option<u64>,
+    #[description = "Tracked role this applies to (leave empty for everyone else)"] role: Option<
+        serenity::Role,
+    >,
+) -> Result<(), Error> {
+    let content = {
+        let mut data = ctx.data().data_manager.guild_mut(&guild_key(ctx)?).await;
+        match (&role, days) {
+            (None, None) => None,
+            (None, Some(days)) => {
+                data.reminder_threshold = days;
+                Some(format!(
+                    "✅ Inactivity threshold set to **{} days**. Members will be pinged if they haven't fished for {} days or more.",
+                    days, days
+                ))
+            }
+            (Some(role), Some(days)) => {
+                data.role_reminder_thresholds
+                    .insert(role.id.to_string(), days);
+                let tracked = data.tracked_role_ids.contains(&role.id.to_string());
+                Some(format!(
+                    "✅ Members of **{}** will be pinged after **{} days** without fishing.{}",
+                    role.name,
+                    days,
+                    if tracked {
+                        ""
+                    } else {
+                        " The role isn't tracked yet, so add it with `/trackrole add`."
+                    }
+                ))
+            }
+            (Some(role), None) => {
+                data.role_reminder_thresholds.remove(&role.id.to_string());
+                Some(format!(
+                    "✅ Members of **{}** now use the server-wide threshold of **{} days**.",
+                    role.name, data.reminder_threshold
+                ))
+            }
+        }
+    };
+    let Some(content) = content else {
+        ctx.send(
+            poise::CreateReply::default()
+                .content(
+                    "❌ Give a number of days, or a role to put back on the server-wide threshold.",
+                )
+                .ephemeral(true),
+        )
+        .await?;
+        return Ok(());
+    };
+    ctx.data().data_manager.save().await;
+    ctx.data()
+        .data_manager
+        .journal_settings(
+            &guild_key(ctx)?,
+            &["reminderThreshold", "roleReminderThresholds"],
+        )
+        .await;
+
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Choose which roles the summary and reminders keep track of
+#[poise::command(
+    slash_command,
+    subcommands("trackrole_add", "trackrole_remove", "trackrole_list"),
+    subcommand_required,
+    guild_only,
+    default_member_permissions = "ADMINISTRATOR"
+)]
+pub async fn trackrole(_ctx: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+/// Start tracking a role for reminders and stats
+#[poise::command(slash_command, rename = "add")]
+pub async fn trackrole_add(
+    ctx: Context<'_>,
+    #[description = "The role to track"] role: serenity::Role,
+) -> Result<(), Error> {
+    let role_id = role.id.to_string();
+    let added = {
+        let mut data = ctx.data().data_manager.guild_mut(&guild_key(ctx)?).await;
+        let added = !data.tracked_role_ids.contains(&role_id);
+        if added {
+            data.tracked_role_ids.push(role_id);
+        }
+        added
+    };
+    let content = if added {
+        ctx.data().data_manager.save().await;
+        ctx.data()
+            .data_manager
+            .journal_settings(&guild_key(ctx)?, &["trackedRoleIds"])
+            .await;
+        format!(
+            "✅ Now tracking the **{}** role for fishing statistics!",
+            role.name
+        )
+    } else {
+        format!("ℹ️ The **{}** role is already tracked.", role.name)
+    };
+
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Stop tracking a role
+#[poise::command(slash_command, rename = "remove")]
+pub async fn trackrole_remove(
+    ctx: Context<'_>,
+    #[description = "The role to stop tracking"] role: serenity::Role,
+) -> Result<(), Error> {
+    untrack(ctx, Some(role)).await
+}
+
+/// Stop tracking one role, or every tracked role (including deleted ones)
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn untrackrole(
+    ctx: Context<'_>,
+    #[description = "Role to stop tracking (leave empty for all)"] role: Option<serenity::Role>,
+) -> Result<(), Error> {
+    untrack(ctx, role).await
+}
+
+/// Drop `role` from the tracked roles, or all of them when it's `None`
+async fn untrack(ctx: Context<'_>, role: Option<serenity::Role>) -> Result<(), Error> {
+    let removed = {
+        let mut data = ctx.data().data_manager.guild_mut(&guild_key(ctx)?).await;
+        let before = data.tracked_role_ids.len();
+        match &role {
+            Some(role) => {
+                let role_id = role.id.to_string();
+                data.tracked_role_ids.retain(|id| *id != role_id);
+            }
+            None => data.tracked_role_ids.clear(),
+        }
+        // An untracked role's threshold has nobody left to apply to
+        let data = &mut *data;
+        data.role_reminder_thresholds
+            .retain(|id, _| data.tracked_role_ids.contains(id));
+        before - data.tracked_role_ids.len()
+    };
+    if removed > 0 {
+        ctx.data().data_manager.save().await;
+        ctx.data()
+            .data_manager
+            .journal_settings(
+                &guild_key(ctx)?,
+                &["trackedRoleIds", "roleReminderThresholds"],
+            )
+            .await;
+    }
+    let content = match (role, removed) {
+        (Some(role), 0) => format!("ℹ️ The **{}** role isn't tracked.", role.name),
+        (Some(role), _) => format!("✅ No longer tracking the **{}** role.", role.name),
+        (None, 0) => "ℹ️ No roles are tracked.".to_string(),
+        (None, removed) => format!(
+            "✅ Stopped tracking all {} role{}. Reminders won't ping anyone until a role is added with `/trackrole add`.",
+            removed,
+            if removed == 1 { "" } else { "s" }
+        ),
+    };
+
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// List the tracked roles
+#[poise::command(slash_command, rename = "list")]
+pub async fn trackrole_list(ctx: Context<'_>) -> Result<(), Error> {
+    let content = {
+        let data = ctx.data().data_manager.guild(&guild_key(ctx)?).await;
+        if data.tracked_role_ids.is_empty() {
+            "👥 No roles are tracked. Add one with `/trackrole add`.".to_string()
+        } else {
+            format!(
+                "👥 **Tracked roles:**\n{}",
+                data.tracked_role_ids
+                    .iter()
+                    .map(|id| format!(
+                        "• <@&{}> — pinged after **{}** days",
+                        id,
+                        data.role_reminder_thresholds
+                            .get(id)
+                            .unwrap_or(&data.reminder_threshold)
+                    ))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            )
+        }
+    };
+
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .allowed_mentions(serenity::CreateAllowedMentions::new())
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Manage blackout days, when nobody's streak breaks for not fishing
+#[poise::command(
+    slash_command,
+    subcommands("blackout_add", "blackout_remove", "blackout_list"),
+    subcommand_required,
+    guild_only,
+    default_member_permissions = "ADMINISTRATOR"
+)]
+pub async fn blackout(_ctx: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+/// "2024-12-25" as is, or "12-25" for every year; `None` if it isn't a date
+fn parse_blackout_date(text: &str) -> Option<String> {
+    let text = text.trim();
+    let full = match text.len() {
+        10 => text.to_string(),
+        // 2000 is a leap year, so 02-29 is accepted
+        5 => format!("2000-{}", text),
+        _ => return None,
+    };
+    chrono::NaiveDate::parse_from_str(&full, "%Y-%m-%d").ok()?;
+    Some(text.to_string())
+}
+
+/// "25 Dec 2024", or "25 Dec (every year)"
+fn blackout_label(date: &str) -> String {
+    match chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+        Ok(day) => day.format("%-d %b %Y").to_string(),
+        Err(_) => chrono::NaiveDate::parse_from_str(&format!("2000-{}", date), "%Y-%m-%d")
+            .map_or(date.to_string(), |day| {
+                format!("{} (every year)", day.format("%-d %b"))
+            }),
+    }
+}
+
+/// Add a blackout day
+#[poise::command(slash_command, rename = "add")]
+pub async fn blackout_add(
+    ctx: Context<'_>,
+    #[description = "YYYY-MM-DD for one day, or MM-DD for every year"] date: String,
+) -> Result<(), Error> {
+    let Some(date) = parse_blackout_date(&date) else {
+        ctx.send(
+            poise::CreateReply::default()
+                .content("📅 Use YYYY-MM-DD for one day (e.g. 2024-12-25) or MM-DD for every year (e.g. 12-25).")
+                .ephemeral(true),
+        )
+        .await?;
+        return Ok(());
+    };
+    let added = ctx
+        .data()
+        .data_manager
+        .guild_mut(&guild_key(ctx)?)
+        .await
+        .blackout_dates
+        .insert(date.clone());
+    let content = if added {
+        ctx.data().data_manager.save().await;
+        ctx.data()
+            .data_manager
+            .journal_settings(&guild_key(ctx)?, &["blackoutDates"])
+            .await;
+        format!(
+            "🕯️ **{}** is now a blackout day. Streaks won't break for anyone who skips fishing, and the summary won't ping.",
+            blackout_label(&date)
+        )
+    } else {
+        format!(
+            "ℹ️ **{}** is already a blackout day.",
+            blackout_label(&date)
+        )
+    };
+
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Remove a blackout day
+#[poise::command(slash_command, rename = "remove")]
+pub async fn blackout_remove(
+    ctx: Context<'_>,
+    #[description = "The date, written as it was added"] date: String,
+) -> Result<(), Error> {
+    let date = date.trim();
+    let removed = ctx
+        .data()
+        .data_manager
+        .guild_mut(&guild_key(ctx)?)
+        .await
+        .blackout_dates
+        .remove(date);
+    let content = if removed {
+        ctx.data().data_manager.save().await;
+        ctx.data()
+            .data_manager
+            .journal_settings(&guild_key(ctx)?, &["blackoutDates"])
+            .await;
+        format!(
+            "✅ **{}** is no longer a blackout day.",
+            blackout_label(date)
+        )
+    } else {
+        format!("ℹ️ `{}` isn't a blackout day. See `/blackout list`.", date)
+    };
+
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// List the blackout days
+#[poise::command(slash_command, rename = "list")]
+pub async fn blackout_list(ctx: Context<'_>) -> Result<(), Error> {
+    let dates = ctx
+        .data()
+        .data_manager
+        .guild(&guild_key(ctx)?)
+        .await
+        .blackout_dates
+        .clone();
+    let content = if dates.is_empty() {
+        "🕯️ No blackout days are set. Add one with `/blackout add`.".to_string()
+    } else {
+        format!(
+            "🕯️ **Blackout days:**\n{}",
+            dates
+                .iter()
+                .map(|date| format!("• `{}` — {}", date, blackout_label(date)))
+                .collect::<Vec<_>>()
+                .join("\n")
+        )
+    };
+
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[derive(poise::ChoiceParameter)]
+pub enum WeekdayChoice {
+    #[name = "Weekends (Saturday and Sunday)"]
+    Weekends,
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+/// Weekdays like "Sat, Sun", or *none*
+fn weekday_list(days: &[chrono::Weekday]) -> String {
+    if days.is_empty() {
+        "*none*".to_string()
+    } else {
+        days.iter()
+            .map(|day| day.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// Skip the summary's reminders on chosen days of the week, or bring them back
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn quietdays(
+    ctx: Context<'_>,
+    #[description = "The day, or weekends"] day: WeekdayChoice,
+    #[description = "False to remind on this day again (defaults to true)"] quiet: Option<bool>,
+) -> Result<(), Error> {
+    use chrono::Weekday;
+
+    let quiet = quiet.unwrap_or(true);
+    let days = match day {
+        WeekdayChoice::Weekends => vec![Weekday::Sat, Weekday::Sun],
+        WeekdayChoice::Monday => vec![Weekday::Mon],
+        WeekdayChoice::Tuesday => vec![Weekday::Tue],
+        WeekdayChoice::Wednesday => vec![Weekday::Wed],
+        WeekdayChoice::Thursday => vec![Weekday::Thu],
+        WeekdayChoice::Friday => vec![Weekday::Fri],
+        WeekdayChoice::Saturday => vec![Weekday::Sat],
+        WeekdayChoice::Sunday => vec![Weekday::Sun],
+    };
+    let quiet_weekdays = {
+        let mut data = ctx.data().data_manager.guild_mut(&guild_key(ctx)?).await;
+        data.quiet_weekdays.retain(|day| !days.contains(day));
+        if quiet {
+            data.quiet_weekdays.extend(days);
+        }
+        data.quiet_weekdays
+            .sort_by_key(|day| day.num_days_from_monday());
+        data.quiet_weekdays.clone()
+    };
+    ctx.data().data_manager.save().await;
+    ctx.data()
+        .data_manager
+        .journal_settings(&guild_key(ctx)?, &["quietWeekdays"])
+        .await;
+
+    ctx.send(
+        poise::CreateReply::default()
+            .content(if quiet_weekdays.is_empty() {
+                "✅ The summary reminds members every day of the week.".to_string()
+            } else {
+                format!(
+                    "✅ The summary won't remind anyone on: {}. Catches and streaks count as usual.",
+                    weekday_list(&quiet_weekdays)
+                )
+            })
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Set the streamer whose go-live starts a stream frenzy in the pond
+#[cfg(feature = "web")]
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn setstreamer(
+    ctx: Context<'_>,
+    #[description = "Twitch login or YouTube channel name (leave empty to disable)"] name: Option<
+        String,
+    >,
+    #[description = "How long the frenzy lasts, in minutes"]
+    #[min = 5]
+    #[max = 720]
+    minutes: Option<u64>,
+) -> Result<(), Error> {
+    let minutes = {
+        let mut data = ctx.data().data_manager.guild_mut(&guild_key(ctx)?).await;
+        data.streamer_name = name.clone();
+        if let Some(minutes) = minutes {
+            data.stream_frenzy_minutes = minutes;
+        }
+        data.stream_frenzy_minutes
+    };
+    ctx.data().data_manager.save().await;
+    ctx.data()
+        .data_manager
+        .journal_settings(&guild_key(ctx)?, &["streamerName", "streamFrenzyMinutes"])
+        .await;
+
+    let content = match name {
+        Some(name) => format!(
+            "✅ When **{}** goes live, the pond gets a **{}-minute** stream frenzy!",
+            name, minutes
+        ),
+        None => "✅ Stream frenzies have been disabled.".to_string(),
+    };
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Set the channel for daily summaries
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn setsummarychannel(ctx: Context<'_>) -> Result<(), Error> {
+    {
+        let mut data = ctx.data().data_manager.guild_mut(&guild_key(ctx)?).await;
+        data.summary_channel_id = Some(ctx.channel_id().to_string());
+    }
+    ctx.data().data_manager.save().await;
+    ctx.data()
+        .data_manager
+        .journal_settings(&guild_key(ctx)?, &["summaryChannelId"])
+        .await;
+
+    ctx.send(
+        poise::CreateReply::default()
+            .content("✅ Daily summaries will be posted in this channel!")
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Post the daily summary to more channels than the main one
+#[poise::command(
+    slash_command,
+    subcommands("summarychannels_add", "summarychannels_remove"),
+    subcommand_required,
+    guild_only,
+    default_member_permissions = "ADMINISTRATOR"
+)]
+pub async fn summarychannels(_ctx: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+#[derive(poise::ChoiceParameter)]
+pub enum ReminderModeChoice {
+    #[name = "Ping members who haven't fished"]
+    Ping,
+    #[name = "List them without pinging"]
+    List,
+    #[name = "Leave them out"]
+    Off,
+}
+
+/// Also post the daily summary in a channel, or change what it does with reminders
+#[poise::command(slash_command, rename = "add")]
+pub async fn summarychannels_add(
+    ctx: Context<'_>,
+    #[description = "What to do with members who haven't fished"] reminders: ReminderModeChoice,
+    #[description = "The channel (defaults to this one)"]
+    #[channel_types("Text")]
+    channel: Option<serenity::GuildChannel>,
+) -> Result<(), Error> {
+    use crate::data::{ReminderMode, SummaryChannel};
+
+    let channel_id = channel.map_or(ctx.channel_id(), |channel| channel.id);
+    let reminder = match reminders {
+        ReminderModeChoice::Ping => ReminderMode::Ping,
+        ReminderModeChoice::List => ReminderMode::List,
+        ReminderModeChoice::Off => ReminderMode::Off,
+    };
+    let is_main = {
+        let mut data = ctx.data().data_manager.guild_mut(&guild_key(ctx)?).await;
+        let is_main = data.summary_channel_id == Some(channel_id.to_string());
+        if !is_main {
+            match data
+                .extra_summary_channels
+                .iter_mut()
+                .find(|extra| extra.channel_id == channel_id.to_string())
+            {
+                Some(extra) => extra.reminder = reminder,
+                None => data.extra_summary_channels.push(SummaryChannel {
+                    channel_id: channel_id.to_string(),
+                    reminder,
+                }),
+            }
+        }
+        is_main
+    };
+    let content = if is_main {
+        format!(
+            "ℹ️ <#{}> is the main summary channel; use `/togglereminder` for its pings.",
+            channel_id
+        )
+    } else {
+        ctx.data().data_manager.save().await;
+        ctx.data()
+            .data_manager
+            .journal_settings(&guild_key(ctx)?, &["extraSummaryChannels"])
+            .await;
+        format!(
+            "✅ The daily summary will also be posted in <#{}> ({}).",
+            channel_id,
+            reminder.label()
+        )
+    };
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Stop posting the daily summary in an extra channel
+#[poise::command(slash_command, rename = "remove")]
+pub async fn summarychannels_remove(
+    ctx: Context<'_>,
+    #[description = "The channel (defaults to this one)"]
+    #[channel_types("Text")]
+    channel: Option<serenity::GuildChannel>,
+) -> Result<(), Error> {
+    let channel_id = channel.map_or(ctx.channel_id(), |channel| channel.id);
+    let removed = {
+        let mut data = ctx.data().data_manager.guild_mut(&guild_key(ctx)?).await;
+        let before = data.extra_summary_channels.len();
+        data.extra_summary_channels
+            .retain(|extra| extra.channel_id != channel_id.to_string());
+        data.extra_summary_channels.len() != before
+    };
+    let content = if removed {
+        ctx.data().data_manager.save().await;
+        ctx.data()
+            .data_manager
+            .journal_settings(&guild_key(ctx)?, &["extraSummaryChannels"])
+            .await;
+        format!(
+            "✅ The daily summary won't be posted in <#{}> any more.",
+            channel_id
+        )
+    } else {
+        format!("ℹ️ <#{}> isn't an extra summary channel.", channel_id)
+    };
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Find tracked members who stopped fishing, so the reminder list doesn't grow forever
+#[poise::command(
+    slash_command,
+    subcommands("inactive_report", "inactive_auto"),
+    subcommand_required,
+    guild_only,
+    default_member_permissions = "ADMINISTRATOR"
+)]
+pub async fn inactive(_ctx: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+/// Most members one inactivity report lists, the limit of a select menu
+const MAX_INACTIVE_LISTED: usize = 25;
+
+/// How long the inactivity report's buttons wait for an answer
+const INACTIVE_REPORT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(300);
+
+/// List tracked members who haven't fished in a month, to untrack or exempt them
+#[poise::command(slash_command, rename = "report")]
+pub async fn inactive_report(ctx: Context<'_>) -> Result<(), Error> {
+    use crate::game::INACTIVE_DAYS;
+
+    let ephemeral = |content: String| {
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true)
+    };
+    ctx.defer_ephemeral().await?;
+    let guild_id = guild_key(ctx)?;
+    let Some(g_id) = ctx.guild_id() else {
+        return Ok(());
+    };
+    let tracked_role_ids = ctx
+        .data()
+        .data_manager
+        .guild(&guild_id)
+        .await
+        .tracked_role_ids
+        .clone();
+    if tracked_role_ids.is_empty() {
+        ctx.send(ephemeral(
+            "❌ No tracked roles are set. Add one with `/trackrole add`.".to_string(),
+        ))
+        .await?;
+        return Ok(());
+    }
+
+    let fishing_manager = &ctx.data().fishing_manager;
+    let members = match crate::roles::tracked_member_roles(
+        fishing_manager,
+        ctx.http(),
+        g_id,
+        &tracked_role_ids,
+    )
+    .await
+    {
+        Ok(members) => members,
+        Err(e) => {
+            ctx.send(ephemeral(format!("❌ Could not fetch members: {}", e)))
+                .await?;
+            return Ok(());
+        }
+    };
+    let ids: Vec<String> = members.iter().map(|(id, _)| id.to_string()).collect();
+    let mut inactive = fishing_manager.inactive_players(&guild_id, &ids).await;
+    if inactive.is_empty() {
+        ctx.send(ephemeral(format!(
+            "✅ Every tracked member who gets reminders has fished in the last {} days.",
+            INACTIVE_DAYS
+        )))
+        .await?;
+        return Ok(());
+    }
+    let total = inactive.len();
+    inactive.truncate(MAX_INACTIVE_LISTED);
+
+    let idle = |days_idle: Option<i64>| match days_idle {
+        Some(days) => format!("{} days idle", days),
+        None => "never fished".to_string(),
+    };
+    let lines = inactive
+        .iter()
+        .map(|player| format!("• <@{}> — {}", player.user_id, idle(player.days_idle)))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let shown = if total > inactive.len() {
+        format!(" (the {} idle longest shown)", inactive.len())
+    } else {
+        String::new()
+    };
+    let content = format!(
+        "💤 **{}** tracked members haven't fished in {}+ days{}:\n{}\n\nEveryone listed is picked; narrow it down in the menu, then take their tracked roles away or leave them out of reminders.",
+        total, INACTIVE_DAYS, shown, lines
+    );
+
+    let prefix = ctx.id();
+    let options = inactive
+        .iter()
+        .map(|player| {
+            serenity::CreateSelectMenuOption::new(
+                player
+                    .username
+                    .clone()
+                    .unwrap_or_else(|| format!("Member {}", player.user_id)),
+                player.user_id.clone(),
+            )
+            .description(idle(player.days_idle))
+            .default_selection(true)
+        })
+        .collect();
+    let components = vec![
+        serenity::CreateActionRow::SelectMenu(
+            serenity::CreateSelectMenu::new(
+                format!("{}pick", prefix),
+                serenity::CreateSelectMenuKind::String { options },
+            )
+            .min_values(1)
+            .max_values(inactive.len() as u8),
+        ),
+        serenity::CreateActionRow::Buttons(vec![
+            serenity::CreateButton::new(format!("{}untrack", prefix))
+                .label("🏷️ Remove tracked roles")
+                .style(serenity::ButtonStyle::Danger),
+            serenity::CreateButton::new(format!("{}exempt", prefix))
+                .label("🔕 Exempt from reminders")
+                .style(serenity::ButtonStyle::Primary),
+            serenity::CreateButton::new(format!("{}cancel", prefix))
+                .label("Cancel")
+                .style(serenity::ButtonStyle::Secondary),
+        ]),
+    ];
+    let reply = ctx.send(ephemeral(content).components(components)).await?;
+    let message = reply.message().await?;
+
+    let mut picked: Vec<String> = inactive
+        .iter()
+        .map(|player| player.user_id.clone())
+        .collect();
+    let author = ctx.author().id;
+    let outcome = loop {
+        let Some(interaction) = serenity::ComponentInteractionCollector::new(ctx)
+            .message_id(message.id)
+            .filter(move |i| i.user.id == author)
+            .timeout(INACTIVE_REPORT_TIMEOUT)
+            .await
+        else {
+            break "⌛ No action taken: no answer in time.".to_string();
+        };
+        interaction
+            .create_response(ctx, serenity::CreateInteractionResponse::Acknowledge)
+            .await?;
+        if let serenity::ComponentInteractionDataKind::StringSelect { values } =
+            &interaction.data.kind
+        {
+            picked = values.clone();
+            continue;
+        }
+
+        let action = interaction
+            .data
+            .custom_id
+            .strip_prefix(&prefix.to_string())
+            .unwrap_or_default();
+        match action {
+            "untrack" => {
+                let mut untracked = 0;
+                for (user_id, roles) in &members {
+                    if picked.contains(&user_id.to_string())
+                        && crate::roles::remove_tracked_roles(
+                            ctx.http(),
+                            g_id,
+                            *user_id,
+                            roles,
+                            "Stopped fishing",
+                        )
+                        .await
+                    {
+                        untracked += 1;
+                    }
+                }
+                break if untracked == picked.len() {
+                    format!("✅ Took the tracked roles off **{}** members.", untracked)
+                } else {
+                    format!(
+                        "⚠️ Took the tracked roles off **{}** of **{}** members. The bot needs **Manage Roles** and must sit above the tracked roles.",
+                        untracked,
+                        picked.len()
+                    )
+                };
+            }
+            "exempt" => {
+                {
+                    let mut data = ctx.data().data_manager.guild_mut(&guild_id).await;
+                    data.reminder_exclusions.extend(picked.iter().cloned());
+                }
+                ctx.data().data_manager.save().await;
+                ctx.data()
+                    .data_manager
+                    .journal_settings(&guild_id, &["reminderExclusions"])
+                    .await;
+                break format!(
+                    "✅ Left **{}** members out of reminders. Add anyone back with `/excludeuser excluded:False`.",
+                    picked.len()
+                );
+            }
+            _ => break "No action taken.".to_string(),
+        }
+    };
+
+    reply
+        .edit(
+            ctx,
+            poise::CreateReply::default()
+                .content(outcome)
+                .components(Vec::new()),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Take the tracked roles off members who stopped fishing a month ago, at each reset
+#[poise::command(slash_command, rename = "auto")]
+pub async fn inactive_auto(
+    ctx: Context<'_>,
+    #[description = "True to untrack inactive members at each reset, false to stop"] enabled: bool,
+) -> Result<(), Error> {
+    {
+        let mut data = ctx.data().data_manager.guild_mut(&guild_key(ctx)?).await;
+        data.auto_untrack_inactive = enabled;
+    }
+    ctx.data().data_manager.save().await;
+    ctx.data()
+        .data_manager
+        .journal_settings(&guild_key(ctx)?, &["autoUntrackInactive"])
+        .await;
+
+    let content = if enabled {
+        format!(
+            "✅ At each reset, members who haven't fished in {}+ days lose the tracked roles. Members who never fished are left alone. The bot needs **Manage Roles**.",
+            crate::game::INACTIVE_DAYS
+        )
+    } else {
+        "✅ Inactive members keep their tracked roles.".to_string()
+    };
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Leave a member out of the daily reminder pings, or add them back
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn excludeuser(
+    ctx: Context<'_>,
+    #[description = "The member (e.g. a bot, alt account, or staff)"] user: serenity::User,
+    #[description = "False to ping them again (defaults to true)"] excluded: Option<bool>,
+) -> Result<(), Error> {
+    let excluded = excluded.unwrap_or(true);
+    {
+        let mut data = ctx.data().data_manager.guild_mut(&guild_key(ctx)?).await;
+        if excluded {
+            data.reminder_exclusions.insert(user.id.to_string());
+        } else {
+            data.reminder_exclusions.remove(&user.id.to_string());
+        }
+    }
+    ctx.data().data_manager.save().await;
+    ctx.data()
+        .data_manager
+        .journal_settings(&guild_key(ctx)?, &["reminderExclusions"])
+        .await;
+
+    let content = if excluded {
+        format!(
+            "🔕 **{}** won't be pinged by the daily reminder.",
+            user.name
+        )
+    } else {
+        format!(
+            "🔔 **{}** will be pinged by the daily reminder again.",
+            user.name
+        )
+    };
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Keep a member from fishing here, for a while or until `/fishunban`
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn fishban(
+    ctx: Context<'_>,
+    #[description = "The member to ban"] user: serenity::User,
+    #[description = "Shown to them when they try to fish"]
+    #[max_length = 200]
+    reason: Option<String>,
+    #[description = "How long, e.g. 12h, 3d, or 2w (empty = no end)"] duration: Option<String>,
+) -> Result<(), Error> {
+    let length = duration.as_deref().map(parse_duration);
+    if length == Some(None) {
+        ctx.send(
+            poise::CreateReply::default()
+                .content("❌ Use a number and a unit, e.g. `30m`, `12h`, `3d`, or `2w`.")
+                .ephemeral(true),
+        )
+        .await?;
+        return Ok(());
+    }
+    let now_millis = chrono::Utc::now().timestamp_millis() as u64;
+    let ban = crate::data::FishBan {
+        reason: reason
+            .map(|text| text.trim().to_string())
+            .filter(|text| !text.is_empty()),
+        banned_by: ctx.author().id.to_string(),
+        banned_at: now_millis,
+        until: length
+            .flatten()
+            .map(|length| now_millis + length.num_milliseconds() as u64),
+    };
+    let until = ban.until;
+    {
+        let mut data = ctx.data().data_manager.guild_mut(&guild_key(ctx)?).await;
+        data.fish_bans.retain(|_, ban| ban.is_active(now_millis));
+        data.fish_bans.insert(user.id.to_string(), ban);
+    }
+    ctx.data().data_manager.save().await;
+    ctx.data()
+        .data_manager
+        .journal_settings(&guild_key(ctx)?, &["fishBans"])
+        .await;
+
+    let content = match until {
+        Some(until) => format!(
+            "🚫 **{}** can't fish here until <t:{}:f>.",
+            user.name,
+            until / 1000
+        ),
+        None => format!(
+            "🚫 **{}** can't fish here until you lift it with `/fishunban`.",
+            user.name
+        ),
+    };
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// "30m", "12h", "3d" or "2w" as a duration
+fn parse_duration(text: &str) -> Option<chrono::Duration> {
+    let text = text.trim().to_lowercase();
+    let unit = text.chars().last()?;
+    let amount = text[..text.len() - unit.len_utf8()]
+        .trim()
+        .parse::<i64>()
+        .ok()?;
+    if amount <= 0 {
+        return None;
+    }
+    match unit {
+        'm' => chrono::Duration::try_minutes(amount),
+        'h' => chrono::Duration::try_hours(amount),
+        'd' => chrono::Duration::try_days(amount),
+        'w' => chrono::Duration::try_weeks(amount),
+        _ => None,
+    }
+}
+
+/// Let a banned member fish again
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn fishunban(
+    ctx: Context<'_>,
+    #[description = "The member to let back in"] user: serenity::User,
+) -> Result<(), Error> {
+    let now_millis = chrono::Utc::now().timestamp_millis() as u64;
+    let was_banned = ctx
+        .data()
+        .data_manager
+        .guild_mut(&guild_key(ctx)?)
+        .await
+        .fish_bans
+        .remove(&user.id.to_string())
+        .is_some_and(|ban| ban.is_active(now_millis));
+    ctx.data().data_manager.save().await;
+    ctx.data()
+        .data_manager
+        .journal_settings(&guild_key(ctx)?, &["fishBans"])
+        .await;
+
+    let content = if was_banned {
+        format!("🎣 **{}** can fish again.", user.name)
+    } else {
+        format!("**{}** isn't banned from fishing.", user.name)
+    };
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// List the members banned from fishing
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn fishbans(ctx: Context<'_>) -> Result<(), Error> {
+    let now_millis = chrono::Utc::now().timestamp_millis() as u64;
+    let mut bans: Vec<(String, crate::data::FishBan)> = ctx
+        .data()
+        .data_manager
+        .guild(&guild_key(ctx)?)
+        .await
+        .fish_bans
+        .iter()
+        .filter(|(_, ban)| ban.is_active(now_millis))
+        .map(|(id, ban)| (id.clone(), ban.clone()))
+        .collect();
+
+    let content = if bans.is_empty() {
+        "Nobody is banned from fishing.".to_string()
+    } else {
+        bans.sort_by_key(|(_, ban)| ban.banned_at);
+        let lines = bans
+            .iter()
+            .map(|(user_id, ban)| {
+                format!(
+                    "<@{}> — {} (by <@{}>, <t:{}:d>){}",
+                    user_id,
+                    ban.until.map_or("no end".to_string(), |until| format!(
+                        "until <t:{}:f>",
+                        until / 1000
+                    )),
+                    ban.banned_by,
+                    ban.banned_at / 1000,
+                    ban.reason
+                        .as_ref()
+                        .map(|reason| format!("\n> {}", reason))
+                        .unwrap_or_default()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        format!("🚫 **Banned from fishing**\n{}", lines)
+    };
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .allowed_mentions(serenity::CreateAllowedMentions::new())
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Freeze the pond for maintenance or an off-season, or thaw it again
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn pausefishing(
+    ctx: Context<'_>,
+    #[description = "True to freeze the pond, false to open it again"] paused: bool,
+) -> Result<(), Error> {
+    let guild_id = guild_key(ctx)?;
+    let changed = ctx
+        .data()
+        .fishing_manager
+        .set_paused(&guild_id, paused)
+        .await;
+    if changed {
+        ctx.data()
+            .data_manager
+            .journal_settings(&guild_id, &["pausedSince", "lastResetTimestamp"])
+            .await;
+        crate::events::refresh_fish_buttons(ctx.http(), &ctx.data().data_manager, &guild_id).await;
+    }
+
+    let content = match (paused, changed) {
+        (true, true) => "🧊 The pond is frozen. Casts, daily resets, and summaries are paused, and streaks are kept as they are.",
+        (false, true) => "🌊 The pond has thawed. Fishing is open again and streaks carry on from where they were.",
+        (true, false) => "🧊 The pond is already frozen.",
+        (false, false) => "🌊 The pond isn't frozen.",
+    };
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Enable or disable pinging members in the daily fishing reminder
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn togglereminder(
+    ctx: Context<'_>,
+    #[description = "Set to true to enable pings, false to disable (shows nicknames instead)"]
+    enabled: bool,
+) -> Result<(), Error> {
+    {
+        let mut data = ctx.data().data_manager.guild_mut(&guild_key(ctx)?).await;
+        data.ping_reminder_enabled = enabled;
+    }
+    ctx.data().data_manager.save().await;
+    ctx.data()
+        .data_manager
+        .journal_settings(&guild_key(ctx)?, &["pingReminderEnabled"])
+        .await;
+
+    ctx.send(
+        poise::CreateReply::default()
+            .content(format!(
+                "✅ Daily reminder pings have been {}.",
+                if enabled { "ENABLED" } else { "DISABLED" }
+            ))
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Get a DM after each reset with who didn't fish, new milestones, and data health
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn admindigest(
+    ctx: Context<'_>,
+    #[description = "False to stop the DMs (defaults to true)"] enabled: Option<bool>,
+) -> Result<(), Error> {
+    let enabled = enabled.unwrap_or(true);
+    {
+        let mut data = ctx.data().data_manager.guild_mut(&guild_key(ctx)?).await;
+        if enabled {
+            data.admin_digest_ids.insert(ctx.author().id.to_string());
+        } else {
+            data.admin_digest_ids.remove(&ctx.author().id.to_string());
+        }
+    }
+    ctx.data().data_manager.save().await;
+    ctx.data()
+        .data_manager
+        .journal_settings(&guild_key(ctx)?, &["adminDigestIds"])
+        .await;
+
+    let content = if enabled {
+        "✅ You'll get the admin digest by DM after each daily reset. Make sure your DMs are open!"
+    } else {
+        "✅ You won't get the admin digest any more."
+    };
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Check on the bot: uptime, the next reset, saves and backups, and data size
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn status(ctx: Context<'_>) -> Result<(), Error> {
+    let guild_id = guild_key(ctx)?;
+    let data_manager = &ctx.data().data_manager;
+
+    let uptime = ctx.data().started_at.elapsed().as_secs();
+    let uptime = format!(
+        "{}d {}h {}m",
+        uptime / 86_400,
+        uptime / 3_600 % 24,
+        uptime / 60 % 60
+    );
+    let next_reset = if data_manager.guild(&guild_id).await.paused_since.is_some() {
+        "🧊 Paused while the pond is frozen".to_string()
+    } else {
+        let at = ctx.data().fishing_manager.next_guild_reset(&guild_id).await;
+        format!("<t:{0}:R> (<t:{0}:t>)", at.timestamp())
+    };
+    let since_startup = |at: Option<u64>| match at {
+        Some(at) => format!("<t:{}:R>", at / 1000),
+        None => "*not since startup*".to_string(),
+    };
+    let newest_backup = data_manager
+        .list_backups()
+        .await
+        .into_iter()
+        .next()
+        .map_or("*none*".to_string(), |name| format!("`{}`", name));
+    let size = match data_manager.stored_size().await {
+        Some(bytes) if bytes >= 1024 * 1024 => {
+            format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+        }
+        Some(bytes) => format!("{:.1} KB", bytes as f64 / 1024.0),
+        None => "*unknown*".to_string(),
+    };
+
+    let embed = serenity::CreateEmbed::new()
+        .color(0x808080)
+        .title("🩺 Bot Status")
+        .field("⏱️ Uptime", uptime, true)
+        .field("🔄 Next reset", next_reset, true)
+        .field(
+            "🌐 Servers",
+            format!(
+                "Connected: **{}**\nWith pond data: **{}**",
+                ctx.cache().guild_count(),
+                data_manager.guild_ids().await.len()
+            ),
+            true,
+        )
+        .field(
+            "💾 Saves",
+            format!(
+                "Last save: {}\nLast backup: {}\nNewest backup: {}",
+                since_startup(data_manager.last_saved_at()),
+                since_startup(data_manager.last_backup_at()),
+                newest_backup
+            ),
+            false,
+        )
+        .field("📦 Data file", size, true);
+
+    ctx.send(poise::CreateReply::default().embed(embed).ephemeral(true))
+        .await?;
+    Ok(())
+}
+
+/// Require a quick pick-the-fish check before each cast, to deter macro clickers
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn toggleverification(
+    ctx: Context<'_>,
+    #[description = "Set to true to require the check, false to cast straight away"] enabled: bool,
+) -> Result<(), Error> {
+    {
+        let mut data = ctx.data().data_manager.guild_mut(&guild_key(ctx)?).await;
+        data.verification_enabled = enabled;
+    }
+    ctx.data().data_manager.save().await;
+    ctx.data()
+        .data_manager
+        .journal_settings(&guild_key(ctx)?, &["verificationEnabled"])
+        .await;
+
+    ctx.send(
+        poise::CreateReply::default()
+            .content(format!(
+                "✅ Catch verification has been {}.",
+                if enabled { "ENABLED" } else { "DISABLED" }
+            ))
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Check on today's fishing before the daily summary goes out
+#[poise::command(
+    slash_command,
+    subcommands("fishsummary_missing", "fishsummary_preview"),
+    subcommand_required,
+    guild_only,
+    default_member_permissions = "ADMINISTRATOR"
+)]
+pub async fn fishsummary(_ctx: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+/// Show tonight's summary privately, without posting or pinging anyone
+#[poise::command(slash_command, rename = "preview")]
+pub async fn fishsummary_preview(ctx: Context<'_>) -> Result<(), Error> {
+    ctx.defer_ephemeral().await?;
+    let guild_id = guild_key(ctx)?;
+    let Some(draft) =
+        crate::summary::draft_daily_summary(&ctx.data().fishing_manager, ctx.http(), &guild_id)
+            .await
+    else {
+        ctx.send(
+            poise::CreateReply::default()
+                .content("❌ Could not build the summary for this server.")
+                .ephemeral(true),
+        )
+        .await?;
+        return Ok(());
+    };
+
+    let channels = if draft.channels.is_empty() {
+        "no channel yet (set one with `/setsummarychannel`)".to_string()
+    } else {
+        draft
+            .channels
+            .iter()
+            .map(|(channel_id, reminder)| format!("<#{}> ({})", channel_id, reminder.label()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let mut content = format!("👀 **Preview** of tonight's summary for {}:", channels);
+    let reminded = draft
+        .channels
+        .iter()
+        .any(|(_, reminder)| *reminder != crate::data::ReminderMode::Off);
+    if let Some(pings) = draft.content.filter(|_| reminded) {
+        content.push_str("\n\n");
+        content.push_str(&pings);
+    }
+    let reply = poise::CreateReply::default()
+        .content(content)
+        .embed(draft.embed)
+        .allowed_mentions(serenity::CreateAllowedMentions::new())
+        .ephemeral(true);
+    #[cfg(feature = "image-gen")]
+    let reply = reply.attachment(draft.chart);
+    ctx.send(reply).await?;
+
+    Ok(())
+}
+
+/// Get a summary of who has not fished today (for the tracked role)
+#[poise::command(slash_command, rename = "missing")]
+pub async fn fishsummary_missing(ctx: Context<'_>) -> Result<(), Error> {
+    let guild_id = match ctx.guild_id() {
+        Some(id) => id,
+        None => {
+            ctx.send(
+                poise::CreateReply::default()
+                    .content("❌ This command can only be used in a server.")
+                    .ephemeral(true),
+            )
+            .await?;
+            return Ok(());
+        }
+    };
+
+    let (tracked_role_ids, fished_ids) = {
+        let data = ctx.data().data_manager.guild(&guild_id.to_string()).await;
+        let fished: Vec<String> = data.users.keys().cloned().collect();
+        (data.tracked_role_ids.clone(), fished)
+    };
+    if tracked_role_ids.is_empty() {
+        ctx.send(
+            poise::CreateReply::default()
+                .content("❌ No role is being tracked. Use `/trackrole add` to add one.")
+                .ephemeral(true),
+        )
+        .await?;
+        return Ok(());
+    }
+    let roles = tracked_role_ids
+        .iter()
+        .map(|id| format!("<@&{}>", id))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let non_fishers: Vec<serenity::UserId> = crate::roles::tracked_members(
+        &ctx.data().fishing_manager,
+        ctx.http(),
+        guild_id,
+        &tracked_role_ids,
+    )
+    .await?
+    .into_iter()
+    .filter(|id| !fished_ids.contains(&id.to_string()))
+    .collect();
+
+    if non_fishers.is_empty() {
+        ctx.send(
+            poise::CreateReply::default()
+                .content(format!(
+                    "🎉 All members of the tracked roles ({}) have fished today!",
+                    roles
+                ))
+                .ephemeral(true),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let mentions = non_fishers
+        .iter()
+        .map(|id| format!("<@{}>", id))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    // Handle Discord's 2000 character limit for messages
+    if mentions.len() > 1850 {
+        let truncated = &mentions[..1800];
+        let last_newline = truncated.rfind('\n').unwrap_or(1800);
+        ctx.send(
+            poise::CreateReply::default()
+                .content(format!(
+                    "**Members of the tracked roles ({}) who have not fished today:**\n{} \n*...and more (too many to display)*",
+                    roles, &truncated[..last_newline]
+                ))
+                .ephemeral(true),
+        )
+        .await?;
+    } else {
+        ctx.send(
+            poise::CreateReply::default()
+                .content(format!(
+                    "**Members of the tracked roles ({}) who have not fished today:**\n{}",
+                    roles, mentions
+                ))
+                .ephemeral(true),
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// List tracked members who didn't fish on a past day
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn missedreport(
+    ctx: Context<'_>,
+    #[description = "The day as YYYY-MM-DD, server time (default: yesterday)"] date: Option<String>,
+) -> Result<(), Error> {
+    let guild_id = guild_key(ctx)?;
+    let fishing_manager = &ctx.data().fishing_manager;
+    let (date, fished) = match fishing_manager.fished_on(&guild_id, date.as_deref()).await {
+        Ok(result) => result,
+        Err(e) => {
+            let content = match crate::ui::fishing_error_message(&e) {
+                Some(content) => content,
+                None => return Err(e.into()),
+            };
+            ctx.send(
+                poise::CreateReply::default()
+                    .content(content)
+                    .ephemeral(true),
+            )
+            .await?;
+            return Ok(());
+        }
+    };
+    let Some(fished) = fished else {
+        ctx.send(
+            poise::CreateReply::default()
+                .content(format!(
+                    "📅 There's no record of who fished on **{}**. Only the last {} days are kept.",
+                    date,
+                    crate::data::MAX_DAILY_HISTORY
+                ))
+                .ephemeral(true),
+        )
+        .await?;
+        return Ok(());
+    };
+
+    let tracked_role_ids = ctx
+        .data()
+        .data_manager
+        .guild(&guild_id)
+        .await
+        .tracked_role_ids
+        .clone();
+    let Some(server_id) = ctx.guild_id().filter(|_| !tracked_role_ids.is_empty()) else {
+        ctx.send(
+            poise::CreateReply::default()
+                .content("❌ No role is being tracked. Use `/trackrole add` to add one.")
+                .ephemeral(true),
+        )
+        .await?;
+        return Ok(());
+    };
+    let roles = tracked_role_ids
+        .iter()
+        .map(|id| format!("<@&{}>", id))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let missed: Vec<String> =
+        crate::roles::tracked_members(fishing_manager, ctx.http(), server_id, &tracked_role_ids)
+            .await?
+            .into_iter()
+            .filter(|id| !fished.contains(&id.to_string()))
+            .map(|id| format!("<@{}>", id))
+            .collect();
+
+    let content = if missed.is_empty() {
+        format!(
+            "🎉 Every tracked member ({}) fished on **{}**!",
+            roles, date
+        )
+    } else {
+        let mut mentions = missed.join("\n");
+        // Keep within Discord's 2000 character limit
+        if mentions.len() > 1800 {
+            let cut = mentions[..1800].rfind('\n').unwrap_or(1800);
+            mentions.truncate(cut);
+            mentions.push_str("\n*...and more (too many to display)*");
+        }
+        format!(
+            "**{} tracked members ({}) missed {}:**\n{}",
+            missed.len(),
+            roles,
+            date,
+            mentions
+        )
+    };
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[derive(poise::ChoiceParameter)]
+pub enum ExportFormat {
+    #[name = "Player stats (CSV)"]
+    Csv,
+    #[name = "Full server data (JSON, for /importdata)"]
+    Json,
+}
+
+/// Download player stats as a CSV file, or this server's full data as JSON
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn exportdata(
+    ctx: Context<'_>,
+    #[description = "What to export (defaults to player stats)"] format: Option<ExportFormat>,
+) -> Result<(), Error> {
+    let guild_id = guild_key(ctx)?;
+    let date = chrono::Utc::now().format("%Y-%m-%d");
+    let (content, bytes, file_name) = match format.unwrap_or(ExportFormat::Csv) {
+        ExportFormat::Csv => (
+            "📄 Here are the current player stats.",
+            ctx.data()
+                .fishing_manager
+                .player_stats_csv(&guild_id)
+                .await
+                .into_bytes(),
+            format!("fishing_stats_{}.csv", date),
+        ),
+        ExportFormat::Json => (
+            "📦 Here is this server's full data. Upload it with `/importdata` to move it to another host.",
+            ctx.data().data_manager.export_guild(&guild_id).await?,
+            format!("fishing_data_{}_{}.json", guild_id, date),
+        ),
+    };
+
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .attachment(serenity::CreateAttachment::bytes(bytes, file_name))
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Largest upload `/importdata` accepts
+const MAX_IMPORT_BYTES: u32 = 20 * 1024 * 1024;
+
+/// Replace this server's data with a JSON file from `/exportdata`
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn importdata(
+    ctx: Context<'_>,
+    #[description = "A JSON file from /exportdata or a fishing_data.json"]
+    file: serenity::Attachment,
+) -> Result<(), Error> {
+    let guild_id = guild_key(ctx)?;
+    let ephemeral = |content: String| {
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true)
+    };
+    if file.size > MAX_IMPORT_BYTES {
+        ctx.send(ephemeral(format!(
+            "❌ That file is too large ({} MB max).",
+            MAX_IMPORT_BYTES / 1024 / 1024
+        )))
+        .await?;
+        return Ok(());
+    }
+
+    ctx.defer_ephemeral().await?;
+    let imported = match file.download().await {
+        Ok(bytes) => nightmare_fishing_core::data::parse_guild_export(&bytes, &guild_id),
+        Err(e) => Err(e.into()),
+    };
+    let imported = match imported {
+        Ok(guild) => guild,
+        Err(e) => {
+            ctx.send(ephemeral(format!(
+                "❌ `{}` can't be imported: {}",
+                file.filename, e
+            )))
+            .await?;
+            return Ok(());
+        }
+    };
+
+    let prefix = ctx.id();
+    let reply = ctx
+        .send(
+            ephemeral(format!(
+                "⚠️ Import `{}` with **{}** players? Every streak, catch, coin, and setting in this server is replaced by the file's. The current state is backed up first.",
+                file.filename,
+                imported.persistent_users.len()
+            ))
+            .components(confirm_buttons(prefix, "📥 Import", false)),
+        )
+        .await?;
+    let message = reply.message().await?;
+
+    let author = ctx.author().id;
+    let answer = serenity::ComponentInteractionCollector::new(ctx)
+        .message_id(message.id)
+        .filter(move |i| i.user.id == author)
+        .timeout(RESTORE_CONFIRM_TIMEOUT)
+        .await;
+
+    let content = match answer {
+        None => "⌛ Import cancelled: no answer in time.".to_string(),
+        Some(interaction) => {
+            interaction
+                .create_response(ctx, serenity::CreateInteractionResponse::Acknowledge)
+                .await?;
+            if interaction.data.custom_id != format!("{}confirm", prefix) {
+                "Import cancelled.".to_string()
+            } else {
+                match ctx
+                    .data()
+                    .data_manager
+                    .replace_guild(&guild_id, imported)
+                    .await
+                {
+                    Ok(()) => {
+                        ctx.data().fishing_manager.clear_cache(&guild_id).await;
+                        format!("✅ Imported this server's data from `{}`.", file.filename)
+                    }
+                    Err(e) => format!("❌ Could not import `{}`: {}", file.filename, e),
+                }
+            }
+        }
+    };
+
+    reply
+        .edit(
+            ctx,
+            poise::CreateReply::default()
+                .content(content)
+                .components(confirm_buttons(prefix, "📥 Import", true)),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Largest sheet `/importstreaks` accepts
+const MAX_STREAK_SHEET_BYTES: u32 = 1024 * 1024;
+
+/// Set streaks and catch totals from a CSV of user IDs, e.g. kept by hand
+/// before the bot
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn importstreaks(
+    ctx: Context<'_>,
+    #[description = "CSV of user_id, streak, and optionally total_catches and username"]
+    file: serenity::Attachment,
+) -> Result<(), Error> {
+    use nightmare_fishing_core::streak_csv::StreakSheet;
+    let guild_id = guild_key(ctx)?;
+    let ephemeral = |content: String| {
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true)
+    };
+    if file.size > MAX_STREAK_SHEET_BYTES {
+        ctx.send(ephemeral(format!(
+            "❌ That file is too large ({} MB max).",
+            MAX_STREAK_SHEET_BYTES / 1024 / 1024
+        )))
+        .await?;
+        return Ok(());
+    }
+
+    ctx.defer_ephemeral().await?;
+    let sheet = match file.download().await {
+        Ok(bytes) => match String::from_utf8(bytes) {
+            Ok(text) => StreakSheet::parse(&text).map_err(|e| e.to_string()),
+            Err(_) => Err("it isn't a text file".to_string()),
+        },
+        Err(e) => Err(e.to_string()),
+    };
+    let sheet = match sheet {
+        Ok(sheet) => sheet,
+        Err(e) => {
+            ctx.send(ephemeral(format!(
+                "❌ `{}` can't be imported: {}",
+                file.filename, e
+            )))
+            .await?;
+            return Ok(());
+        }
+    };
+    let new_players = {
+        let data = ctx.data().data_manager.guild(&guild_id).await;
+        sheet
+            .rows
+            .iter()
+            .filter(|row| !data.persistent_users.contains_key(&row.user_id))
+            .count()
+    };
+
+    let prefix = ctx.id();
+    let reply = ctx
+        .send(
+            ephemeral(format!(
+                "⚠️ Import streaks for **{}** players from `{}` (**{}** new here)? Their streaks are set to the sheet's, and catch totals are raised where the sheet's are higher.",
+                sheet.rows.len(),
+                file.filename,
+                new_players
+            ))
+            .components(confirm_buttons(prefix, "📋 Import", false)),
+        )
+        .await?;
+    let message = reply.message().await?;
+
+    let author = ctx.author().id;
+    let answer = serenity::ComponentInteractionCollector::new(ctx)
+        .message_id(message.id)
+        .filter(move |i| i.user.id == author)
+        .timeout(RESTORE_CONFIRM_TIMEOUT)
+        .await;
+
+    let content = match answer {
+        None => "⌛ Import cancelled: no answer in time.".to_string(),
+        Some(interaction) => {
+            interaction
+                .create_response(ctx, serenity::CreateInteractionResponse::Acknowledge)
+                .await?;
+            if interaction.data.custom_id != format!("{}confirm", prefix) {
+                "Import cancelled.".to_string()
+            } else {
+                let summary = ctx
+                    .data()
+                    .fishing_manager
+                    .import_streaks(&guild_id, &author.to_string(), sheet)
+                    .await;
+                if summary.added > 0 {
+                    // Fill in real names for players the sheet didn't name
+                    let fishing_manager = ctx.data().fishing_manager.clone();
+                    let http = ctx.serenity_context().http.clone();
+                    let guild_id = guild_id.clone();
+                    tokio::spawn(async move {
+                        crate::usernames::refresh_usernames(&fishing_manager, &http, &guild_id)
+                            .await
+                    });
+                }
+                format!(
+                    "✅ Imported streaks from `{}`: **{}** players updated, **{}** added.",
+                    file.filename, summary.merged, summary.added
+                )
+            }
+        }
+    };
+
+    reply
+        .edit(
+            ctx,
+            poise::CreateReply::default()
+                .content(content)
+                .components(confirm_buttons(prefix, "📋 Import", true)),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// How long the restore, import, reset, and merge confirmations stay open
+const RESTORE_CONFIRM_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(60);
+
+async fn autocomplete_backup(ctx: Context<'_>, partial: &str) -> Vec<serenity::AutocompleteChoice> {
+    ctx.data()
+        .data_manager
+        .list_backups()
+        .await
+        .into_iter()
+        .filter(|name| name.contains(partial))
+        .take(25)
+        .map(|name| serenity::AutocompleteChoice::new(name.clone(), name))
+        .collect()
+}
+
+/// Confirm and cancel buttons for replacing or wiping data
+fn confirm_buttons(prefix: u64, label: &str, disabled: bool) -> Vec<serenity::CreateActionRow> {
+    vec![serenity::CreateActionRow::Buttons(vec![
+        serenity::CreateButton::new(format!("{}confirm", prefix))
+            .label(label)
+            .style(serenity::ButtonStyle::Danger)
+            .disabled(disabled),
+        serenity::CreateButton::new(format!("{}cancel", prefix))
+            .label("Cancel")
+            .style(serenity::ButtonStyle::Secondary)
+            .disabled(disabled),
+    ])]
+}
+
+/// List the saved backups, or roll this server back to one of them
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn restorebackup(
+    ctx: Context<'_>,
+    #[description = "Backup to restore (leave empty to list them)"]
+    #[autocomplete = "autocomplete_backup"]
+    backup: Option<String>,
+) -> Result<(), Error> {
+    let guild_id = guild_key(ctx)?;
+    let backups = ctx.data().data_manager.list_backups().await;
+
+    let name = match backup {
+        Some(name) if backups.contains(&name) => name,
+        Some(_) => {
+            ctx.send(
+                poise::CreateReply::default()
+                    .content("❌ Unknown backup. Pick one from the suggestions.")
+                    .ephemeral(true),
+            )
+            .await?;
+            return Ok(());
+        }
+        None => {
+            let content = if backups.is_empty() {
+                "📦 There are no backups to restore yet.".to_string()
+            } else {
+                format!(
+                    "📦 **Backups** (newest first):\n{}",
+                    backups
+                        .iter()
+                        .map(|name| format!("• `{}`", name))
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                )
+            };
+            ctx.send(
+                poise::CreateReply::default()
+                    .content(content)
+                    .ephemeral(true),
+            )
+            .await?;
+            return Ok(());
+        }
+    };
+
+    let prefix = ctx.id();
+    let reply = ctx
+        .send(
+            poise::CreateReply::default()
+                .content(format!(
+                    "⚠️ Restore `{}`? Every streak, catch, coin, and setting in this server goes back to how it was then. The current state is backed up first.",
+                    name
+                ))
+                .components(confirm_buttons(prefix, "⏪ Restore", false))
+                .ephemeral(true),
+        )
+        .await?;
+    let message = reply.message().await?;
+
+    let author = ctx.author().id;
+    let answer = serenity::ComponentInteractionCollector::new(ctx)
+        .message_id(message.id)
+        .filter(move |i| i.user.id == author)
+        .timeout(RESTORE_CONFIRM_TIMEOUT)
+        .await;
+
+    let content = match answer {
+        None => "⌛ Restore cancelled: no answer in time.".to_string(),
+        Some(interaction) => {
+            interaction
+                .create_response(ctx, serenity::CreateInteractionResponse::Acknowledge)
+                .await?;
+            if interaction.data.custom_id != format!("{}confirm", prefix) {
+                "Restore cancelled.".to_string()
+            } else {
+                match ctx
+                    .data()
+                    .data_manager
+                    .restore_guild(&guild_id, &name)
+                    .await
+                {
+                    Ok(()) => {
+                        ctx.data().fishing_manager.clear_cache(&guild_id).await;
+                        format!("✅ Restored this server from `{}`.", name)
+                    }
+                    Err(e) => format!("❌ Could not restore `{}`: {}", name, e),
+                }
+            }
+        }
+    };
+
+    reply
+        .edit(
+            ctx,
+            poise::CreateReply::default()
+                .content(content)
+                .components(confirm_buttons(prefix, "⏪ Restore", true)),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Take a backup right away, e.g. before a risky change
+#[poise::command(slash_command, default_member_permissions = "ADMINISTRATOR")]
+pub async fn backupnow(ctx: Context<'_>) -> Result<(), Error> {
+    ctx.defer_ephemeral().await?;
+    let content = match ctx.data().data_manager.backup_now().await {
+        Ok(Some(info)) => format!(
+            "💾 Backed up to `{}` ({}). It can be restored with `/restorebackup`.",
+            info.name,
+            format_size(info.bytes)
+        ),
+        Ok(None) => "📦 Nothing was backed up: this storage keeps its own backups, or nothing has been saved yet.".to_string(),
+        Err(e) => format!("❌ Backup failed: {}", e),
+    };
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+    Ok(())
+}
+
+/// A byte count in the largest unit that keeps it above 1
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+/// Re-read the saved data into memory after it was fixed by hand (bot owner only)
+#[poise::command(
+    slash_command,
+    owners_only,
+    default_member_permissions = "ADMINISTRATOR"
+)]
+pub async fn reloaddata(ctx: Context<'_>) -> Result<(), Error> {
+    let content = match ctx.data().data_manager.reload().await {
+        Ok(()) => {
+            let fishing_manager = &ctx.data().fishing_manager;
+            for guild_id in fishing_manager.guild_ids().await {
+                fishing_manager.clear_cache(&guild_id).await;
+            }
+            "🔄 Reloaded the saved data. Unsaved changes from the last few seconds were dropped."
+                .to_string()
+        }
+        Err(e) => format!(
+            "❌ Could not reload the data, keeping what's in memory: {}",
+            e
+        ),
+    };
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+    Ok(())
+}
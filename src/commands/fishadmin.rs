@@ -0,0 +1,249 @@
+use super::guild_key;
+use crate::{Context, Data, Error};
+use poise::serenity_prelude as serenity;
+
+/// Debugging tools for pond admins
+#[poise::command(
+    slash_command,
+    subcommands("simulatecatch", "audit", "vacation", "forget"),
+    subcommand_required,
+    default_member_permissions = "ADMINISTRATOR"
+)]
+pub async fn fishadmin(_ctx: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+/// `/fishadmin` with every subcommand enabled in this build
+pub fn command() -> poise::Command<Data, Error> {
+    #[allow(unused_mut)]
+    let mut command = fishadmin();
+    #[cfg(feature = "metrics")]
+    command.subcommands.push(perf());
+    command
+}
+
+/// Freeze a member's streak for a declared absence (0 days cancels)
+#[poise::command(slash_command)]
+pub async fn vacation(
+    ctx: Context<'_>,
+    #[description = "The member who'll be away"] user: serenity::User,
+    #[description = "How many days they'll be away"]
+    #[min = 0]
+    #[max = 14]
+    days: u64,
+) -> Result<(), Error> {
+    let result = ctx
+        .data()
+        .fishing_manager
+        .set_vacation(&guild_key(ctx)?, &user.id.to_string(), days)
+        .await;
+    super::fishing::respond_vacation(ctx, &user, result).await
+}
+
+/// Erase a member's data, e.g. after they've left the server
+#[poise::command(slash_command)]
+pub async fn forget(
+    ctx: poise::ApplicationContext<'_, Data, Error>,
+    #[description = "The member whose data to erase"] user: serenity::User,
+) -> Result<(), Error> {
+    super::fishing::confirm_delete_data(ctx, &user).await
+}
+
+/// Dry-run a catch for a user and explain the result (nothing is saved or posted)
+#[poise::command(slash_command)]
+pub async fn simulatecatch(
+    ctx: Context<'_>,
+    #[description = "The member to simulate"] user: serenity::User,
+    #[description = "Location to cast at (defaults to the pond)"]
+    #[autocomplete = "super::autocomplete_location"]
+    location: Option<String>,
+) -> Result<(), Error> {
+    let location = location.unwrap_or_else(|| crate::fish::LOCATIONS[0].id.to_string());
+    let booster = match ctx.guild_id() {
+        Some(guild_id) => guild_id
+            .member(ctx, user.id)
+            .await
+            .is_ok_and(|member| member.premium_since.is_some()),
+        None => false,
+    };
+    let (result, breakdown) = ctx
+        .data()
+        .fishing_manager
+        .simulate_catch(
+            &guild_key(ctx)?,
+            &user.id.to_string(),
+            &user.name,
+            &location,
+            booster,
+        )
+        .await;
+
+    let mut embed = serenity::CreateEmbed::new()
+        .color(0x808080)
+        .title(format!("🧪 Simulated catch for {}", user.name))
+        .field(
+            "📅 Dates",
+            format!(
+                "Today: `{}`\nYesterday: `{}`\nLast fished: `{}`",
+                breakdown.today_date,
+                breakdown.yesterday_date,
+                breakdown.last_fished_date.as_deref().unwrap_or("never")
+            ),
+            true,
+        )
+        .field(
+            "🔥 Streak",
+            format!(
+                "Stored: **{}**\nRule: {}",
+                breakdown.previous_streak, breakdown.streak_rule
+            ),
+            true,
+        )
+        .footer(serenity::CreateEmbedFooter::new(
+            "Dry run — nothing was saved or posted",
+        ));
+
+    let modifiers = if breakdown.luck_modifiers.is_empty() {
+        "None active".to_string()
+    } else {
+        breakdown
+            .luck_modifiers
+            .iter()
+            .map(|(name, multiplier)| format!("{}: ×{:.2}", name, multiplier))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    embed = embed.field(
+        "🍀 Luck",
+        format!("{}\n**Total: ×{:.2}**", modifiers, breakdown.luck),
+        false,
+    );
+
+    embed = match result {
+        Ok(outcome) => embed.field(
+            "✅ Outcome (sample roll)",
+            format!(
+                "{} {} at {} — {} cm, {}\nStreak → **{}**, total → **{}**, +{} coins",
+                outcome.species.emoji,
+                outcome.species.name,
+                outcome.location.name,
+                outcome.size_cm,
+                outcome.species.rarity.label(),
+                outcome.streak,
+                outcome.total_catches,
+                outcome.coins_earned
+            ),
+            false,
+        ),
+        Err(e) => {
+            let mut reason = format!("`{}`", e);
+            if let Some(fished_at) = &breakdown.fished_today_at {
+                reason.push_str(&format!("\nAlready in today's list since `{}`", fished_at));
+            }
+            embed.field("❌ Would be rejected", reason, false)
+        }
+    };
+
+    ctx.send(poise::CreateReply::default().embed(embed).ephemeral(true))
+        .await?;
+    Ok(())
+}
+
+/// Show interaction latency and failures over the last hour
+#[cfg(feature = "metrics")]
+#[poise::command(slash_command)]
+pub async fn perf(ctx: Context<'_>) -> Result<(), Error> {
+    let summary = ctx.data().metrics.summary();
+
+    let failure_rate = if summary.count == 0 {
+        0.0
+    } else {
+        (summary.discord_failures + summary.bot_failures) as f64 / summary.count as f64 * 100.0
+    };
+
+    let mut embed = serenity::CreateEmbed::new()
+        .color(0x808080)
+        .title("📈 Interaction Performance (last hour)")
+        .description(
+            "Latency is measured from when Discord created the interaction until the bot finished handling it.",
+        )
+        .field("Interactions", format!("**{}**", summary.count), true)
+        .field(
+            "Latency",
+            format!(
+                "p50: **{} ms**\np95: **{} ms**\nmax: **{} ms**",
+                summary.p50_ms, summary.p95_ms, summary.max_ms
+            ),
+            true,
+        )
+        .field(
+            "Failures",
+            format!(
+                "Discord API: **{}**\nBot: **{}**\nRate: **{:.1}%**",
+                summary.discord_failures, summary.bot_failures, failure_rate
+            ),
+            true,
+        );
+
+    if !summary.recent_failures.is_empty() {
+        let recent = summary
+            .recent_failures
+            .iter()
+            .map(|f| {
+                let message: String = f.message.chars().take(120).collect();
+                format!(
+                    "<t:{}:R> `{}` ({:?}): {}",
+                    f.at_millis / 1000,
+                    f.name,
+                    f.source,
+                    message
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        embed = embed.field("Recent failures", recent, false);
+    }
+
+    ctx.send(poise::CreateReply::default().embed(embed).ephemeral(true))
+        .await?;
+    Ok(())
+}
+
+/// Show the most recent audit trail entries
+#[poise::command(slash_command)]
+pub async fn audit(
+    ctx: Context<'_>,
+    #[description = "Only show entries involving this member"] user: Option<serenity::User>,
+) -> Result<(), Error> {
+    let user_id = user.as_ref().map(|u| u.id.to_string());
+    let entries = ctx
+        .data()
+        .fishing_manager
+        .audit_entries(&guild_key(ctx)?, user_id.as_deref(), 15)
+        .await;
+
+    let description = if entries.is_empty() {
+        "No audit entries yet.".to_string()
+    } else {
+        entries
+            .iter()
+            .map(|e| {
+                format!(
+                    "<t:{}:f> <@{}> {}",
+                    e.at / 1000,
+                    e.actor_id,
+                    e.action.describe()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    let embed = serenity::CreateEmbed::new()
+        .color(0x808080)
+        .title("📜 Audit Trail")
+        .description(description);
+    ctx.send(poise::CreateReply::default().embed(embed).ephemeral(true))
+        .await?;
+    Ok(())
+}
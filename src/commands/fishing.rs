@@ -1,66 +1,1234 @@
-use crate::game::FishingError;
-use crate::{Context, Error};
-use chrono::Utc;
+use super::guild_key;
+#[cfg(feature = "economy")]
+use crate::game::Gift;
+use crate::{Context, Data, Error};
 use poise::serenity_prelude as serenity;
 
 /// Cast your line and catch a fish!
 #[poise::command(slash_command)]
 pub async fn fish(ctx: Context<'_>) -> Result<(), Error> {
+    let guild_id = guild_key(ctx)?;
     let user_id = ctx.author().id.to_string();
 
-    // Get display name (nickname) if available, otherwise username
-    let username = ctx
+    let fishing_manager = &ctx.data().fishing_manager;
+    if fishing_manager.is_paused(&guild_id).await {
+        ctx.send(
+            poise::CreateReply::default()
+                .content(crate::ui::POND_FROZEN_MESSAGE)
+                .ephemeral(true),
+        )
+        .await?;
+        return Ok(());
+    }
+    if let Some(ban) = fishing_manager.fish_ban(&guild_id, &user_id).await {
+        ctx.send(
+            poise::CreateReply::default()
+                .content(crate::ui::fish_ban_message(
+                    ban.until,
+                    ban.reason.as_deref(),
+                ))
+                .ephemeral(true),
+        )
+        .await?;
+        return Ok(());
+    }
+    let redirect = {
+        let data = ctx.data().data_manager.guild(&guild_id).await;
+        (!data.fish_allowed_in(&ctx.channel_id().to_string())).then(|| {
+            crate::ui::fish_channel_message(data.button_channel_id.as_deref(), &data.fish_channels)
+        })
+    };
+    if let Some(content) = redirect {
+        ctx.send(
+            poise::CreateReply::default()
+                .content(content)
+                .ephemeral(true),
+        )
+        .await?;
+        return Ok(());
+    }
+    let booster = ctx
         .author_member()
         .await
-        .and_then(|m| m.nick.clone())
-        .unwrap_or_else(|| ctx.author().name.clone());
+        .is_some_and(|member| member.premium_since.is_some());
+    if fishing_manager
+        .out_of_casts(&guild_id, &user_id, booster)
+        .await
+    {
+        let local_reset = fishing_manager.local_next_reset(&guild_id, &user_id).await;
+        ctx.send(
+            poise::CreateReply::default()
+                .content(crate::ui::already_fished_message(local_reset))
+                .ephemeral(true),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    // The cast itself happens once a location is picked (see events.rs)
+    let access = fishing_manager.location_access(&guild_id, &user_id).await;
+    ctx.send(
+        poise::CreateReply::default()
+            .content("🗺️ Where would you like to fish today?")
+            .components(vec![crate::ui::location_menu(
+                crate::events::LOCATION_MENU_COMMAND,
+                &access,
+            )])
+            .ephemeral(true),
+    )
+    .await?;
 
-    // Call shared fishing logic
-    match ctx
+    Ok(())
+}
+
+#[cfg(feature = "economy")]
+async fn autocomplete_locked_location(
+    ctx: Context<'_>,
+    partial: &str,
+) -> Vec<serenity::AutocompleteChoice> {
+    let Ok(guild_id) = guild_key(ctx) else {
+        return Vec::new();
+    };
+    let access = ctx
         .data()
         .fishing_manager
-        .handle_fishing(user_id, username.clone())
+        .location_access(&guild_id, &ctx.author().id.to_string())
+        .await;
+    access
+        .into_iter()
+        .filter(|(_, unlocked)| !unlocked)
+        .filter(|(location, _)| {
+            location
+                .name
+                .to_lowercase()
+                .contains(&partial.to_lowercase())
+        })
+        .map(|(location, _)| {
+            serenity::AutocompleteChoice::new(
+                format!("{} ({} coins)", location.name, location.unlock_coins),
+                location.id,
+            )
+        })
+        .collect()
+}
+
+#[cfg(feature = "economy")]
+/// Spend coins to unlock a new fishing spot early
+#[poise::command(slash_command)]
+pub async fn unlock(
+    ctx: Context<'_>,
+    #[description = "The location to unlock"]
+    #[autocomplete = "autocomplete_locked_location"]
+    location: String,
+) -> Result<(), Error> {
+    let guild_id = guild_key(ctx)?;
+    let user_id = ctx.author().id.to_string();
+
+    let content = match ctx
+        .data()
+        .fishing_manager
+        .unlock_location(&guild_id, &user_id, &location)
         .await
     {
-        Ok((current_streak, total_catches, daily_count)) => {
-            // Create and send embed
-            let embed = serenity::CreateEmbed::new()
-                .color(0x0099FF)
-                .title("🎣 Catch of the Day!")
-                .description(format!(
-                    "**{}** cast their line and caught a fish! 🐟",
-                    username
-                ))
-                .thumbnail(ctx.author().face())
-                .field("🔥 Streak", format!("{} Days", current_streak), true)
-                .field("✨ Total Catches", format!("{}", total_catches), true)
-                .field("🌍 Total Catches Today", format!("{}", daily_count), true)
-                .timestamp(Utc::now())
-                .footer(serenity::CreateEmbedFooter::new("Stardust Pond"));
+        Ok(remaining) => {
+            let loc = crate::fish::find_location(&location).expect("validated by unlock_location");
+            format!(
+                "🔓 Unlocked {} **{}**! You have **{}** coins left.",
+                loc.emoji, loc.name, remaining
+            )
+        }
+        Err(e) => match crate::ui::fishing_error_message(&e) {
+            Some(content) => content,
+            None => return Err(e.into()),
+        },
+    };
 
-            ctx.send(poise::CreateReply::default().embed(embed)).await?;
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+    Ok(())
+}
+
+#[cfg(feature = "economy")]
+/// See your coins and items
+#[poise::command(slash_command)]
+pub async fn inventory(ctx: Context<'_>) -> Result<(), Error> {
+    let (coins, items) = ctx
+        .data()
+        .fishing_manager
+        .inventory(&guild_key(ctx)?, &ctx.author().id.to_string())
+        .await;
+
+    let items_text = if items.is_empty() {
+        "Nothing yet — keep an eye out for the travelling merchant!".to_string()
+    } else {
+        items
+            .iter()
+            .map(|(item, count)| {
+                format!(
+                    "{} **{}** ×{} — {}",
+                    item.emoji, item.name, count, item.description
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    let branding = ctx.data().fishing_manager.branding(&guild_key(ctx)?).await;
+    let embed = serenity::CreateEmbed::new()
+        .color(branding.color)
+        .title(format!("🎒 {}'s Tackle Box", ctx.author().name))
+        .field("🪙 Coins", format!("**{}**", coins), false)
+        .field("Items", items_text, false)
+        .footer(serenity::CreateEmbedFooter::new(
+            "Bait is used automatically on your next cast",
+        ));
+
+    ctx.send(poise::CreateReply::default().embed(embed).ephemeral(true))
+        .await?;
+    Ok(())
+}
+
+#[cfg(feature = "economy")]
+async fn autocomplete_gift(ctx: Context<'_>, partial: &str) -> Vec<serenity::AutocompleteChoice> {
+    let Ok(guild_id) = guild_key(ctx) else {
+        return Vec::new();
+    };
+    let (coins, items) = ctx
+        .data()
+        .fishing_manager
+        .inventory(&guild_id, &ctx.author().id.to_string())
+        .await;
+
+    let mut choices = vec![(format!("🪙 Coins ({} owned)", coins), "coins")];
+    choices.extend(items.into_iter().map(|(item, count)| {
+        (
+            format!("{} {} ({} owned)", item.emoji, item.name, count),
+            item.id,
+        )
+    }));
+    choices
+        .into_iter()
+        .filter(|(label, _)| label.to_lowercase().contains(&partial.to_lowercase()))
+        .map(|(label, value)| serenity::AutocompleteChoice::new(label, value))
+        .collect()
+}
+
+#[cfg(feature = "economy")]
+/// Send coins or an item to another angler
+#[poise::command(slash_command, guild_only)]
+pub async fn gift(
+    ctx: Context<'_>,
+    #[description = "Who to send the gift to"] user: serenity::User,
+    #[description = "Coins or an item from your tackle box"]
+    #[autocomplete = "autocomplete_gift"]
+    gift: String,
+    #[description = "How many to send"]
+    #[min = 1]
+    amount: u64,
+) -> Result<(), Error> {
+    if user.bot {
+        ctx.send(
+            poise::CreateReply::default()
+                .content("🤖 Bots don't need presents!")
+                .ephemeral(true),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let what = if gift == "coins" {
+        Gift::Coins(amount)
+    } else {
+        match crate::items::find_item(&gift) {
+            Some(item) => Gift::Item(item, amount),
+            None => {
+                ctx.send(
+                    poise::CreateReply::default()
+                        .content("❌ Unknown gift. Pick coins or an item from the suggestions.")
+                        .ephemeral(true),
+                )
+                .await?;
+                return Ok(());
+            }
+        }
+    };
+
+    let result = ctx
+        .data()
+        .fishing_manager
+        .gift(
+            &guild_key(ctx)?,
+            &ctx.author().id.to_string(),
+            &user.id.to_string(),
+            &user.name,
+            what,
+        )
+        .await;
+
+    match result {
+        Ok(()) => {
+            let description = match what {
+                Gift::Coins(amount) => format!("{} 🪙 coins", amount),
+                Gift::Item(item, amount) => format!("{}× {} {}", amount, item.emoji, item.name),
+            };
+            ctx.send(poise::CreateReply::default().content(format!(
+                "🎁 <@{}> sent <@{}> **{}**! How generous!",
+                ctx.author().id,
+                user.id,
+                description
+            )))
+            .await?;
         }
-        Err(FishingError::AlreadyFished) => {
+        Err(e) => match crate::ui::fishing_error_message(&e) {
+            Some(content) => {
+                ctx.send(
+                    poise::CreateReply::default()
+                        .content(content)
+                        .ephemeral(true),
+                )
+                .await?;
+            }
+            None => return Err(e.into()),
+        },
+    }
+
+    Ok(())
+}
+
+/// Catches shown on one `/fishhistory` page
+const HISTORY_PAGE: usize = 10;
+
+/// Look back at your recent catches
+#[poise::command(slash_command)]
+pub async fn fishhistory(
+    ctx: Context<'_>,
+    #[description = "Whose history to show (defaults to you)"] user: Option<serenity::User>,
+) -> Result<(), Error> {
+    let user = user.as_ref().unwrap_or_else(|| ctx.author());
+    let history = ctx
+        .data()
+        .fishing_manager
+        .catch_history(&guild_key(ctx)?, &user.id.to_string())
+        .await;
+
+    if history.is_empty() {
+        ctx.send(
+            poise::CreateReply::default()
+                .content(format!("🎣 **{}** hasn't landed anything yet.", user.name))
+                .ephemeral(true),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let recent = history
+        .iter()
+        .take(HISTORY_PAGE)
+        .map(|record| {
+            let species = crate::fish::find_species(&record.species);
+            let location = crate::fish::find_location(&record.location);
+            format!(
+                "<t:{}:d> {} **{}** ({} cm) at {}",
+                record.at / 1000,
+                species.map_or("🐟", |s| s.emoji),
+                species.map_or(record.species.clone(), |s| crate::ui::species_name(
+                    s,
+                    record.shiny
+                )),
+                record.size_cm,
+                location.map_or(record.location.as_str(), |l| l.name)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mut by_rarity = std::collections::BTreeMap::new();
+    for species in history
+        .iter()
+        .filter_map(|r| crate::fish::find_species(&r.species))
+    {
+        *by_rarity
+            .entry(std::cmp::Reverse(species.rarity))
+            .or_insert(0u64) += 1;
+    }
+    let rarity_text = by_rarity
+        .iter()
+        .map(|(std::cmp::Reverse(rarity), count)| {
+            format!(
+                "{} {}: **{}**",
+                crate::ui::rarity_style(*rarity).emoji,
+                rarity.label(),
+                count
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let branding = ctx.data().fishing_manager.branding(&guild_key(ctx)?).await;
+    let mut embed = serenity::CreateEmbed::new()
+        .color(branding.color)
+        .title(format!("📜 {}'s Fishing Log", user.name))
+        .thumbnail(user.face())
+        .field("🕰️ Recent Catches", recent, false)
+        .field("📊 By Rarity", rarity_text, true)
+        .footer(serenity::CreateEmbedFooter::new(format!(
+            "Last {} catches on record",
+            history.len()
+        )));
+    if let Some(biggest) = history.iter().max_by_key(|r| r.size_cm) {
+        let name = crate::fish::find_species(&biggest.species)
+            .map_or(biggest.species.as_str(), |s| s.name);
+        embed = embed.field(
+            "📏 Biggest Catch",
+            format!("**{}** at {} cm", name, biggest.size_cm),
+            true,
+        );
+    }
+
+    ctx.send(poise::CreateReply::default().embed(embed)).await?;
+    Ok(())
+}
+
+/// Get a DM at a time of your choosing on days you haven't fished yet
+#[poise::command(slash_command)]
+pub async fn remindme(
+    ctx: Context<'_>,
+    #[description = "24-hour time as HH:MM on your clock (see /settimezone); leave empty to stop"]
+    time: Option<String>,
+) -> Result<(), Error> {
+    let at = match time.as_deref() {
+        Some(time) => match chrono::NaiveTime::parse_from_str(time.trim(), "%H:%M") {
+            Ok(at) => Some(at),
+            Err(_) => {
+                ctx.send(
+                    poise::CreateReply::default()
+                        .content("❌ Use 24-hour HH:MM, e.g. `18:30`.")
+                        .ephemeral(true),
+                )
+                .await?;
+                return Ok(());
+            }
+        },
+        None => None,
+    };
+    let next = ctx
+        .data()
+        .fishing_manager
+        .set_dm_reminder(&guild_key(ctx)?, &ctx.author().id.to_string(), at)
+        .await;
+
+    let content = match next {
+        Some(next) => format!(
+            "⏰ I'll DM you at **<t:{}:t>** on days you haven't fished yet, starting <t:{}:R>. Make sure your DMs are open!",
+            next.timestamp(),
+            next.timestamp()
+        ),
+        None => "🔕 Your DM reminder is off.".to_string(),
+    };
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[derive(poise::ChoiceParameter)]
+pub enum ReminderSetting {
+    #[name = "on"]
+    On,
+    #[name = "off"]
+    Off,
+}
+
+/// Turn the daily reminder pings on or off for yourself
+#[poise::command(slash_command)]
+pub async fn fishreminders(
+    ctx: Context<'_>,
+    #[description = "Whether the daily reminder may ping you"] setting: ReminderSetting,
+) -> Result<(), Error> {
+    let enabled = matches!(setting, ReminderSetting::On);
+    ctx.data()
+        .fishing_manager
+        .set_reminders(&guild_key(ctx)?, &ctx.author().id.to_string(), enabled)
+        .await;
+
+    let content = if enabled {
+        "🔔 The daily reminder will ping you again when you haven't fished."
+    } else {
+        "🔕 The daily reminder won't ping you anymore. Your streak and stats are tracked as usual."
+    };
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Freeze your streak while you're away (0 days cancels)
+#[poise::command(slash_command)]
+pub async fn vacation(
+    ctx: Context<'_>,
+    #[description = "How many days you'll be away"]
+    #[min = 0]
+    #[max = 14]
+    days: u64,
+) -> Result<(), Error> {
+    let result = ctx
+        .data()
+        .fishing_manager
+        .set_vacation(&guild_key(ctx)?, &ctx.author().id.to_string(), days)
+        .await;
+    respond_vacation(ctx, ctx.author(), result).await
+}
+
+/// Ephemeral reply for `/vacation` and `/fishadmin vacation`
+pub async fn respond_vacation(
+    ctx: Context<'_>,
+    user: &serenity::User,
+    result: Result<Option<String>, crate::game::FishingError>,
+) -> Result<(), Error> {
+    let content = match result {
+        Ok(Some(until)) => format!(
+            "🏖️ **{}**'s streak is frozen through **{}** (UTC). Casting again ends the vacation early.",
+            user.name, until
+        ),
+        Ok(None) => format!("✅ **{}** is back from vacation.", user.name),
+        Err(e) => match crate::ui::fishing_error_message(&e) {
+            Some(content) => content,
+            None => return Err(e.into()),
+        },
+    };
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+    Ok(())
+}
+
+/// Confirmation shown before erasing a player's data
+#[derive(Debug, poise::Modal)]
+#[name = "Erase fishing data"]
+struct DeleteDataModal {
+    #[name = "Type DELETE to confirm"]
+    #[placeholder = "DELETE"]
+    #[min_length = 6]
+    #[max_length = 6]
+    confirm: String,
+}
+
+/// Permanently erase your streak, catches, history, and inventory in this server
+#[poise::command(slash_command)]
+pub async fn deletemydata(ctx: poise::ApplicationContext<'_, Data, Error>) -> Result<(), Error> {
+    let author = ctx.interaction.user.clone();
+    confirm_delete_data(ctx, &author).await
+}
+
+/// Ask for confirmation, then erase `user`'s data. Shared by `/deletemydata`
+/// and `/fishadmin forget`.
+pub async fn confirm_delete_data(
+    app_ctx: poise::ApplicationContext<'_, Data, Error>,
+    user: &serenity::User,
+) -> Result<(), Error> {
+    let ctx: Context<'_> = app_ctx.into();
+    let guild_id = guild_key(ctx)?;
+    let confirmed = poise::execute_modal::<_, _, DeleteDataModal>(
+        app_ctx,
+        None,
+        Some(std::time::Duration::from_secs(120)),
+    )
+    .await?
+    .is_some_and(|modal| modal.confirm.trim().eq_ignore_ascii_case("DELETE"));
+
+    let content = if !confirmed {
+        "❎ Nothing was deleted.".to_string()
+    } else if ctx
+        .data()
+        .fishing_manager
+        .delete_user_data(&guild_id, &user.id.to_string())
+        .await
+    {
+        format!(
+            "🗑️ Erased everything stored about **{}** in this server. Copies in older backups disappear as they rotate out.",
+            user.name
+        )
+    } else {
+        format!("There's no data stored about **{}** here.", user.name)
+    };
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+    Ok(())
+}
+
+/// Take credit for inviting a new member; you're paid when they reach a 7-day streak
+#[poise::command(slash_command, guild_only)]
+pub async fn invitecredit(
+    ctx: Context<'_>,
+    #[description = "The new member you invited"] user: serenity::User,
+) -> Result<(), Error> {
+    let reply = |content: String| {
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true)
+    };
+    if user.bot {
+        ctx.send(reply("🤖 Bots don't count as referrals!".to_string()))
+            .await?;
+        return Ok(());
+    }
+
+    // Only members holding a tracked role count, so alts that never got verified can't be claimed
+    let guild_id = guild_key(ctx)?;
+    let tracked_roles: Vec<serenity::RoleId> = ctx
+        .data()
+        .data_manager
+        .guild(&guild_id)
+        .await
+        .tracked_role_ids
+        .iter()
+        .filter_map(|id| id.parse::<u64>().ok())
+        .map(serenity::RoleId::new)
+        .collect();
+    if let Some(guild_id) = ctx.guild_id().filter(|_| !tracked_roles.is_empty()) {
+        let has_role = match guild_id.member(ctx, user.id).await {
+            Ok(member) => member.roles.iter().any(|role| tracked_roles.contains(role)),
+            Err(_) => false,
+        };
+        if !has_role {
+            ctx.send(reply(format!(
+                "🤝 **{}** needs a tracked role ({}) before they can be credited.",
+                user.name,
+                tracked_roles
+                    .iter()
+                    .map(|role| format!("<@&{}>", role))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )))
+            .await?;
+            return Ok(());
+        }
+    }
+
+    let result = ctx
+        .data()
+        .fishing_manager
+        .credit_referral(
+            &guild_id,
+            &ctx.author().id.to_string(),
+            &user.id.to_string(),
+        )
+        .await;
+    let content = match result {
+        Ok(()) => format!(
+            "🤝 You're credited for inviting **{}**! When they reach a {} day streak you'll get **{}** 🪙.",
+            user.name,
+            crate::game::REFERRAL_STREAK,
+            crate::game::REFERRAL_BONUS_COINS
+        ),
+        Err(crate::game::FishingError::NeverFished) => {
+            "🎣 Catch your first fish before crediting referrals!".to_string()
+        }
+        Err(e) => match crate::ui::fishing_error_message(&e) {
+            Some(content) => content,
+            None => return Err(e.into()),
+        },
+    };
+    ctx.send(reply(content)).await?;
+    Ok(())
+}
+
+/// Register your birthday for a guaranteed shiny and a gift on the day
+#[poise::command(slash_command)]
+pub async fn birthday(
+    ctx: Context<'_>,
+    #[description = "Birth month (1-12); leave both empty to see your birthday"]
+    #[min = 1]
+    #[max = 12]
+    month: Option<u32>,
+    #[description = "Day of the month"]
+    #[min = 1]
+    #[max = 31]
+    day: Option<u32>,
+) -> Result<(), Error> {
+    let guild_id = guild_key(ctx)?;
+    let user_id = ctx.author().id.to_string();
+    let fishing_manager = &ctx.data().fishing_manager;
+    let content = match (month, day) {
+        (Some(month), Some(day)) => match fishing_manager
+            .set_birthday(&guild_id, &user_id, month, day)
+            .await
+        {
+            Ok(birthday) => format!(
+                "🎂 Birthday saved as **{}** (MM-DD, server time). Your first catch that day is guaranteed shiny and comes with a gift!",
+                birthday
+            ),
+            Err(crate::game::FishingError::NeverFished) => {
+                "🎣 Catch your first fish before registering a birthday!".to_string()
+            }
+            Err(crate::game::FishingError::InvalidDate) => {
+                "📅 That date doesn't exist. Check the month and day.".to_string()
+            }
+            Err(e) => match crate::ui::fishing_error_message(&e) {
+                Some(content) => content,
+                None => return Err(e.into()),
+            },
+        },
+        (None, None) => match fishing_manager.birthday(&guild_id, &user_id).await {
+            Some(birthday) => format!("🎂 Your birthday is registered as **{}** (MM-DD).", birthday),
+            None => "🎂 You haven't registered a birthday yet. Pass a month and day to set it (it can't be changed later).".to_string(),
+        },
+        _ => "📅 Pass both a month and a day.".to_string(),
+    };
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+    Ok(())
+}
+
+/// Set your timezone so reset times are shown on your own clock
+#[poise::command(slash_command)]
+pub async fn settimezone(
+    ctx: Context<'_>,
+    #[description = "IANA timezone, e.g. Europe/Berlin; leave empty to see or clear yours"]
+    #[autocomplete = "super::autocomplete_timezone"]
+    timezone: Option<String>,
+    #[description = "Go back to showing times without a timezone"] clear: Option<bool>,
+) -> Result<(), Error> {
+    let guild_id = guild_key(ctx)?;
+    let user_id = ctx.author().id.to_string();
+    let fishing_manager = &ctx.data().fishing_manager;
+    let content = if timezone.is_none() && !clear.unwrap_or(false) {
+        match fishing_manager.local_next_reset(&guild_id, &user_id).await {
+            Some(reset) => format!(
+                "🕒 Your timezone is **{}**. The next reset is at **{}**.",
+                reset.timezone().name(),
+                crate::ui::local_reset_time(reset)
+            ),
+            None => "🕒 You haven't set a timezone yet. Pass one like `Europe/Berlin` to see reset times on your own clock.".to_string(),
+        }
+    } else {
+        match fishing_manager
+            .set_timezone(&guild_id, &user_id, timezone.as_deref())
+            .await
+        {
+            Ok(Some(tz)) => format!(
+                "🕒 Timezone set to **{}**. The next reset is at **{}**; the reset itself is the same for the whole server.",
+                tz.name(),
+                crate::ui::local_reset_time(
+                    fishing_manager.next_guild_reset(&guild_id).await.with_timezone(&tz)
+                )
+            ),
+            Ok(None) => "🕒 Timezone cleared.".to_string(),
+            Err(crate::game::FishingError::NeverFished) => {
+                "🎣 Catch your first fish before setting a timezone!".to_string()
+            }
+            Err(e) => match crate::ui::fishing_error_message(&e) {
+                Some(content) => content,
+                None => return Err(e.into()),
+            },
+        }
+    };
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+    Ok(())
+}
+
+/// See which days you've fished over the last three months
+#[cfg(feature = "image-gen")]
+#[poise::command(slash_command)]
+pub async fn calendar(
+    ctx: Context<'_>,
+    #[description = "Whose calendar to show (defaults to you)"] user: Option<serenity::User>,
+) -> Result<(), Error> {
+    use crate::render::calendar;
+
+    let user = user.as_ref().unwrap_or_else(|| ctx.author());
+    let guild_id = guild_key(ctx)?;
+    let fishing_manager = &ctx.data().fishing_manager;
+    let history = fishing_manager
+        .catch_history(&guild_id, &user.id.to_string())
+        .await;
+    let tz = fishing_manager.guild_timezone(&guild_id).await;
+
+    let today = chrono::Utc::now().with_timezone(&tz).date_naive();
+    let first_day = calendar::first_day(today);
+    let mut catches = std::collections::HashMap::new();
+    for record in &history {
+        let day = chrono::DateTime::from_timestamp_millis(record.at as i64)
+            .map(|at| at.with_timezone(&tz).date_naive())
+            .filter(|day| *day >= first_day);
+        if let Some(day) = day {
+            *catches.entry(day).or_insert(0u64) += 1;
+        }
+    }
+
+    let image = calendar::render(&user.name, &catches, today);
+    let embed = serenity::CreateEmbed::new()
+        .color(0x39D353)
+        .title(format!("📅 {}'s Fishing Calendar", user.name))
+        .image(format!("attachment://{}", calendar::FILE_NAME))
+        .footer(serenity::CreateEmbedFooter::new(format!(
+            "Last 13 weeks, in {}",
+            tz.name()
+        )));
+    ctx.send(poise::CreateReply::default().embed(embed).attachment(
+        serenity::CreateAttachment::bytes(image, calendar::FILE_NAME),
+    ))
+    .await?;
+    Ok(())
+}
+
+/// Draw today's fishing fortune, with a little luck (good or bad) for your casts
+#[poise::command(slash_command)]
+pub async fn fortune(ctx: Context<'_>) -> Result<(), Error> {
+    let guild_id = guild_key(ctx)?;
+    let user_id = ctx.author().id.to_string();
+    let fishing_manager = &ctx.data().fishing_manager;
+    let (fortune, fresh) = fishing_manager.draw_fortune(&guild_id, &user_id).await;
+
+    let percent = ((fortune.luck - 1.0) * 100.0).round() as i64;
+    let luck_text = match percent {
+        0 => "Your luck is unchanged today.".to_string(),
+        p if p > 0 => format!("Your casts are **{}%** luckier until the reset.", p),
+        p => format!("Your casts are **{}%** less lucky until the reset.", -p),
+    };
+    let mut embed = serenity::CreateEmbed::new()
+        .color(0x9B59B6)
+        .title("🔮 Your Fishing Fortune")
+        .description(format!("*{}*\n\n{}", fortune.text, luck_text));
+    if !fresh {
+        let footer = match fishing_manager.local_next_reset(&guild_id, &user_id).await {
+            Some(reset) => format!(
+                "You've already drawn today's fortune. A new one awaits after the reset at {}.",
+                crate::ui::local_reset_time(reset)
+            ),
+            None => "You've already drawn today's fortune. A new one awaits after the reset."
+                .to_string(),
+        };
+        embed = embed.footer(serenity::CreateEmbedFooter::new(footer));
+    }
+
+    ctx.send(poise::CreateReply::default().embed(embed).ephemeral(!fresh))
+        .await?;
+    Ok(())
+}
+
+/// Show the server's all-time fishing records
+#[poise::command(slash_command)]
+pub async fn halloffame(ctx: Context<'_>) -> Result<(), Error> {
+    let hall = ctx
+        .data()
+        .fishing_manager
+        .hall_of_fame(&guild_key(ctx)?)
+        .await;
+    let records = crate::ui::hall_of_fame_lines(&hall, None);
+    let branding = ctx.data().fishing_manager.branding(&guild_key(ctx)?).await;
+
+    let embed = serenity::CreateEmbed::new()
+        .color(branding.highlight)
+        .title("🏛️ Hall of Fame")
+        .description(if records.is_empty() {
+            "No records yet. Go catch something!".to_string()
+        } else {
+            records
+        })
+        .footer(serenity::CreateEmbedFooter::new(format!(
+            "{} All-Time Records",
+            branding.pond_name
+        )));
+
+    ctx.send(poise::CreateReply::default().embed(embed)).await?;
+    Ok(())
+}
+
+/// Put two players' stats side by side
+#[poise::command(slash_command)]
+pub async fn compare(
+    ctx: Context<'_>,
+    #[description = "The first player"] a: serenity::User,
+    #[description = "The second player (defaults to you)"] b: Option<serenity::User>,
+) -> Result<(), Error> {
+    let b = b.as_ref().unwrap_or_else(|| ctx.author());
+    let (card_a, card_b) = ctx
+        .data()
+        .fishing_manager
+        .compare_players(&guild_key(ctx)?, &a.id.to_string(), &b.id.to_string())
+        .await;
+    let missing = if card_a.is_none() { &a.name } else { &b.name };
+    let (card_a, card_b) = match (card_a, card_b) {
+        (Some(card_a), Some(card_b)) => (card_a, card_b),
+        _ => {
             ctx.send(
                 poise::CreateReply::default()
-                    .content("❌ You've already fished today! Come back tomorrow.")
+                    .content(format!("🎣 **{}** hasn't landed anything yet.", missing))
                     .ephemeral(true),
             )
             .await?;
+            return Ok(());
         }
-        Err(e) => return Err(e.into()),
+    };
+
+    let species_total = crate::fish::all_species().count();
+    let column = |card: &crate::game::PlayerCard| {
+        format!(
+            "{}\n🔥 Streak: **{}** days\n🏆 Best streak: **{}** days\n🐟 Total catches: **{}**\n📖 Fishdex: **{}/{}** ({:.0}%)",
+            crate::ui::titled_name(&card.username, card.title),
+            card.streak,
+            card.best_streak,
+            card.total_catches,
+            card.fishdex,
+            species_total,
+            card.fishdex as f64 * 100.0 / species_total.max(1) as f64
+        )
+    };
+    let embed = serenity::CreateEmbed::new()
+        .color(0x9B59B6)
+        .title(format!("⚖️ {} vs {}", a.name, b.name))
+        .field(a.name.clone(), column(&card_a), true)
+        .field(b.name.clone(), column(&card_b), true)
+        .field(
+            "⚔️ Head to Head",
+            format!(
+                "Duels won: **{}** – **{}**",
+                card_a.duel_wins, card_b.duel_wins
+            ),
+            false,
+        );
+
+    ctx.send(poise::CreateReply::default().embed(embed)).await?;
+    Ok(())
+}
+
+/// Players shown on one `/topstreaks` page
+const STREAKS_PAGE: usize = 10;
+/// How long the page buttons keep working after the last press
+const STREAKS_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5 * 60);
+
+#[derive(poise::ChoiceParameter)]
+pub enum StreakBoard {
+    #[name = "Current streaks"]
+    Current,
+    #[name = "All-time best streaks"]
+    AllTime,
+}
+
+/// Rank players by streak, running or all-time
+#[poise::command(slash_command)]
+pub async fn topstreaks(
+    ctx: Context<'_>,
+    #[description = "Which board to show (defaults to current streaks)"] board: Option<StreakBoard>,
+) -> Result<(), Error> {
+    let all_time = matches!(board, Some(StreakBoard::AllTime));
+    let entries = ctx
+        .data()
+        .fishing_manager
+        .streak_board(&guild_key(ctx)?, all_time)
+        .await;
+    let title = if all_time {
+        "🏆 All-Time Best Streaks"
+    } else {
+        "🔥 Current Streaks"
+    };
+    if entries.is_empty() {
+        ctx.send(
+            poise::CreateReply::default()
+                .content("🎣 Nobody has a streak going yet. Go catch something!")
+                .ephemeral(true),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let pages: Vec<String> = entries
+        .chunks(STREAKS_PAGE)
+        .enumerate()
+        .map(|(page, chunk)| {
+            chunk
+                .iter()
+                .enumerate()
+                .map(|(i, entry)| {
+                    format!(
+                        "**{}.** {}: **{}** days",
+                        page * STREAKS_PAGE + i + 1,
+                        crate::ui::titled_name(&entry.username, entry.title),
+                        entry.streak
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .collect();
+    let embed = |page: usize| {
+        serenity::CreateEmbed::new()
+            .color(0xFF6B35)
+            .title(title)
+            .description(&pages[page])
+            .footer(serenity::CreateEmbedFooter::new(format!(
+                "Page {} of {}",
+                page + 1,
+                pages.len()
+            )))
+    };
+    let prefix = ctx.id();
+    let buttons = |page: usize, disabled: bool| {
+        vec![serenity::CreateActionRow::Buttons(vec![
+            serenity::CreateButton::new(format!("{}prev", prefix))
+                .emoji('◀')
+                .style(serenity::ButtonStyle::Secondary)
+                .disabled(disabled || page == 0),
+            serenity::CreateButton::new(format!("{}next", prefix))
+                .emoji('▶')
+                .style(serenity::ButtonStyle::Secondary)
+                .disabled(disabled || page + 1 == pages.len()),
+        ])]
+    };
+
+    let mut page = 0;
+    let mut reply = poise::CreateReply::default().embed(embed(page));
+    if pages.len() > 1 {
+        reply = reply.components(buttons(page, false));
+    }
+    let handle = ctx.send(reply).await?;
+    if pages.len() == 1 {
+        return Ok(());
+    }
+    let message = handle.message().await?;
+
+    while let Some(press) = serenity::ComponentInteractionCollector::new(ctx)
+        .message_id(message.id)
+        .timeout(STREAKS_TIMEOUT)
+        .await
+    {
+        if press.data.custom_id == format!("{}next", prefix) {
+            page = (page + 1).min(pages.len() - 1);
+        } else {
+            page = page.saturating_sub(1);
+        }
+        press
+            .create_response(
+                ctx,
+                serenity::CreateInteractionResponse::UpdateMessage(
+                    serenity::CreateInteractionResponseMessage::new()
+                        .embed(embed(page))
+                        .components(buttons(page, false)),
+                ),
+            )
+            .await?;
     }
+    handle
+        .edit(
+            ctx,
+            poise::CreateReply::default()
+                .embed(embed(page))
+                .components(buttons(page, true)),
+        )
+        .await?;
 
     Ok(())
 }
 
-/// Show the daily summary
+/// Show how actively the server has been fishing lately
 #[poise::command(slash_command)]
-pub async fn summary(ctx: Context<'_>) -> Result<(), Error> {
-    ctx.data()
+pub async fn serverstats(ctx: Context<'_>) -> Result<(), Error> {
+    let stats = ctx
+        .data()
+        .fishing_manager
+        .server_stats(&guild_key(ctx)?)
+        .await;
+
+    let embed = serenity::CreateEmbed::new()
+        .color(0x3498DB)
+        .title("📈 Server Stats")
+        .description(if stats.players == 0 {
+            "Nobody has fished here yet. Be the first!".to_string()
+        } else {
+            crate::ui::server_stats_lines(&stats)
+        })
+        .footer(serenity::CreateEmbedFooter::new(format!(
+            "Drifted away: fished in the last {} days, but not the last {}",
+            crate::analytics::MONTH_DAYS,
+            crate::analytics::WEEK_DAYS
+        )));
+
+    ctx.send(poise::CreateReply::default().embed(embed)).await?;
+    Ok(())
+}
+
+async fn autocomplete_species(
+    _ctx: Context<'_>,
+    partial: &str,
+) -> Vec<serenity::AutocompleteChoice> {
+    crate::fish::all_species()
+        .filter(|species| {
+            species
+                .name
+                .to_lowercase()
+                .contains(&partial.to_lowercase())
+        })
+        .take(25)
+        .map(|species| {
+            serenity::AutocompleteChoice::new(
+                format!("{} {}", species.emoji, species.name),
+                species.id,
+            )
+        })
+        .collect()
+}
+
+/// Look up a fish species, or browse every species
+#[poise::command(slash_command)]
+pub async fn fishpedia(
+    ctx: Context<'_>,
+    #[description = "The species to look up"]
+    #[autocomplete = "autocomplete_species"]
+    species: Option<String>,
+) -> Result<(), Error> {
+    let branding = ctx.data().fishing_manager.branding(&guild_key(ctx)?).await;
+    let species = match species {
+        Some(id) => match crate::fish::find_species(&id) {
+            Some(species) => species,
+            None => {
+                ctx.send(
+                    poise::CreateReply::default()
+                        .content("❌ Unknown species. Pick one from the suggestions.")
+                        .ephemeral(true),
+                )
+                .await?;
+                return Ok(());
+            }
+        },
+        None => {
+            let mut embed = serenity::CreateEmbed::new()
+                .color(branding.color)
+                .title("📖 Fishpedia")
+                .description("Use `/fishpedia species:` for details on any fish.")
+                .footer(serenity::CreateEmbedFooter::new(&branding.pond_name));
+            for location in crate::fish::LOCATIONS {
+                let entries = location
+                    .pool
+                    .iter()
+                    .map(|species| {
+                        format!(
+                            "{} {} — {} {}",
+                            species.emoji,
+                            species.name,
+                            crate::ui::rarity_style(species.rarity).emoji,
+                            species.rarity.label()
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                embed = embed.field(
+                    format!("{} {}", location.emoji, location.name),
+                    entries,
+                    false,
+                );
+            }
+            ctx.send(poise::CreateReply::default().embed(embed)).await?;
+            return Ok(());
+        }
+    };
+
+    let stats = ctx
+        .data()
         .fishing_manager
-        .post_daily_summary(ctx.serenity_context())
+        .species_stats(&guild_key(ctx)?, species.id)
         .await;
+    let style = crate::ui::rarity_style(species.rarity);
+
+    let mut embed = serenity::CreateEmbed::new()
+        .color(style.color)
+        .title(format!("{} {}", species.emoji, species.name))
+        .field(
+            "Rarity",
+            format!("{} {}", style.emoji, species.rarity.label()),
+            true,
+        )
+        .field(
+            "📏 Size",
+            format!("{}–{} cm", species.min_size_cm, species.max_size_cm),
+            true,
+        )
+        .field(
+            "🪙 Reward",
+            format!("{} coins", species.rarity.coins()),
+            true,
+        )
+        .field(
+            "🌍 Caught by the Server",
+            format!("**{}** (🌈 {} shiny)", stats.caught, stats.shiny_caught),
+            true,
+        )
+        .footer(serenity::CreateEmbedFooter::new(format!(
+            "{} Fishpedia",
+            branding.pond_name
+        )));
+
+    if let Some(location) = crate::fish::species_location(species.id) {
+        let mut where_text = format!(
+            "{} {} — **{:.1}%** per cast",
+            location.emoji,
+            location.name,
+            location.catch_chance(species, 1.0) * 100.0
+        );
+        if !location.is_free() {
+            where_text.push_str(&format!(
+                "\n🔒 Unlocks at {} catches or {} coins",
+                location.unlock_catches, location.unlock_coins
+            ));
+        }
+        embed = embed.field("📍 Where to Find It", where_text, false);
+    }
+
+    let mut when_text = match stats.peak_hour {
+        Some(hour) => format!("Most often caught around **{:02}:00 UTC**", hour),
+        None => "Nobody has caught one on record yet".to_string(),
+    };
+    if species.rarity > crate::fish::Rarity::Common {
+        when_text
+            .push_str("\nBite more often during stream frenzies, lucky pond events, and with bait");
+    }
+    embed = embed.field("🕰️ Best Time", when_text, false);
+
+    if let Some((username, size)) = stats.biggest {
+        embed = embed.field(
+            "🏆 Record Catch",
+            format!("**{}** with {} cm", username, size),
+            false,
+        );
+    }
+
+    ctx.send(poise::CreateReply::default().embed(embed)).await?;
+    Ok(())
+}
+
+/// Show the daily summary
+#[poise::command(slash_command)]
+pub async fn summary(ctx: Context<'_>) -> Result<(), Error> {
+    let guild_id = guild_key(ctx)?;
+    crate::summary::post_daily_summary(&ctx.data().fishing_manager, ctx.http(), &guild_id).await;
     ctx.send(
         poise::CreateReply::default()
             .content("✅ Summary posted (check the configured channel if set)")
@@ -0,0 +1,165 @@
+use super::guild_key;
+use crate::game::DuelRoll;
+use crate::{Context, Error};
+use chrono::Utc;
+use poise::serenity_prelude as serenity;
+use std::time::Duration;
+
+/// How long the challenged player has to answer
+const DUEL_TIMEOUT: Duration = Duration::from_secs(60);
+
+fn duel_buttons(prefix: u64, disabled: bool) -> Vec<serenity::CreateActionRow> {
+    vec![serenity::CreateActionRow::Buttons(vec![
+        serenity::CreateButton::new(format!("{}duel_accept", prefix))
+            .label("⚔️ Accept")
+            .style(serenity::ButtonStyle::Success)
+            .disabled(disabled),
+        serenity::CreateButton::new(format!("{}duel_decline", prefix))
+            .label("Decline")
+            .style(serenity::ButtonStyle::Secondary)
+            .disabled(disabled),
+    ])]
+}
+
+fn describe_roll(name: &str, roll: &DuelRoll) -> String {
+    format!(
+        "**{}** reeled in a {} **{}** ({} cm, {})",
+        name,
+        roll.species.emoji,
+        roll.species.name,
+        roll.size_cm,
+        roll.species.rarity.label()
+    )
+}
+
+/// Challenge another angler: both cast once, the rarer (then bigger) fish takes the wager
+#[poise::command(slash_command, guild_only)]
+pub async fn duel(
+    ctx: Context<'_>,
+    #[description = "Who to challenge"] user: serenity::User,
+    #[description = "Coins each side puts up"] wager: u64,
+) -> Result<(), Error> {
+    if user.bot {
+        ctx.send(
+            poise::CreateReply::default()
+                .content("🤖 Bots can't hold a rod!")
+                .ephemeral(true),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let guild_id = guild_key(ctx)?;
+    let challenger_id = ctx.author().id.to_string();
+    let opponent_id = user.id.to_string();
+    if let Err(e) = ctx
+        .data()
+        .fishing_manager
+        .check_duel_wager(&guild_id, &challenger_id, &opponent_id, wager)
+        .await
+    {
+        return match crate::ui::fishing_error_message(&e) {
+            Some(content) => {
+                ctx.send(
+                    poise::CreateReply::default()
+                        .content(content)
+                        .ephemeral(true),
+                )
+                .await?;
+                Ok(())
+            }
+            None => Err(e.into()),
+        };
+    }
+
+    let prefix = ctx.id();
+    let expires = Utc::now().timestamp() as u64 + DUEL_TIMEOUT.as_secs();
+    let reply = ctx
+        .send(
+            poise::CreateReply::default()
+                .content(format!(
+                    "⚔️ <@{}> challenges <@{}> to a fishing duel for **{}** 🪙! Answer <t:{}:R>.",
+                    ctx.author().id,
+                    user.id,
+                    wager,
+                    expires
+                ))
+                .components(duel_buttons(prefix, false)),
+        )
+        .await?;
+    let message = reply.message().await?;
+
+    let opponent = user.id;
+    let answer = serenity::ComponentInteractionCollector::new(ctx)
+        .message_id(message.id)
+        .filter(move |i| i.user.id == opponent)
+        .timeout(DUEL_TIMEOUT)
+        .await;
+
+    let interaction = match answer {
+        Some(interaction) => interaction,
+        None => {
+            reply
+                .edit(
+                    ctx,
+                    poise::CreateReply::default()
+                        .content(format!("⌛ <@{}> didn't answer the duel in time.", user.id))
+                        .components(duel_buttons(prefix, true)),
+                )
+                .await?;
+            return Ok(());
+        }
+    };
+    interaction
+        .create_response(ctx, serenity::CreateInteractionResponse::Acknowledge)
+        .await?;
+
+    if interaction.data.custom_id != format!("{}duel_accept", prefix) {
+        reply
+            .edit(
+                ctx,
+                poise::CreateReply::default()
+                    .content(format!("🏳️ <@{}> declined the duel.", user.id))
+                    .components(duel_buttons(prefix, true)),
+            )
+            .await?;
+        return Ok(());
+    }
+
+    let result = ctx
+        .data()
+        .fishing_manager
+        .resolve_duel(&guild_id, &challenger_id, &opponent_id, wager)
+        .await;
+    let content = match result {
+        Ok(result) => {
+            let verdict = match result.winner_id.as_deref() {
+                Some(winner) if wager > 0 => {
+                    format!("🏆 <@{}> wins **{}** 🪙!", winner, wager)
+                }
+                Some(winner) => format!("🏆 <@{}> wins bragging rights!", winner),
+                None => "🤝 A dead heat — everyone keeps their coins.".to_string(),
+            };
+            format!(
+                "⚔️ **Fishing duel!**\n{}\n{}\n\n{}",
+                describe_roll(&ctx.author().name, &result.challenger),
+                describe_roll(&user.name, &result.opponent),
+                verdict
+            )
+        }
+        Err(e) => match crate::ui::fishing_error_message(&e) {
+            Some(message) => format!("The duel was called off. {}", message),
+            None => return Err(e.into()),
+        },
+    };
+
+    reply
+        .edit(
+            ctx,
+            poise::CreateReply::default()
+                .content(content)
+                .components(vec![]),
+        )
+        .await?;
+    Ok(())
+}
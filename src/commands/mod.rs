@@ -1,2 +1,135 @@
 pub mod admin;
+pub mod fishadmin;
 pub mod fishing;
+#[cfg(feature = "minigames")]
+pub mod minigames;
+pub mod streakrole;
+#[cfg(feature = "minigames")]
+pub mod team;
+pub mod title;
+
+use crate::{Data, Error};
+use poise::serenity_prelude as serenity;
+
+/// Every slash command enabled in this build
+pub fn all() -> Vec<poise::Command<Data, Error>> {
+    #[allow(unused_mut)]
+    let mut commands = vec![
+        fishing::fish(),
+        fishing::summary(),
+        fishing::fishhistory(),
+        fishing::fishpedia(),
+        fishing::halloffame(),
+        fishing::topstreaks(),
+        fishing::compare(),
+        fishing::serverstats(),
+        fishing::fortune(),
+        fishing::birthday(),
+        fishing::settimezone(),
+        fishing::invitecredit(),
+        fishing::vacation(),
+        fishing::fishreminders(),
+        fishing::remindme(),
+        fishing::deletemydata(),
+        title::title(),
+        admin::exportdata(),
+        admin::fishconfig(),
+        admin::fishsetup(),
+        admin::refreshbutton(),
+        admin::fishchannels(),
+        admin::importdata(),
+        admin::importstreaks(),
+        fishadmin::command(),
+        admin::fishsummary(),
+        admin::missedreport(),
+        admin::reloaddata(),
+        admin::repairstreak(),
+        admin::setstreak(),
+        admin::adjustcatches(),
+        admin::resetuser(),
+        admin::mergeusers(),
+        admin::restorebackup(),
+        admin::backupnow(),
+        admin::setbestanglerstreak(),
+        admin::setboosterperk(),
+        admin::setpondname(),
+        admin::setembedcolor(),
+        admin::setmessage(),
+        admin::setlocale(),
+        admin::setfireworks(),
+        admin::setreminderthreshold(),
+        admin::setresettime(),
+        admin::setsummarytime(),
+        admin::setguildtimezone(),
+        admin::trackrole(),
+        admin::untrackrole(),
+        admin::blackout(),
+        admin::quietdays(),
+        admin::setsummarychannel(),
+        admin::summarychannels(),
+        streakrole::streakrole(),
+        admin::togglereminder(),
+        admin::admindigest(),
+        admin::status(),
+        admin::inactive(),
+        admin::excludeuser(),
+        admin::fishban(),
+        admin::fishunban(),
+        admin::fishbans(),
+        admin::pausefishing(),
+        admin::toggleverification(),
+    ];
+
+    #[cfg(feature = "economy")]
+    commands.extend([fishing::unlock(), fishing::inventory(), fishing::gift()]);
+
+    #[cfg(feature = "minigames")]
+    commands.extend([minigames::duel(), team::team()]);
+
+    #[cfg(feature = "web")]
+    commands.push(admin::setstreamer());
+
+    #[cfg(feature = "image-gen")]
+    commands.push(fishing::calendar());
+
+    commands
+}
+
+/// The server a command was run in, which keys all of its game state
+pub fn guild_key(ctx: crate::Context<'_>) -> Result<String, Error> {
+    ctx.guild_id()
+        .map(|id| id.to_string())
+        .ok_or_else(|| "This command can only be used in a server".into())
+}
+
+/// Autocomplete over every fishing location
+pub async fn autocomplete_location(
+    _ctx: crate::Context<'_>,
+    partial: &str,
+) -> Vec<serenity::AutocompleteChoice> {
+    crate::fish::LOCATIONS
+        .iter()
+        .filter(|location| {
+            location
+                .name
+                .to_lowercase()
+                .contains(&partial.to_lowercase())
+        })
+        .map(|location| serenity::AutocompleteChoice::new(location.name, location.id))
+        .collect()
+}
+
+/// Autocomplete over IANA timezone names
+pub async fn autocomplete_timezone(
+    _ctx: crate::Context<'_>,
+    partial: &str,
+) -> Vec<serenity::AutocompleteChoice> {
+    let partial = partial.to_lowercase();
+    chrono_tz::TZ_VARIANTS
+        .iter()
+        .map(|tz| tz.name())
+        .filter(|name| name.to_lowercase().contains(&partial))
+        .take(25)
+        .map(|name| serenity::AutocompleteChoice::new(name, name))
+        .collect()
+}
@@ -0,0 +1,116 @@
+use super::guild_key;
+use crate::data::StreakRole;
+use crate::{Context, Error};
+use poise::serenity_prelude as serenity;
+
+/// Reward long fishing streaks with Discord roles
+#[poise::command(
+    slash_command,
+    subcommands("add", "remove", "list"),
+    subcommand_required,
+    guild_only,
+    default_member_permissions = "ADMINISTRATOR"
+)]
+pub async fn streakrole(_ctx: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+/// Give a role to members once their streak reaches a number of days
+#[poise::command(slash_command)]
+pub async fn add(
+    ctx: Context<'_>,
+    #[description = "Streak length in days"]
+    #[min = 1]
+    streak: u64,
+    #[description = "The role to hand out"] role: serenity::Role,
+) -> Result<(), Error> {
+    {
+        let mut data = ctx.data().data_manager.guild_mut(&guild_key(ctx)?).await;
+        data.streak_roles.retain(|r| r.streak != streak);
+        data.streak_roles.push(StreakRole {
+            streak,
+            role_id: role.id.to_string(),
+        });
+        data.streak_roles.sort_by_key(|r| r.streak);
+    }
+    ctx.data().data_manager.save().await;
+    ctx.data()
+        .data_manager
+        .journal_settings(&guild_key(ctx)?, &["streakRoles"])
+        .await;
+
+    ctx.send(
+        poise::CreateReply::default()
+            .content(format!(
+                "✅ Members with a **{}+ day** streak will now get the **{}** role. Make sure my role sits above it!",
+                streak, role.name
+            ))
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Stop rewarding a streak threshold (roles already given are kept)
+#[poise::command(slash_command)]
+pub async fn remove(
+    ctx: Context<'_>,
+    #[description = "Streak length in days"] streak: u64,
+) -> Result<(), Error> {
+    let removed = {
+        let mut data = ctx.data().data_manager.guild_mut(&guild_key(ctx)?).await;
+        let before = data.streak_roles.len();
+        data.streak_roles.retain(|r| r.streak != streak);
+        before != data.streak_roles.len()
+    };
+
+    let content = if removed {
+        ctx.data().data_manager.save().await;
+        ctx.data()
+            .data_manager
+            .journal_settings(&guild_key(ctx)?, &["streakRoles"])
+            .await;
+        format!("✅ Removed the **{} day** streak reward.", streak)
+    } else {
+        format!("❌ There's no reward for a **{} day** streak.", streak)
+    };
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Show the configured streak rewards
+#[poise::command(slash_command)]
+pub async fn list(ctx: Context<'_>) -> Result<(), Error> {
+    let roles = ctx
+        .data()
+        .data_manager
+        .guild(&guild_key(ctx)?)
+        .await
+        .streak_roles
+        .clone();
+
+    let content = if roles.is_empty() {
+        "No streak rewards yet. Add one with `/streakrole add`.".to_string()
+    } else {
+        roles
+            .iter()
+            .map(|r| format!("🔥 **{}+ days** → <@&{}>", r.streak, r.role_id))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
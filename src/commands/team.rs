@@ -0,0 +1,158 @@
+use super::guild_key;
+use crate::{Context, Error};
+use poise::serenity_prelude as serenity;
+
+/// Fish for a team and compete for the monthly crown
+#[poise::command(
+    slash_command,
+    subcommands("create", "join", "standings"),
+    subcommand_required,
+    guild_only
+)]
+pub async fn team(_ctx: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+async fn autocomplete_team(ctx: Context<'_>, partial: &str) -> Vec<serenity::AutocompleteChoice> {
+    let Ok(guild_id) = guild_key(ctx) else {
+        return Vec::new();
+    };
+    ctx.data()
+        .fishing_manager
+        .team_standings(&guild_id)
+        .await
+        .into_iter()
+        .filter(|(name, _, _)| name.to_lowercase().contains(&partial.to_lowercase()))
+        .map(|(name, _, _)| serenity::AutocompleteChoice::new(name.clone(), name.to_lowercase()))
+        .collect()
+}
+
+async fn reply(ctx: Context<'_>, content: String, ephemeral: bool) -> Result<(), Error> {
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(ephemeral),
+    )
+    .await?;
+    Ok(())
+}
+
+/// Start a new team and join it
+#[poise::command(slash_command)]
+pub async fn create(
+    ctx: Context<'_>,
+    #[description = "Team name, e.g. Red Reef"]
+    #[min_length = 2]
+    #[max_length = 32]
+    name: String,
+) -> Result<(), Error> {
+    let result = ctx
+        .data()
+        .fishing_manager
+        .create_team(
+            &guild_key(ctx)?,
+            &ctx.author().id.to_string(),
+            &ctx.author().name,
+            &name,
+        )
+        .await;
+
+    match result {
+        Ok(()) => {
+            reply(
+                ctx,
+                format!(
+                    "🏁 <@{}> founded **{}**! Join with `/team join`.",
+                    ctx.author().id,
+                    name.trim()
+                ),
+                false,
+            )
+            .await
+        }
+        Err(e) => match crate::ui::fishing_error_message(&e) {
+            Some(content) => reply(ctx, content, true).await,
+            None => Err(e.into()),
+        },
+    }
+}
+
+/// Join a team (points you've already earned stay with your old team)
+#[poise::command(slash_command)]
+pub async fn join(
+    ctx: Context<'_>,
+    #[description = "The team to join"]
+    #[autocomplete = "autocomplete_team"]
+    team: String,
+) -> Result<(), Error> {
+    let result = ctx
+        .data()
+        .fishing_manager
+        .join_team(
+            &guild_key(ctx)?,
+            &ctx.author().id.to_string(),
+            &ctx.author().name,
+            &team,
+        )
+        .await;
+
+    match result {
+        Ok(name) => {
+            reply(
+                ctx,
+                format!("🏁 <@{}> joined **{}**!", ctx.author().id, name),
+                false,
+            )
+            .await
+        }
+        Err(e) => match crate::ui::fishing_error_message(&e) {
+            Some(content) => reply(ctx, content, true).await,
+            None => Err(e.into()),
+        },
+    }
+}
+
+/// Show this month's team standings
+#[poise::command(slash_command)]
+pub async fn standings(ctx: Context<'_>) -> Result<(), Error> {
+    let standings = ctx
+        .data()
+        .fishing_manager
+        .team_standings(&guild_key(ctx)?)
+        .await;
+    if standings.is_empty() {
+        return reply(
+            ctx,
+            "No teams yet. Start one with `/team create`!".to_string(),
+            true,
+        )
+        .await;
+    }
+
+    let medals = ["🥇", "🥈", "🥉"];
+    let lines = standings
+        .iter()
+        .enumerate()
+        .map(|(i, (name, today, month))| {
+            format!(
+                "{} **{}** — {} points this month (+{} today)",
+                medals.get(i).unwrap_or(&"🏁"),
+                name,
+                month,
+                today
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let branding = ctx.data().fishing_manager.branding(&guild_key(ctx)?).await;
+    let embed = serenity::CreateEmbed::new()
+        .color(branding.color)
+        .title("🏁 Team Standings")
+        .description(lines)
+        .footer(serenity::CreateEmbedFooter::new(
+            "The top team is crowned at the end of each month",
+        ));
+    ctx.send(poise::CreateReply::default().embed(embed)).await?;
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use super::guild_key;
+use crate::{Context, Error};
+use poise::serenity_prelude as serenity;
+
+/// Show off a title you've earned
+#[poise::command(slash_command, subcommands("set", "clear"), subcommand_required)]
+pub async fn title(_ctx: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+async fn autocomplete_earned_title(
+    ctx: Context<'_>,
+    partial: &str,
+) -> Vec<serenity::AutocompleteChoice> {
+    let Ok(guild_id) = guild_key(ctx) else {
+        return Vec::new();
+    };
+    ctx.data()
+        .fishing_manager
+        .earned_titles(&guild_id, &ctx.author().id.to_string())
+        .await
+        .into_iter()
+        .filter(|title| title.name.to_lowercase().contains(&partial.to_lowercase()))
+        .map(|title| serenity::AutocompleteChoice::new(title.label(), title.id))
+        .collect()
+}
+
+/// Equip one of your earned titles
+#[poise::command(slash_command)]
+pub async fn set(
+    ctx: Context<'_>,
+    #[description = "A title you've earned"]
+    #[autocomplete = "autocomplete_earned_title"]
+    title: String,
+) -> Result<(), Error> {
+    let result = ctx
+        .data()
+        .fishing_manager
+        .equip_title(&guild_key(ctx)?, &ctx.author().id.to_string(), Some(&title))
+        .await;
+    respond(ctx, result).await
+}
+
+/// Stop showing a title next to your name
+#[poise::command(slash_command)]
+pub async fn clear(ctx: Context<'_>) -> Result<(), Error> {
+    let result = ctx
+        .data()
+        .fishing_manager
+        .equip_title(&guild_key(ctx)?, &ctx.author().id.to_string(), None)
+        .await;
+    respond(ctx, result).await
+}
+
+async fn respond(
+    ctx: Context<'_>,
+    result: Result<Option<&'static crate::titles::Title>, crate::game::FishingError>,
+) -> Result<(), Error> {
+    let content = match result {
+        Ok(Some(title)) => format!("🏅 You are now known as **{}**!", title.label()),
+        Ok(None) => "✅ Title cleared.".to_string(),
+        Err(e) => match crate::ui::fishing_error_message(&e) {
+            Some(content) => content,
+            None => return Err(e.into()),
+        },
+    };
+    ctx.send(
+        poise::CreateReply::default()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await?;
+    Ok(())
+}
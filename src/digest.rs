@@ -0,0 +1,162 @@
+//! The opt-in digest admins get by DM after each reset: who didn't fish,
+//! the day's milestones, and how the saved data is doing. Staff see the
+//! details without them cluttering public channels.
+
+use crate::game::{AdminDigest, FishingManager};
+use chrono::Utc;
+use nightmare_fishing_core::storage::{BackupInfo, StorageError};
+use poise::serenity_prelude::{self as serenity, CreateEmbed, CreateMessage};
+
+/// Room for a list in one embed field
+const FIELD_LIMIT: usize = 1000;
+
+/// A server's digest, gathered before its reset clears the day
+pub struct PreparedDigest {
+    guild_id: String,
+    digest: AdminDigest,
+    non_fishers: Vec<serenity::UserId>,
+    pond_name: String,
+    color: u32,
+}
+
+/// Gather `guild_id`'s digest; `None` if no admin opted in
+pub async fn prepare(
+    fishing_manager: &FishingManager,
+    http: &serenity::Http,
+    guild_id: &str,
+) -> Option<PreparedDigest> {
+    let digest = fishing_manager.admin_digest(guild_id).await?;
+    let summary = fishing_manager.daily_summary(guild_id).await;
+    let g_id = serenity::GuildId::new(guild_id.parse::<u64>().ok()?);
+    let non_fishers = crate::summary::non_fishers(fishing_manager, http, g_id, &summary).await;
+    let branding = fishing_manager.branding(guild_id).await;
+    Some(PreparedDigest {
+        guild_id: guild_id.to_string(),
+        digest,
+        non_fishers,
+        pond_name: branding.pond_name,
+        color: branding.color,
+    })
+}
+
+/// Join `items` with `separator`, cut short with a count of the rest once
+/// they no longer fit in an embed field
+fn fit(items: &[String], separator: &str) -> String {
+    let mut text = String::new();
+    for (shown, item) in items.iter().enumerate() {
+        if text.len() + separator.len() + item.len() > FIELD_LIMIT {
+            text.push_str(&format!("{}…and {} more", separator, items.len() - shown));
+            break;
+        }
+        if shown > 0 {
+            text.push_str(separator);
+        }
+        text.push_str(item);
+    }
+    text
+}
+
+fn embed(
+    prepared: &PreparedDigest,
+    backup: &Result<Option<BackupInfo>, StorageError>,
+) -> CreateEmbed {
+    let digest = &prepared.digest;
+
+    let non_fishers = if prepared.non_fishers.is_empty() {
+        "Everyone tracked is caught up! 🎉".to_string()
+    } else {
+        let mentions: Vec<String> = prepared
+            .non_fishers
+            .iter()
+            .map(|id| format!("<@{}>", id))
+            .collect();
+        fit(&mentions, " ")
+    };
+
+    let mut milestones: Vec<String> = digest
+        .milestones
+        .iter()
+        .map(|milestone| match &milestone.role_id {
+            Some(role_id) => format!(
+                "🔥 **{}** hit a **{}-day** streak and earned <@&{}>",
+                milestone.username, milestone.streak, role_id
+            ),
+            None => format!(
+                "🔥 **{}** hit a **{}-day** streak",
+                milestone.username, milestone.streak
+            ),
+        })
+        .collect();
+    let records = crate::ui::hall_of_fame_lines(&digest.hall_of_fame, Some(&digest.today_date));
+    milestones.extend(
+        records
+            .lines()
+            .filter(|line| line.ends_with("🆕"))
+            .map(|line| format!("🏆 New record! {}", line)),
+    );
+    let milestones = if milestones.is_empty() {
+        "*None today*".to_string()
+    } else {
+        fit(&milestones, "\n")
+    };
+
+    let backup = match backup {
+        Ok(Some(info)) => format!("✅ `{}` ({} KB)", info.name, info.bytes / 1024),
+        Ok(None) => "➖ Nothing to back up".to_string(),
+        Err(e) => format!("🚨 **Failed**: {}", e),
+    };
+    let health = format!(
+        "Backup: {}\nPlayers saved: **{}**\nCatches in live history: **{}**\nDays charted: **{}**\nMessages awaiting cleanup: **{}**",
+        backup,
+        digest.players,
+        digest.history_records,
+        digest.history_days,
+        digest.pending_deletions
+    );
+
+    CreateEmbed::new()
+        .title(format!("📬 Admin Digest: {}", prepared.pond_name))
+        .description(format!(
+            "The day of **{}** is done: **{}** fish caught.",
+            digest.today_date, digest.daily_count
+        ))
+        .field(
+            format!("🎣 Didn't fish ({})", prepared.non_fishers.len()),
+            non_fishers,
+            false,
+        )
+        .field("🏅 New milestones", milestones, false)
+        .field("🩺 Data health", health, false)
+        .footer(serenity::CreateEmbedFooter::new(
+            "Stop these with /admindigest enabled:False",
+        ))
+        .color(prepared.color)
+        .timestamp(Utc::now())
+}
+
+/// DM the digest to every admin who opted in, with how the reset's backup went
+pub async fn send(
+    http: &serenity::Http,
+    prepared: &PreparedDigest,
+    backup: &Result<Option<BackupInfo>, StorageError>,
+) {
+    let embed = embed(prepared, backup);
+    for admin_id in &prepared.digest.recipients {
+        let Ok(user_id) = admin_id.parse::<u64>() else {
+            continue;
+        };
+        let msg = CreateMessage::new().embed(embed.clone());
+        if let Err(e) = serenity::UserId::new(user_id)
+            .direct_message(http, msg)
+            .await
+        {
+            // Usually DMs switched off; nothing to do but note it
+            tracing::warn!(
+                "⚠️ Could not DM the admin digest to {} in {}: {}",
+                admin_id,
+                prepared.guild_id,
+                e
+            );
+        }
+    }
+}
@@ -1,102 +1,848 @@
-use crate::game::{FishingError, FishingManager};
-use chrono::Utc;
+use crate::game::FishingManager;
+use crate::verify::CastOrigin;
 use poise::serenity_prelude as serenity;
 
-/// Handle button interactions
-pub async fn handle_button_interaction(
+/// Location menu opened from the pond button
+const LOCATION_MENU_BUTTON: &str = "fish_location:button";
+/// Location menu opened from `/fish`
+pub const LOCATION_MENU_COMMAND: &str = "fish_location:command";
+/// How long a fireworks burst stays up before the janitor removes it
+const FIREWORKS_LIFETIME: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Route component interactions (buttons and select menus) to their handlers
+pub async fn handle_component_interaction(
     ctx: &serenity::Context,
     interaction: &serenity::ComponentInteraction,
-    data_manager: &std::sync::Arc<crate::data::DataManager>,
-    fishing_manager: &std::sync::Arc<FishingManager>,
+    data: &crate::Data,
 ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    if interaction.data.custom_id != "fish_button" {
+    // Every button belongs to a server's pond; there are none in DMs
+    let Some(guild_id) = interaction.guild_id.map(|id| id.to_string()) else {
         return Ok(());
+    };
+    let guild_id = guild_id.as_str();
+    match interaction.data.custom_id.as_str() {
+        "fish_button" => {
+            handle_fish_button(ctx, interaction, guild_id, &data.fishing_manager).await
+        }
+        LOCATION_MENU_BUTTON | LOCATION_MENU_COMMAND => {
+            handle_location_select(ctx, interaction, guild_id, data).await
+        }
+        crate::summary::SNOOZE_BUTTON => handle_snooze(ctx, interaction, guild_id, data).await,
+        #[cfg(feature = "minigames")]
+        crate::boat::BOARD_BUTTON => handle_board_boat(ctx, interaction, guild_id, data).await,
+        id => {
+            if let Some(location_id) = id.strip_prefix("fish_button:") {
+                return handle_biome_button(ctx, interaction, guild_id, location_id, data).await;
+            }
+            if let Some(choice) = id.strip_prefix(crate::verify::VERIFY_BUTTON_PREFIX) {
+                return handle_verify(ctx, interaction, guild_id, choice, data).await;
+            }
+            #[cfg(feature = "minigames")]
+            if let Some(spawn_id) = id.strip_prefix(crate::wild_fish::CATCH_BUTTON_PREFIX) {
+                return handle_wild_fish(ctx, interaction, guild_id, spawn_id, data).await;
+            }
+            #[cfg(feature = "minigames")]
+            if let Some(owner) = id.strip_prefix("spin_wheel:") {
+                return handle_spin_wheel(ctx, interaction, guild_id, owner, data).await;
+            }
+            #[cfg(feature = "economy")]
+            if let Some(item_id) = id.strip_prefix("merchant_buy:") {
+                return handle_merchant_buy(ctx, interaction, guild_id, item_id, data).await;
+            }
+            Ok(())
+        }
     }
+}
 
-    let user_id = interaction.user.id.to_string();
-    let username = interaction
+/// The name the clicker goes by here, matching what the daily username
+/// refresh stores
+fn display_name(interaction: &serenity::ComponentInteraction) -> String {
+    interaction
         .member
         .as_ref()
-        .and_then(|m| m.nick.as_ref())
-        .unwrap_or(&interaction.user.name)
-        .clone();
+        .map_or_else(|| interaction.user.display_name(), |m| m.display_name())
+        .to_string()
+}
 
+/// Whether the clicking member is boosting the server
+fn is_booster(interaction: &serenity::ComponentInteraction) -> bool {
+    interaction
+        .member
+        .as_ref()
+        .is_some_and(|m| m.premium_since.is_some())
+}
 
-    // Call shared fishing logic
-    let (current_streak, total_catches, daily_count, old_button_msg, old_button_channel) =
-        match fishing_manager
-            .handle_fishing(user_id, username.clone())
+/// The summary's snooze button mutes the clicker's reminders for a few days
+async fn handle_snooze(
+    ctx: &serenity::Context,
+    interaction: &serenity::ComponentInteraction,
+    guild_id: &str,
+    data: &crate::Data,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let until = data
+        .fishing_manager
+        .snooze_reminders(guild_id, &interaction.user.id.to_string())
+        .await;
+    let locale = data.data_manager.guild(guild_id).await.locale;
+    let content =
+        crate::data::fill_template(crate::i18n::strings(locale).snoozed, &[("date", &until)]);
+    interaction
+        .create_response(
+            &ctx.http,
+            serenity::CreateInteractionResponse::Message(
+                serenity::CreateInteractionResponseMessage::new()
+                    .content(content)
+                    .ephemeral(true),
+            ),
+        )
+        .await?;
+    Ok(())
+}
+
+/// Biome buttons skip the picker and cast straight into their location
+async fn handle_biome_button(
+    ctx: &serenity::Context,
+    interaction: &serenity::ComponentInteraction,
+    guild_id: &str,
+    location_id: &str,
+    data: &crate::Data,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    if verification_enabled(guild_id, data).await {
+        let (content, row) =
+            data.verifier
+                .issue(interaction.user.id, location_id, CastOrigin::Biome);
+        interaction
+            .create_response(
+                &ctx.http,
+                serenity::CreateInteractionResponse::Message(
+                    serenity::CreateInteractionResponseMessage::new()
+                        .content(content)
+                        .components(vec![row])
+                        .ephemeral(true),
+                ),
+            )
+            .await?;
+        return Ok(());
+    }
+
+    let username = display_name(interaction);
+
+    let outcome = match data
+        .fishing_manager
+        .handle_fishing(
+            guild_id,
+            interaction.user.id.to_string(),
+            username.clone(),
+            location_id,
+            is_booster(interaction),
+        )
+        .await
+    {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            let content = match crate::ui::fishing_error_message(&e) {
+                Some(content) => content,
+                None => {
+                    tracing::error!("Error during biome fishing: {:?}", e);
+                    return Err(e.into());
+                }
+            };
+            interaction
+                .create_response(
+                    &ctx.http,
+                    serenity::CreateInteractionResponse::Message(
+                        serenity::CreateInteractionResponseMessage::new()
+                            .content(content)
+                            .ephemeral(true),
+                    ),
+                )
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let (fish_embed, card) = crate::ui::catch_post(&username, interaction.user.face(), &outcome);
+    let mut message = serenity::CreateInteractionResponseMessage::new().embed(fish_embed);
+    if let Some(card) = card {
+        message = message.add_file(card);
+    }
+    #[cfg(feature = "minigames")]
+    {
+        message = message.components(vec![crate::ui::spin_button_row(interaction.user.id)]);
+    }
+    interaction
+        .create_response(
+            &ctx.http,
+            serenity::CreateInteractionResponse::Message(message),
+        )
+        .await?;
+
+    celebrate_catch(ctx, guild_id, interaction.channel_id, &outcome, data).await;
+    announce_shiny(
+        ctx,
+        guild_id,
+        interaction.channel_id,
+        &username,
+        &outcome,
+        data,
+    )
+    .await;
+    crate::roles::grant_streak_roles(ctx, interaction, &outcome).await;
+    repost_fish_button(
+        ctx,
+        guild_id,
+        interaction.channel_id,
+        Some(outcome.location),
+        &data.data_manager,
+    )
+    .await
+}
+
+/// Reply privately with the location picker
+async fn handle_fish_button(
+    ctx: &serenity::Context,
+    interaction: &serenity::ComponentInteraction,
+    guild_id: &str,
+    fishing_manager: &std::sync::Arc<FishingManager>,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let user_id = interaction.user.id.to_string();
+
+    let response = if fishing_manager.is_paused(guild_id).await {
+        serenity::CreateInteractionResponseMessage::new()
+            .content(crate::ui::POND_FROZEN_MESSAGE)
+            .ephemeral(true)
+    } else if let Some(ban) = fishing_manager.fish_ban(guild_id, &user_id).await {
+        serenity::CreateInteractionResponseMessage::new()
+            .content(crate::ui::fish_ban_message(
+                ban.until,
+                ban.reason.as_deref(),
+            ))
+            .ephemeral(true)
+    } else if fishing_manager
+        .out_of_casts(guild_id, &user_id, is_booster(interaction))
+        .await
+    {
+        let local_reset = fishing_manager.local_next_reset(guild_id, &user_id).await;
+        serenity::CreateInteractionResponseMessage::new()
+            .content(crate::ui::already_fished_message(local_reset))
+            .ephemeral(true)
+    } else {
+        let access = fishing_manager.location_access(guild_id, &user_id).await;
+        serenity::CreateInteractionResponseMessage::new()
+            .content("🗺️ Where would you like to fish today?")
+            .components(vec![crate::ui::location_menu(
+                LOCATION_MENU_BUTTON,
+                &access,
+            )])
+            .ephemeral(true)
+    };
+
+    interaction
+        .create_response(
+            &ctx.http,
+            serenity::CreateInteractionResponse::Message(response),
+        )
+        .await?;
+    Ok(())
+}
+
+/// Cast at the chosen location and announce the catch
+async fn handle_location_select(
+    ctx: &serenity::Context,
+    interaction: &serenity::ComponentInteraction,
+    guild_id: &str,
+    data: &crate::Data,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let location_id = match &interaction.data.kind {
+        serenity::ComponentInteractionDataKind::StringSelect { values } => match values.first() {
+            Some(v) => v.clone(),
+            None => return Ok(()),
+        },
+        _ => return Ok(()),
+    };
+    let origin = if interaction.data.custom_id == LOCATION_MENU_BUTTON {
+        CastOrigin::PondButton
+    } else {
+        CastOrigin::Command
+    };
+
+    if verification_enabled(guild_id, data).await {
+        let (content, row) = data
+            .verifier
+            .issue(interaction.user.id, &location_id, origin);
+        interaction
+            .create_response(
+                &ctx.http,
+                serenity::CreateInteractionResponse::UpdateMessage(
+                    serenity::CreateInteractionResponseMessage::new()
+                        .content(content)
+                        .components(vec![row]),
+                ),
+            )
+            .await?;
+        return Ok(());
+    }
+
+    cast_from_private_message(ctx, interaction, guild_id, &location_id, origin, data).await
+}
+
+/// Check the answer to a verification challenge and cast if it was right
+async fn handle_verify(
+    ctx: &serenity::Context,
+    interaction: &serenity::ComponentInteraction,
+    guild_id: &str,
+    choice: &str,
+    data: &crate::Data,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let challenge = choice
+        .parse::<usize>()
+        .ok()
+        .and_then(|choice| data.verifier.answer(interaction.user.id, choice));
+    match challenge {
+        Some(challenge) => {
+            cast_from_private_message(
+                ctx,
+                interaction,
+                guild_id,
+                &challenge.location_id,
+                challenge.origin,
+                data,
+            )
             .await
-        {
-            Ok((streak, catches, count)) => {
-                let data = data_manager.data.read().await;
-                let msg = data.button_message_id.clone();
-                let ch = data.button_channel_id.clone();
-                (streak, catches, count, msg, ch)
-            }
-            Err(FishingError::AlreadyFished) => {
-                interaction
-                    .create_response(
-                        &ctx.http,
-                        serenity::CreateInteractionResponse::Message(
-                            serenity::CreateInteractionResponseMessage::new()
-                                .content("❌ You've already fished today! Come back tomorrow.")
-                                .ephemeral(true),
-                        ),
-                    )
-                    .await?;
-                return Ok(());
-            }
-            Err(e) => {
-                tracing::error!("Error during button fishing: {:?}", e);
-                return Err(e.into());
-            }
-        };
+        }
+        None => {
+            tracing::warn!("🛡️ {} failed a catch check", interaction.user.name);
+            interaction
+                .create_response(
+                    &ctx.http,
+                    serenity::CreateInteractionResponse::UpdateMessage(
+                        serenity::CreateInteractionResponseMessage::new()
+                            .content("❌ That wasn't it (or the check timed out). Click the fish button to try again.")
+                            .components(vec![]),
+                    ),
+                )
+                .await?;
+            Ok(())
+        }
+    }
+}
+
+async fn verification_enabled(guild_id: &str, data: &crate::Data) -> bool {
+    data.data_manager.guild(guild_id).await.verification_enabled
+}
+
+/// Cast from a private message (the location picker or a challenge), close it,
+/// and announce the catch publicly
+async fn cast_from_private_message(
+    ctx: &serenity::Context,
+    interaction: &serenity::ComponentInteraction,
+    guild_id: &str,
+    location_id: &str,
+    origin: CastOrigin,
+    data: &crate::Data,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let user_id = interaction.user.id.to_string();
+    let username = display_name(interaction);
+
+    // Call shared fishing logic
+    let outcome = match data
+        .fishing_manager
+        .handle_fishing(
+            guild_id,
+            user_id,
+            username.clone(),
+            location_id,
+            is_booster(interaction),
+        )
+        .await
+    {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            let content = match crate::ui::fishing_error_message(&e) {
+                Some(content) => content,
+                None => {
+                    tracing::error!("Error during button fishing: {:?}", e);
+                    return Err(e.into());
+                }
+            };
+            interaction
+                .create_response(
+                    &ctx.http,
+                    serenity::CreateInteractionResponse::UpdateMessage(
+                        serenity::CreateInteractionResponseMessage::new()
+                            .content(content)
+                            .components(vec![]),
+                    ),
+                )
+                .await?;
+            return Ok(());
+        }
+    };
+
+    // Close the private picker before announcing publicly
+    interaction
+        .create_response(
+            &ctx.http,
+            serenity::CreateInteractionResponse::UpdateMessage(
+                serenity::CreateInteractionResponseMessage::new()
+                    .content(format!(
+                        "🎣 You cast your line at {} {}!",
+                        outcome.location.emoji, outcome.location.name
+                    ))
+                    .components(vec![]),
+            ),
+        )
+        .await?;
 
-    // Create fish embed response
-    let fish_embed = serenity::CreateEmbed::new()
-        .color(0x0099FF)
-        .title("🎣 Catch of the Day!")
+    let (fish_embed, card) = crate::ui::catch_post(&username, interaction.user.face(), &outcome);
+    let mut message = serenity::CreateMessage::new().embed(fish_embed);
+    if let Some(card) = card {
+        message = message.add_file(card);
+    }
+    #[cfg(feature = "minigames")]
+    {
+        message = message.components(vec![crate::ui::spin_button_row(interaction.user.id)]);
+    }
+    interaction
+        .channel_id
+        .send_message(&ctx.http, message)
+        .await?;
+
+    celebrate_catch(ctx, guild_id, interaction.channel_id, &outcome, data).await;
+    announce_shiny(
+        ctx,
+        guild_id,
+        interaction.channel_id,
+        &username,
+        &outcome,
+        data,
+    )
+    .await;
+    crate::roles::grant_streak_roles(ctx, interaction, &outcome).await;
+
+    match origin {
+        CastOrigin::PondButton => {
+            repost_fish_button(
+                ctx,
+                guild_id,
+                interaction.channel_id,
+                None,
+                &data.data_manager,
+            )
+            .await?
+        }
+        CastOrigin::Biome => {
+            repost_fish_button(
+                ctx,
+                guild_id,
+                interaction.channel_id,
+                Some(outcome.location),
+                &data.data_manager,
+            )
+            .await?
+        }
+        CastOrigin::Command => {}
+    }
+
+    Ok(())
+}
+
+/// Spin the bonus wheel offered under a catch
+#[cfg(feature = "minigames")]
+async fn handle_spin_wheel(
+    ctx: &serenity::Context,
+    interaction: &serenity::ComponentInteraction,
+    guild_id: &str,
+    owner: &str,
+    data: &crate::Data,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    use crate::wheel::SpinPrize;
+
+    let ephemeral = |content: String| {
+        serenity::CreateInteractionResponse::Message(
+            serenity::CreateInteractionResponseMessage::new()
+                .content(content)
+                .ephemeral(true),
+        )
+    };
+
+    if interaction.user.id.to_string() != owner {
+        interaction
+            .create_response(
+                &ctx.http,
+                ephemeral(
+                    "🎡 That's someone else's wheel — catch a fish to get your own!".to_string(),
+                ),
+            )
+            .await?;
+        return Ok(());
+    }
+
+    let prize = match data.fishing_manager.spin_wheel(guild_id, owner).await {
+        Ok(prize) => prize,
+        Err(e) => {
+            let content = match crate::ui::fishing_error_message(&e) {
+                Some(content) => content,
+                None => return Err(e.into()),
+            };
+            interaction
+                .create_response(&ctx.http, ephemeral(content))
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let result = match prize {
+        SpinPrize::Nothing => "...and it lands on **nothing**. Better luck tomorrow!".to_string(),
+        SpinPrize::Coins(amount) => format!("...and wins **{}** 🪙!", amount),
+        SpinPrize::Item(id) => match crate::items::find_item(id) {
+            Some(item) => format!("...and wins a {} **{}**!", item.emoji, item.name),
+            None => format!("...and wins a **{}**!", id),
+        },
+    };
+
+    // Drop the button so the wheel can't be offered again
+    interaction
+        .create_response(
+            &ctx.http,
+            serenity::CreateInteractionResponse::UpdateMessage(
+                serenity::CreateInteractionResponseMessage::new().components(vec![]),
+            ),
+        )
+        .await?;
+    interaction
+        .create_followup(
+            &ctx.http,
+            serenity::CreateInteractionResponseFollowup::new().content(format!(
+                "🎡 <@{}> spins the wheel {}",
+                interaction.user.id, result
+            )),
+        )
+        .await?;
+    Ok(())
+}
+
+/// First click wins the wild fish; the claim itself is atomic in the engine
+#[cfg(feature = "minigames")]
+async fn handle_wild_fish(
+    ctx: &serenity::Context,
+    interaction: &serenity::ComponentInteraction,
+    guild_id: &str,
+    spawn_id: &str,
+    data: &crate::Data,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let username = display_name(interaction);
+    let catch = match data
+        .fishing_manager
+        .claim_wild_fish(
+            guild_id,
+            spawn_id,
+            &interaction.user.id.to_string(),
+            &username,
+        )
+        .await
+    {
+        Ok(catch) => catch,
+        Err(e) => {
+            let content = match crate::ui::fishing_error_message(&e) {
+                Some(content) => content,
+                None => return Err(e.into()),
+            };
+            interaction
+                .create_response(
+                    &ctx.http,
+                    serenity::CreateInteractionResponse::Message(
+                        serenity::CreateInteractionResponseMessage::new()
+                            .content(content)
+                            .ephemeral(true),
+                    ),
+                )
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let style = crate::ui::rarity_style(catch.species.rarity);
+    let embed = serenity::CreateEmbed::new()
+        .color(style.color)
+        .title("🐟 Wild fish caught!")
         .description(format!(
-            "**{}** cast their line and caught a fish! 🐟",
-            username
+            "**{}** grabbed the wild {} **{}** ({} cm, {} {})!",
+            username,
+            catch.species.emoji,
+            catch.species.name,
+            catch.size_cm,
+            style.emoji,
+            catch.species.rarity.label()
         ))
-        .thumbnail(interaction.user.face())
-        .field("🔥 Streak", format!("{} Days", current_streak), true)
-        .field("✨ Total Catches", format!("{}", total_catches), true)
-        .field("🌍 Total Catches Today", format!("{}", daily_count), true)
-        .timestamp(Utc::now())
-        .footer(serenity::CreateEmbedFooter::new("Stardust Pond"));
+        .field("🪙 Coins", format!("+{}", catch.coins_earned), true)
+        .field("✨ Total Catches", format!("{}", catch.total_catches), true)
+        .footer(serenity::CreateEmbedFooter::new(
+            data.fishing_manager.branding(guild_id).await.pond_name,
+        ));
+    interaction
+        .create_response(
+            &ctx.http,
+            serenity::CreateInteractionResponse::UpdateMessage(
+                serenity::CreateInteractionResponseMessage::new()
+                    .embed(embed)
+                    .components(vec![]),
+            ),
+        )
+        .await?;
+    Ok(())
+}
+
+/// Add the clicker to the boat's crew and refresh the crew list
+#[cfg(feature = "minigames")]
+async fn handle_board_boat(
+    ctx: &serenity::Context,
+    interaction: &serenity::ComponentInteraction,
+    guild_id: &str,
+    data: &crate::Data,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let result = data
+        .fishing_manager
+        .board_boat(
+            guild_id,
+            &interaction.user.id.to_string(),
+            &display_name(interaction),
+        )
+        .await;
 
+    let content = match result {
+        Ok(crew) => format!(
+            "⛵ Welcome aboard! The crew is **{}** strong. The haul will be posted here.",
+            crew
+        ),
+        Err(e) => match crate::ui::fishing_error_message(&e) {
+            Some(content) => content,
+            None => return Err(e.into()),
+        },
+    };
     interaction
         .create_response(
             &ctx.http,
             serenity::CreateInteractionResponse::Message(
-                serenity::CreateInteractionResponseMessage::new().embed(fish_embed),
+                serenity::CreateInteractionResponseMessage::new()
+                    .content(content)
+                    .ephemeral(true),
             ),
         )
         .await?;
 
-    // Create new button message
-    let row = serenity::CreateActionRow::Buttons(vec![serenity::CreateButton::new("fish_button")
-        .label("🎣 Fish!")
-        .style(serenity::ButtonStyle::Primary)]);
+    if let Some(trip) = data.fishing_manager.boat_trip(guild_id).await {
+        let branding = data.fishing_manager.branding(guild_id).await;
+        let (embed, row) = crate::boat::boarding_message(&trip, &branding.pond_name);
+        interaction
+            .channel_id
+            .edit_message(
+                &ctx.http,
+                interaction.message.id,
+                serenity::EditMessage::new()
+                    .embed(embed)
+                    .components(vec![row]),
+            )
+            .await?;
+    }
+    Ok(())
+}
 
-    let new_button_msg = interaction
-        .channel_id
+/// Buy from the travelling merchant and refresh the remaining stock
+#[cfg(feature = "economy")]
+async fn handle_merchant_buy(
+    ctx: &serenity::Context,
+    interaction: &serenity::ComponentInteraction,
+    guild_id: &str,
+    item_id: &str,
+    data: &crate::Data,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let result = data
+        .fishing_manager
+        .buy_from_merchant(guild_id, &interaction.user.id.to_string(), item_id)
+        .await;
+
+    let (content, visit) = match result {
+        Ok((item, price, coins_left, visit)) => (
+            format!(
+                "🧳 You bought {} **{}** for {} coins! You have **{}** coins left.",
+                item.emoji, item.name, price, coins_left
+            ),
+            Some(visit),
+        ),
+        Err(e) => match crate::ui::fishing_error_message(&e) {
+            Some(content) => (content, None),
+            None => return Err(e.into()),
+        },
+    };
+
+    interaction
+        .create_response(
+            &ctx.http,
+            serenity::CreateInteractionResponse::Message(
+                serenity::CreateInteractionResponseMessage::new()
+                    .content(content)
+                    .ephemeral(true),
+            ),
+        )
+        .await?;
+
+    if let Some(visit) = visit {
+        let branding = data.fishing_manager.branding(guild_id).await;
+        let (embed, components) = crate::merchant::merchant_message(&visit, &branding.pond_name);
+        interaction
+            .channel_id
+            .edit_message(
+                &ctx.http,
+                interaction.message.id,
+                serenity::EditMessage::new()
+                    .embed(embed)
+                    .components(components),
+            )
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// Short-lived fireworks burst for legendary catches, cleaned up by the janitor
+async fn celebrate_catch(
+    ctx: &serenity::Context,
+    guild_id: &str,
+    channel_id: serenity::ChannelId,
+    outcome: &crate::game::CatchOutcome,
+    data: &crate::Data,
+) {
+    if outcome.species.rarity != crate::fish::Rarity::Legendary {
+        return;
+    }
+    let intensity = data.data_manager.guild(guild_id).await.fireworks_intensity;
+    let content = match crate::ui::fireworks_burst(intensity, outcome) {
+        Some(content) => content,
+        None => return,
+    };
+
+    match channel_id
+        .send_message(&ctx.http, serenity::CreateMessage::new().content(content))
+        .await
+    {
+        Ok(msg) => {
+            data.janitor
+                .schedule_delete(guild_id, channel_id, msg.id, FIREWORKS_LIFETIME)
+                .await
+        }
+        Err(e) => tracing::error!("❌ Error posting fireworks: {}", e),
+    }
+}
+
+/// Tell the pond channel about a shiny catch made anywhere else
+async fn announce_shiny(
+    ctx: &serenity::Context,
+    guild_id: &str,
+    catch_channel: serenity::ChannelId,
+    username: &str,
+    outcome: &crate::game::CatchOutcome,
+    data: &crate::Data,
+) {
+    if !outcome.shiny {
+        return;
+    }
+    let pond_channel = match data
+        .data_manager
+        .guild(guild_id)
+        .await
+        .button_channel_id
+        .as_ref()
+        .and_then(|id| id.parse::<u64>().ok())
+    {
+        Some(id) => serenity::ChannelId::new(id),
+        None => return,
+    };
+    // The catch embed already makes a fuss in the pond channel itself
+    if pond_channel == catch_channel {
+        return;
+    }
+
+    let embed = serenity::CreateEmbed::new()
+        .color(crate::ui::SHINY_COLOR)
+        .title("🌈 A shiny fish appeared!")
+        .description(format!(
+            "**{}** just reeled in a {} **{}** at {} {}!",
+            username,
+            outcome.species.emoji,
+            crate::ui::species_name(outcome.species, true),
+            outcome.location.emoji,
+            outcome.location.name
+        ))
+        .footer(serenity::CreateEmbedFooter::new(&outcome.pond_name));
+    if let Err(e) = pond_channel
+        .send_message(&ctx.http, serenity::CreateMessage::new().embed(embed))
+        .await
+    {
+        tracing::error!("❌ Error announcing shiny catch: {}", e);
+    }
+}
+
+/// Move a fish button to the bottom of the channel and delete the old one
+pub async fn repost_fish_button(
+    ctx: &serenity::Context,
+    guild_id: &str,
+    channel_id: serenity::ChannelId,
+    biome: Option<&'static crate::fish::Location>,
+    data_manager: &std::sync::Arc<crate::data::DataManager>,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let channel_key = channel_id.to_string();
+    let (old_button_msg, old_button_channel, featured, pond_name, paused) = {
+        let data = data_manager.guild(guild_id).await;
+        let (msg, channel) = match biome {
+            Some(_) => (
+                data.biome_channels
+                    .get(&channel_key)
+                    .and_then(|b| b.button_message_id.clone()),
+                Some(channel_key.clone()),
+            ),
+            None => (
+                data.button_message_id.clone(),
+                data.button_channel_id.clone(),
+            ),
+        };
+        (
+            msg,
+            channel,
+            data.featured_species(),
+            data.branding().pond_name,
+            data.paused_since.is_some(),
+        )
+    };
+
+    // Create new button message
+    let new_button_msg = channel_id
         .send_message(
             &ctx.http,
             serenity::CreateMessage::new()
-                .content("🎣 Welcome to Stardust Pond — click to fish!")
-                .components(vec![row]),
+                .content(crate::ui::fish_button_content(&pond_name, biome, featured))
+                .components(vec![crate::ui::fish_button_row(biome, paused)]),
         )
         .await?;
 
     // Update button info
     {
-        let mut data = data_manager.data.write().await;
-        data.button_message_id = Some(new_button_msg.id.to_string());
-        data.button_channel_id = Some(interaction.channel_id.to_string());
+        let mut data = data_manager.guild_mut(guild_id).await;
+        match biome {
+            Some(location) => {
+                data.biome_channels.insert(
+                    channel_key,
+                    crate::data::BiomeChannel {
+                        location: location.id.to_string(),
+                        button_message_id: Some(new_button_msg.id.to_string()),
+                    },
+                );
+            }
+            None => {
+                data.button_message_id = Some(new_button_msg.id.to_string());
+                data.button_channel_id = Some(channel_key);
+            }
+        }
     }
     data_manager.save().await;
 
@@ -112,3 +858,52 @@ pub async fn handle_button_interaction(
 
     Ok(())
 }
+
+/// Rewrite every fish button in a server, e.g. after a new Fish of the Day
+/// is picked, the pond is frozen, or it's renamed
+pub async fn refresh_fish_buttons(
+    http: &serenity::Http,
+    data_manager: &crate::data::DataManager,
+    guild_id: &str,
+) {
+    let (buttons, featured, paused, pond_name) = {
+        let data = data_manager.guild(guild_id).await;
+        let mut buttons = Vec::new();
+        if let (Some(channel), Some(message)) = (&data.button_channel_id, &data.button_message_id) {
+            buttons.push((channel.clone(), message.clone(), None));
+        }
+        for (channel, biome) in &data.biome_channels {
+            if let Some(message) = &biome.button_message_id {
+                buttons.push((
+                    channel.clone(),
+                    message.clone(),
+                    crate::fish::find_location(&biome.location),
+                ));
+            }
+        }
+        (
+            buttons,
+            data.featured_species(),
+            data.paused_since.is_some(),
+            data.branding().pond_name,
+        )
+    };
+
+    for (channel, message, biome) in buttons {
+        let (Ok(channel), Ok(message)) = (channel.parse::<u64>(), message.parse::<u64>()) else {
+            continue;
+        };
+        if let Err(e) = serenity::ChannelId::new(channel)
+            .edit_message(
+                http,
+                serenity::MessageId::new(message),
+                serenity::EditMessage::new()
+                    .content(crate::ui::fish_button_content(&pond_name, biome, featured))
+                    .components(vec![crate::ui::fish_button_row(biome, paused)]),
+            )
+            .await
+        {
+            tracing::error!("❌ Error refreshing fish button in {}: {}", channel, e);
+        }
+    }
+}
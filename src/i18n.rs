@@ -0,0 +1,346 @@
+//! Player-facing text in each [`Locale`] a server can pick with `/setlocale`.
+//! Templates use `{name}` placeholders, filled with
+//! [`fill_template`](crate::data::fill_template).
+
+use crate::data::Locale;
+use crate::fish::Rarity;
+
+/// Every translated text, one table per locale
+pub struct Strings {
+    /// Catch embed titles, by rarity
+    rarity_titles: [&'static str; 5],
+    /// Rarity names, by rarity
+    rarity_labels: [&'static str; 5],
+    /// Catch flavor lines, by rarity; one is picked at random for each catch
+    flavors: [&'static [&'static str]; 5],
+
+    // Catch embed
+    pub shiny_title: &'static str,
+    pub birthday_title: &'static str,
+    pub shiny_name: &'static str,
+    pub crystal_name: &'static str,
+    pub catch_description: &'static str,
+    pub streak: &'static str,
+    pub streak_days: &'static str,
+    pub total_catches: &'static str,
+    pub catches_today: &'static str,
+    pub coins: &'static str,
+    pub coins_earned: &'static str,
+    pub lottery: &'static str,
+    pub lottery_ticket: &'static str,
+    pub lottery_tickets: &'static str,
+    pub team_points: &'static str,
+    pub team_points_earned: &'static str,
+    pub fish_of_the_day: &'static str,
+    pub fish_of_the_day_bonus: &'static str,
+    pub fish_of_the_day_team_bonus: &'static str,
+    pub birthday_catch: &'static str,
+    pub birthday_gift: &'static str,
+    pub birthday_item: &'static str,
+    pub referral_reward: &'static str,
+    pub referral_paid: &'static str,
+    pub streak_combo: &'static str,
+    pub streak_combo_text: &'static str,
+    pub booster_luck: &'static str,
+    pub booster_luck_text: &'static str,
+    pub booster_perk: &'static str,
+    pub booster_cast_text: &'static str,
+    pub stream_frenzy: &'static str,
+    pub stream_frenzy_text: &'static str,
+    pub bait_used: &'static str,
+    pub bait_used_text: &'static str,
+    pub new_title: &'static str,
+    pub new_title_hint: &'static str,
+    pub new_record: &'static str,
+    pub new_record_text: &'static str,
+    pub and: &'static str,
+
+    // Daily summary
+    pub summary_title: &'static str,
+    pub summary_footer: &'static str,
+    pub summary_catches: &'static str,
+    pub members_missed: &'static str,
+    pub catches_by_spot: &'static str,
+    pub best_anglers: &'static str,
+    pub best_angler_line: &'static str,
+    pub team_standings: &'static str,
+    pub team_standing_line: &'static str,
+    pub team_of_the_month: &'static str,
+    pub team_winner: &'static str,
+    pub lottery_winner: &'static str,
+    pub lottery_winner_text: &'static str,
+    pub hall_of_fame: &'static str,
+    pub message: &'static str,
+    pub and_others: &'static str,
+    pub everyone: &'static str,
+
+    // Weekly summary
+    pub weekly_title: &'static str,
+    pub weekly_description: &'static str,
+    pub weekly_footer: &'static str,
+    #[cfg(feature = "image-gen")]
+    pub weekly_card_title: &'static str,
+    #[cfg(not(feature = "image-gen"))]
+    pub most_catches: &'static str,
+    pub pond_health: &'static str,
+
+    // `/remindme` DMs
+    pub dm_reminder: &'static str,
+    pub dm_reminder_streak: &'static str,
+
+    // Snooze button under the summary's reminder
+    pub snooze_button: &'static str,
+    pub snoozed: &'static str,
+}
+
+impl Strings {
+    pub fn rarity_title(&self, rarity: Rarity) -> &'static str {
+        self.rarity_titles[rarity as usize]
+    }
+
+    pub fn rarity_label(&self, rarity: Rarity) -> &'static str {
+        self.rarity_labels[rarity as usize]
+    }
+
+    pub fn flavors(&self, rarity: Rarity) -> &'static [&'static str] {
+        self.flavors[rarity as usize]
+    }
+}
+
+/// The table for `locale`
+pub fn strings(locale: Locale) -> &'static Strings {
+    match locale {
+        Locale::En => &EN,
+        Locale::Es => &ES,
+    }
+}
+
+pub static EN: Strings = Strings {
+    rarity_titles: [
+        "🎣 Catch of the Day!",
+        "🎣 Nice Catch!",
+        "✨ Rare Catch!",
+        "💜 Epic Catch!",
+        "🌟 LEGENDARY CATCH! 🌟",
+    ],
+    rarity_labels: ["Common", "Uncommon", "Rare", "Epic", "Legendary"],
+    flavors: [
+        &[
+            "A solid catch for the bucket.",
+            "Every angler starts somewhere.",
+            "It wriggles happily in the net.",
+        ],
+        &[
+            "Not something you see every day!",
+            "The line tugged a little harder this time.",
+            "A few onlookers nod approvingly.",
+        ],
+        &[
+            "The water shimmers as it breaks the surface!",
+            "Word of this one will spread around the pond.",
+            "That's going straight in the album.",
+        ],
+        &[
+            "The rod bends nearly in half!",
+            "A crowd gathers to see what you've landed.",
+            "Even the ducks stop to stare.",
+        ],
+        &[
+            "The stars themselves seem to glow brighter!",
+            "Anglers will tell stories about this day for years.",
+            "The pond falls silent in awe.",
+        ],
+    ],
+
+    shiny_title: "🌈 SHINY! {title}",
+    birthday_title: "🎂 HAPPY BIRTHDAY! {title}",
+    shiny_name: "✨ Shiny {fish} ✨",
+    crystal_name: "Crystal {fish}",
+    catch_description:
+        "{name} cast their line at {location} and caught a {fish} ({size} cm, {rarity})!",
+    streak: "🔥 Streak",
+    streak_days: "{days} Days",
+    total_catches: "✨ Total Catches",
+    catches_today: "🌍 Total Catches Today",
+    coins: "🪙 Coins",
+    coins_earned: "+{earned} ({total} total)",
+    lottery: "🎟️ Lottery",
+    lottery_ticket: "{count} ticket for tonight's drawing",
+    lottery_tickets: "{count} tickets for tonight's drawing",
+    team_points: "🏁 Team Points",
+    team_points_earned: "+{points} for {team}",
+    fish_of_the_day: "🌟 Fish of the Day",
+    fish_of_the_day_bonus: "Today's featured fish! {multiplier}x coins.",
+    fish_of_the_day_team_bonus: "Today's featured fish! {multiplier}x coins and team points.",
+    birthday_catch: "🎂 Birthday Catch",
+    birthday_gift:
+        "🎉 Happy birthday, {name}! The pond sent a guaranteed shiny, plus a gift of {coins} 🪙{item}.",
+    birthday_item: " and a {item}",
+    referral_reward: "🤝 Referral Reward",
+    referral_paid:
+        "{name} reached a {streak} day streak! {referrer} earned **{coins}** 🪙 for inviting them.",
+    streak_combo: "⚡ Streak Combo",
+    streak_combo_text: "Day {streak} of the streak! Best of two rolls and {multiplier}x coins.",
+    booster_luck: "💎 Booster Luck",
+    booster_luck_text: "Thanks for boosting — the odds were in their favour!",
+    booster_perk: "💎 Booster Perk",
+    booster_cast_text: "Thanks for boosting — boosters get two casts a day!",
+    stream_frenzy: "📺 Stream Frenzy",
+    stream_frenzy_text: "The streamer is live — rare fish are biting!",
+    bait_used: "{emoji} Bait Used",
+    bait_used_text: "Their {bait} boosted the odds!",
+    new_title: "🏅 New Title Earned",
+    new_title_hint: "Show it off with `/title set`.",
+    new_record: "🏛️ New Record",
+    new_record_text: "{name} set a new **{records}** record! See `/halloffame`.",
+    and: "and",
+
+    summary_title: "🐠 Daily Guild Aquarium Contributions",
+    summary_footer: "{pond} Daily Summary",
+    summary_catches: "🎣 Total Catches Today",
+    members_missed: "😴 Members Missed",
+    catches_by_spot: "🗺️ Catches by Spot",
+    best_anglers: "🔥 Best Anglers ({streak}+ Day Streak)",
+    best_angler_line: "🏆 {name}: {catches} 🐟 ({streak} day streak)",
+    team_standings: "🏁 Team Standings",
+    team_standing_line: "**{team}**: +{today} today ({month} this month)",
+    team_of_the_month: "🏆 Team of the Month",
+    team_winner: "**{team}** wins {month} with **{points}** points! Scores reset tomorrow.",
+    lottery_winner: "🎟️ Lottery Winner",
+    lottery_winner_text: "{winner} won the **{jackpot}** 🪙 jackpot out of {tickets} tickets!",
+    hall_of_fame: "🏛️ Hall of Fame",
+    message: "Message",
+    and_others: "...and {count} others",
+    everyone: "everyone",
+
+    weekly_title: "🏆 Weekly Leaderboard",
+    weekly_description: "The pond's busiest anglers over the last seven days.",
+    weekly_footer: "{pond} Weekly Summary",
+    #[cfg(feature = "image-gen")]
+    weekly_card_title: "Top anglers this week",
+    #[cfg(not(feature = "image-gen"))]
+    most_catches: "🎣 Most Catches",
+    pond_health: "📈 Pond Health",
+
+    dm_reminder: "🎣 You haven't fished yet today! The pond is waiting for you.",
+    dm_reminder_streak:
+        "🎣 You haven't fished yet today! Your **{streak}** day streak is waiting for you.",
+
+    snooze_button: "😴 Snooze my reminders for {days} days",
+    snoozed: "😴 Got it! No reminders for you through **{date}**. Your catches still count whenever you fish.",
+};
+
+pub static ES: Strings = Strings {
+    rarity_titles: [
+        "🎣 ¡Captura del día!",
+        "🎣 ¡Buena captura!",
+        "✨ ¡Captura rara!",
+        "💜 ¡Captura épica!",
+        "🌟 ¡CAPTURA LEGENDARIA! 🌟",
+    ],
+    rarity_labels: ["Común", "Poco común", "Raro", "Épico", "Legendario"],
+    flavors: [
+        &[
+            "Una buena captura para el cubo.",
+            "Todo pescador empieza por algún sitio.",
+            "Se retuerce feliz en la red.",
+        ],
+        &[
+            "¡No es algo que se vea todos los días!",
+            "El sedal tiró un poco más fuerte esta vez.",
+            "Algunos curiosos asienten con aprobación.",
+        ],
+        &[
+            "¡El agua brilla cuando sale a la superficie!",
+            "Todo el estanque hablará de esta.",
+            "Esta va directa al álbum.",
+        ],
+        &[
+            "¡La caña casi se dobla por la mitad!",
+            "Se forma un corrillo para ver lo que has sacado.",
+            "Hasta los patos se paran a mirar.",
+        ],
+        &[
+            "¡Las propias estrellas parecen brillar más!",
+            "Los pescadores contarán historias de este día durante años.",
+            "El estanque enmudece de asombro.",
+        ],
+    ],
+
+    shiny_title: "🌈 ¡BRILLANTE! {title}",
+    birthday_title: "🎂 ¡FELIZ CUMPLEAÑOS! {title}",
+    shiny_name: "✨ {fish} brillante ✨",
+    crystal_name: "{fish} de cristal",
+    catch_description:
+        "¡{name} lanzó el sedal en {location} y pescó un {fish} ({size} cm, {rarity})!",
+    streak: "🔥 Racha",
+    streak_days: "{days} días",
+    total_catches: "✨ Capturas totales",
+    catches_today: "🌍 Capturas de hoy",
+    coins: "🪙 Monedas",
+    coins_earned: "+{earned} ({total} en total)",
+    lottery: "🎟️ Lotería",
+    lottery_ticket: "{count} boleto para el sorteo de esta noche",
+    lottery_tickets: "{count} boletos para el sorteo de esta noche",
+    team_points: "🏁 Puntos de equipo",
+    team_points_earned: "+{points} para {team}",
+    fish_of_the_day: "🌟 Pez del día",
+    fish_of_the_day_bonus: "¡El pez destacado de hoy! Monedas x{multiplier}.",
+    fish_of_the_day_team_bonus: "¡El pez destacado de hoy! Monedas y puntos de equipo x{multiplier}.",
+    birthday_catch: "🎂 Captura de cumpleaños",
+    birthday_gift:
+        "🎉 ¡Feliz cumpleaños, {name}! El estanque te envía un brillante garantizado y un regalo de {coins} 🪙{item}.",
+    birthday_item: " y un {item}",
+    referral_reward: "🤝 Recompensa por invitación",
+    referral_paid:
+        "¡{name} llegó a una racha de {streak} días! {referrer} gana **{coins}** 🪙 por invitarle.",
+    streak_combo: "⚡ Combo de racha",
+    streak_combo_text: "¡Día {streak} de la racha! La mejor de dos tiradas y monedas x{multiplier}.",
+    booster_luck: "💎 Suerte de booster",
+    booster_luck_text: "Gracias por el boost: ¡la suerte estuvo de su lado!",
+    booster_perk: "💎 Ventaja de booster",
+    booster_cast_text: "Gracias por el boost: ¡los boosters lanzan dos veces al día!",
+    stream_frenzy: "📺 Frenesí de directo",
+    stream_frenzy_text: "El streamer está en directo: ¡pican peces raros!",
+    bait_used: "{emoji} Cebo usado",
+    bait_used_text: "¡Su {bait} mejoró las probabilidades!",
+    new_title: "🏅 Nuevo título",
+    new_title_hint: "Lúcelo con `/title set`.",
+    new_record: "🏛️ Nuevo récord",
+    new_record_text: "¡{name} establece un nuevo récord de **{records}**! Mira `/halloffame`.",
+    and: "y",
+
+    summary_title: "🐠 Aportaciones diarias al acuario",
+    summary_footer: "Resumen diario de {pond}",
+    summary_catches: "🎣 Capturas de hoy",
+    members_missed: "😴 Miembros ausentes",
+    catches_by_spot: "🗺️ Capturas por lugar",
+    best_anglers: "🔥 Mejores pescadores (racha de {streak}+ días)",
+    best_angler_line: "🏆 {name}: {catches} 🐟 (racha de {streak} días)",
+    team_standings: "🏁 Clasificación de equipos",
+    team_standing_line: "**{team}**: +{today} hoy ({month} este mes)",
+    team_of_the_month: "🏆 Equipo del mes",
+    team_winner:
+        "¡**{team}** gana {month} con **{points}** puntos! Las puntuaciones se reinician mañana.",
+    lottery_winner: "🎟️ Ganador de la lotería",
+    lottery_winner_text: "¡{winner} ganó el bote de **{jackpot}** 🪙 entre {tickets} boletos!",
+    hall_of_fame: "🏛️ Salón de la fama",
+    message: "Mensaje",
+    and_others: "...y {count} más",
+    everyone: "todos",
+
+    weekly_title: "🏆 Clasificación semanal",
+    weekly_description: "Los pescadores más activos del estanque en los últimos siete días.",
+    weekly_footer: "Resumen semanal de {pond}",
+    #[cfg(feature = "image-gen")]
+    weekly_card_title: "Mejores pescadores de la semana",
+    #[cfg(not(feature = "image-gen"))]
+    most_catches: "🎣 Más capturas",
+    pond_health: "📈 Salud del estanque",
+
+    dm_reminder: "🎣 ¡Todavía no has pescado hoy! El estanque te espera.",
+    dm_reminder_streak: "🎣 ¡Todavía no has pescado hoy! Tu racha de **{streak}** días te espera.",
+
+    snooze_button: "😴 Silenciar mis recordatorios {days} días",
+    snoozed: "😴 ¡Entendido! No recibirás recordatorios hasta el **{date}**. Tus capturas siguen contando cuando pesques.",
+};
@@ -0,0 +1,89 @@
+use crate::data::{DataManager, PendingDeletion};
+use poise::serenity_prelude as serenity;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// How often the janitor looks for expired messages
+pub const SWEEP_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Deletes short-lived bot messages after a delay. Pending deletions are
+/// persisted so a restart doesn't leave them behind.
+pub struct Janitor {
+    data_manager: Arc<DataManager>,
+}
+
+impl Janitor {
+    pub fn new(data_manager: Arc<DataManager>) -> Self {
+        Self { data_manager }
+    }
+
+    pub async fn schedule_delete(
+        &self,
+        guild_id: &str,
+        channel_id: serenity::ChannelId,
+        message_id: serenity::MessageId,
+        after: Duration,
+    ) {
+        let delete_at = chrono::Utc::now().timestamp_millis() as u64 + after.as_millis() as u64;
+        {
+            let mut data = self.data_manager.guild_mut(guild_id).await;
+            data.pending_deletions.push(PendingDeletion {
+                channel_id: channel_id.to_string(),
+                message_id: message_id.to_string(),
+                delete_at,
+            });
+        }
+        self.data_manager.save().await;
+    }
+
+    /// Delete every message whose time is up
+    pub async fn sweep(&self, http: &serenity::Http) {
+        let now_millis = chrono::Utc::now().timestamp_millis() as u64;
+        let due: Vec<PendingDeletion> = {
+            let mut data = self.data_manager.data.write().await;
+            let mut due = Vec::new();
+            for guild in data.guilds.values_mut() {
+                if !guild
+                    .pending_deletions
+                    .iter()
+                    .any(|p| p.delete_at <= now_millis)
+                {
+                    continue;
+                }
+                let (expired, pending): (Vec<_>, Vec<_>) =
+                    std::mem::take(&mut guild.pending_deletions)
+                        .into_iter()
+                        .partition(|p| p.delete_at <= now_millis);
+                guild.pending_deletions = pending;
+                due.extend(expired);
+            }
+            if due.is_empty() {
+                return;
+            }
+            due
+        };
+        self.data_manager.save().await;
+
+        for pending in due {
+            if let (Ok(ch_id), Ok(msg_id)) = (
+                pending.channel_id.parse::<u64>(),
+                pending.message_id.parse::<u64>(),
+            ) {
+                let channel = serenity::ChannelId::new(ch_id);
+                // Already-deleted messages are fine to ignore
+                let _ = channel
+                    .delete_message(http, serenity::MessageId::new(msg_id))
+                    .await;
+            }
+        }
+    }
+
+    /// Sweep forever on a fixed interval
+    pub async fn run(self: Arc<Self>, http: Arc<serenity::Http>) {
+        let mut interval = tokio::time::interval(SWEEP_INTERVAL);
+        loop {
+            interval.tick().await;
+            self.sweep(&http).await;
+        }
+    }
+}
@@ -1,10 +1,44 @@
+#[cfg(feature = "minigames")]
+mod boat;
 mod commands;
-mod data;
+mod digest;
 mod events;
-mod game;
+mod i18n;
+mod janitor;
+#[cfg(feature = "economy")]
+mod merchant;
+#[cfg(feature = "metrics")]
+mod metrics;
+#[cfg(feature = "minigames")]
+mod pond_events;
+mod reminders;
+#[cfg(feature = "image-gen")]
+mod render;
+mod roles;
+mod summary;
+mod ui;
+mod usernames;
+mod verify;
+#[cfg(feature = "web")]
+mod webhook;
+#[cfg(feature = "minigames")]
+mod wild_fish;
 
+// The engine lives in its own crate; imported here so modules can keep using `crate::game` etc.
+#[cfg(any(feature = "economy", feature = "minigames"))]
+use nightmare_fishing_core::items;
+#[cfg(feature = "minigames")]
+use nightmare_fishing_core::wheel;
+use nightmare_fishing_core::{analytics, data, fish, game, titles};
+
+use chrono::{Datelike, Utc, Weekday};
 use data::DataManager;
 use game::FishingManager;
+use janitor::Janitor;
+#[cfg(feature = "metrics")]
+use metrics::Metrics;
+use nightmare_fishing_core::legacy::LegacyData;
+use nightmare_fishing_core::JsonFileStorage;
 use poise::serenity_prelude as serenity;
 use std::sync::Arc;
 use tokio_cron_scheduler::{Job, JobScheduler};
@@ -12,86 +46,488 @@ use tokio_cron_scheduler::{Job, JobScheduler};
 pub struct Data {
     pub data_manager: Arc<DataManager>,
     pub fishing_manager: Arc<FishingManager>,
+    pub janitor: Arc<Janitor>,
+    pub verifier: Arc<verify::Verifier>,
+    #[cfg(feature = "metrics")]
+    pub metrics: Arc<Metrics>,
+    /// When the bot came up, for `/status`
+    pub started_at: std::time::Instant,
 }
 
 pub type Error = Box<dyn std::error::Error + Send + Sync>;
 pub type Context<'a> = poise::Context<'a, Data, Error>;
 
+/// The live data store: the `DATABASE_URL` database when it is set and the
+/// `sqlite` or `postgres` feature is built in, otherwise `fishing_data.json`.
+/// With the `s3` feature, backups are also uploaded when `BACKUP_S3_*` is set.
+async fn open_live_data() -> DataManager {
+    #[cfg(feature = "s3")]
+    if let Some(remote) =
+        nightmare_fishing_core::s3::S3Backup::from_env().expect("invalid BACKUP_S3_ENDPOINT")
+    {
+        tracing::info!("☁️ Uploading backups to object storage");
+        #[cfg(feature = "encryption")]
+        let remote = match encryption_key() {
+            Some(key) => remote.with_encryption(key),
+            None => remote,
+        };
+        return open_storage().await.with_remote_backup(remote);
+    }
+    open_storage().await
+}
+
+/// Backup directory and how many backups to keep, from `BACKUP_DIR` and
+/// `BACKUP_KEEP` (default `backups` and 5)
+fn backup_settings() -> (String, usize) {
+    let dir = std::env::var("BACKUP_DIR").unwrap_or_else(|_| "backups".to_string());
+    let keep = match std::env::var("BACKUP_KEEP") {
+        Ok(keep) => keep.parse().expect("BACKUP_KEEP must be a number"),
+        Err(_) => nightmare_fishing_core::storage::DEFAULT_KEEP_BACKUPS,
+    };
+    (dir, keep)
+}
+
+async fn open_storage() -> DataManager {
+    let (backup_dir, keep_backups) = backup_settings();
+
+    #[cfg(any(feature = "sqlite", feature = "postgres"))]
+    if let Ok(url) = std::env::var("DATABASE_URL") {
+        let storage = nightmare_fishing_core::SqlStorage::connect(&url)
+            .await
+            .expect("could not open the DATABASE_URL database")
+            .with_backups(backup_dir, keep_backups);
+        tracing::info!(
+            "🗄️ Storing data in {}",
+            url.split(':').next().unwrap_or("SQL")
+        );
+        return DataManager::open(storage)
+            .await
+            .with_journal(journal_path());
+    }
+    let mut storage =
+        JsonFileStorage::new("fishing_data.json").with_backups(backup_dir, keep_backups);
+    if std::env::var("BACKUP_DIFFS").is_ok_and(|v| v == "true" || v == "1") {
+        tracing::info!("🧩 Logging every save as an incremental backup");
+        storage = storage.with_diffs();
+    }
+    if std::env::var("DATA_FORMAT").is_ok_and(|v| v.eq_ignore_ascii_case("msgpack")) {
+        #[cfg(feature = "msgpack")]
+        {
+            tracing::info!("📦 Saving data as MessagePack");
+            storage = storage.with_messagepack();
+        }
+        #[cfg(not(feature = "msgpack"))]
+        tracing::warn!("⚠️ DATA_FORMAT=msgpack needs the msgpack feature; saving JSON");
+    }
+    DataManager::open(encrypted(storage))
+        .await
+        .with_journal(journal_path())
+}
+
+/// The key from `DATA_ENCRYPTION_KEY`, if set
+#[cfg(feature = "encryption")]
+fn encryption_key() -> Option<nightmare_fishing_core::crypt::DataKey> {
+    nightmare_fishing_core::crypt::DataKey::from_env().expect("invalid DATA_ENCRYPTION_KEY")
+}
+
+/// Encrypt `storage` at rest when the `encryption` feature is built in and
+/// `DATA_ENCRYPTION_KEY` is set
+fn encrypted(storage: JsonFileStorage) -> JsonFileStorage {
+    #[cfg(feature = "encryption")]
+    if let Some(key) = encryption_key() {
+        tracing::info!("🔒 Encrypting data at rest");
+        return storage.with_encryption(key);
+    }
+    storage
+}
+
+/// The game engine, answering hot lookups from Redis when the `redis`
+/// feature is built in and `REDIS_URL` is set
+async fn open_fishing_manager(data_manager: Arc<DataManager>) -> FishingManager {
+    let fishing_manager = FishingManager::new(data_manager);
+    #[cfg(feature = "redis")]
+    if let Ok(url) = std::env::var("REDIS_URL") {
+        let prefix = std::env::var("REDIS_PREFIX").unwrap_or_else(|_| "pond:".to_string());
+        match nightmare_fishing_core::cache::RedisCache::connect(&url, prefix).await {
+            Ok(cache) => {
+                tracing::info!("⚡ Caching hot lookups in Redis");
+                return fishing_manager.with_cache(cache);
+            }
+            Err(e) => tracing::error!("❌ Could not connect to Redis, running without it: {}", e),
+        }
+    }
+    fishing_manager
+}
+
+/// Where catches, resets, and settings changes are journaled (`JOURNAL_PATH`)
+fn journal_path() -> String {
+    std::env::var("JOURNAL_PATH").unwrap_or_else(|_| "fishing_journal.jsonl".to_string())
+}
+
+/// Rebuild a data file from the journal, for when `fishing_data.json` is lost
+async fn replay_journal(journal: &str, out: &str) {
+    let (data, skipped) = nightmare_fishing_core::journal::replay(journal)
+        .await
+        .expect("could not read the journal");
+    let json = serde_json::to_string_pretty(&data).expect("state serializes");
+    std::fs::write(out, json).expect("could not write the replayed data");
+    tracing::info!(
+        "📜 Replayed {} into {} ({} unreadable lines skipped)",
+        journal,
+        out,
+        skipped
+    );
+}
+
+/// Merge a save file from the original Node.js bot into the live data.
+/// The server comes from the file's `guildId` unless one is given.
+async fn import_legacy(path: &str, guild_id: Option<String>) {
+    let json = std::fs::read_to_string(path).expect("could not read the legacy data file");
+    let legacy = LegacyData::parse(&json).expect("not a fishing_data.json from the Node.js bot");
+    let guild_id = guild_id
+        .or_else(|| legacy.guild_id.clone())
+        .expect("the file has no guildId; pass the server ID after the path");
+
+    let data_manager = open_live_data().await;
+    // Creates the server first so it adopts any unassigned older data
+    drop(data_manager.guild(&guild_id).await);
+    let result = data_manager
+        .update(|data| {
+            let guild = data.guilds.get_mut(&guild_id).expect("created above");
+            legacy.merge_into(guild)
+        })
+        .await;
+    match result {
+        Ok(summary) => tracing::info!(
+            "📥 Imported {} new and {} existing players into server {}",
+            summary.added,
+            summary.merged,
+            guild_id
+        ),
+        Err(e) => tracing::error!("❌ Error saving the import: {}", e),
+    }
+}
+
+/// Rebuild the qualified names of `command` and its subcommands under
+/// `parent`, after a rename
+fn qualify(command: &mut poise::Command<Data, Error>, parent: Option<&str>) {
+    command.qualified_name = match parent {
+        Some(parent) => format!("{} {}", parent, command.name),
+        None => command.name.clone(),
+    };
+    let qualified_name = command.qualified_name.clone();
+    for subcommand in &mut command.subcommands {
+        qualify(subcommand, Some(&qualified_name));
+    }
+}
+
+/// Wait for Ctrl+C, or SIGTERM on Unix
+async fn shutdown_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+        let mut terminate = signal(SignalKind::terminate()).expect("Failed to listen for SIGTERM");
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => {}
+            _ = terminate.recv() => {}
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = tokio::signal::ctrl_c().await;
+}
+
 #[tokio::main]
 async fn main() {
     dotenvy::dotenv().ok();
     tracing_subscriber::fmt::init();
 
-    let data_manager = Arc::new(DataManager::new());
-    let fishing_manager = Arc::new(FishingManager::new(data_manager.clone()));
+    // One-shot maintenance tools that run and exit
+    let mut args = std::env::args().skip(1);
+    match args.next().as_deref() {
+        // `--import-legacy <file> [guild_id]` imports old data
+        Some("--import-legacy") => {
+            let path = args
+                .next()
+                .expect("usage: --import-legacy <fishing_data.json> [guild_id]");
+            import_legacy(&path, args.next()).await;
+            return;
+        }
+        // `--replay-journal [journal] [out]` rebuilds a data file from the journal
+        Some("--replay-journal") => {
+            let journal = args.next().unwrap_or_else(journal_path);
+            let out = args
+                .next()
+                .unwrap_or_else(|| "fishing_data.replayed.json".to_string());
+            replay_journal(&journal, &out).await;
+            return;
+        }
+        _ => {}
+    }
+
+    // Staging runs a second bot against a read-only copy of the live data
+    let staging = std::env::var("BOT_MODE").is_ok_and(|mode| mode.eq_ignore_ascii_case("staging"));
+    let data_manager = if staging {
+        let snapshot =
+            std::env::var("STAGING_DATA_PATH").unwrap_or_else(|_| "fishing_data.json".to_string());
+        tracing::warn!("🧪 Staging mode: loading {} read-only", snapshot);
+        Arc::new(DataManager::open_read_only(encrypted(JsonFileStorage::new(snapshot))).await)
+    } else {
+        Arc::new(open_live_data().await)
+    };
+    let fishing_manager = Arc::new(open_fishing_manager(data_manager.clone()).await);
+    let janitor = Arc::new(Janitor::new(data_manager.clone()));
+    #[cfg(feature = "metrics")]
+    let metrics = Arc::new(Metrics::new());
 
-    let token = std::env::var("DISCORD_BOT_TOKEN").expect("missing DISCORD_BOT_TOKEN");
+    let token = if staging {
+        std::env::var("DISCORD_STAGING_BOT_TOKEN").expect("missing DISCORD_STAGING_BOT_TOKEN")
+    } else {
+        std::env::var("DISCORD_BOT_TOKEN").expect("missing DISCORD_BOT_TOKEN")
+    };
     let intents = serenity::GatewayIntents::GUILDS
         | serenity::GatewayIntents::GUILD_MESSAGES
         | serenity::GatewayIntents::GUILD_MEMBERS;
 
-    // Schedule Daily Reset (runs at 14:30 GMT / 8:00 PM IST)
+    // Schedule Daily Resets (14:30 GMT / 8:00 PM IST unless a server sets its own)
     let sched = JobScheduler::new().await.unwrap();
     let fishing_manager_clone = fishing_manager.clone();
     let data_manager_clone = data_manager.clone();
     let token_clone = token.clone();
     let http = Arc::new(serenity::Http::new(&token_clone));
+    #[cfg(feature = "web")]
+    let webhook_http = http.clone();
+    tokio::spawn(janitor.clone().run(http.clone()));
+    tokio::spawn(data_manager.clone().run_saver());
+    let shutdown_data = data_manager.clone();
 
-    sched
-        .add(
-            Job::new_async("0 30 14 * * *", move |_uuid, _l| {
-                let fishing_manager = fishing_manager_clone.clone();
-                let data_manager = data_manager_clone.clone();
-                let http = http.clone();
-                Box::pin(async move {
-                    // 1. Post final summary for the day
-                    fishing_manager.post_daily_summary_http(&http).await;
-                    // 2. Backup data before wipe
-                    data_manager.backup().await;
-                    // 3. Reset for next day
-                    fishing_manager.reset_daily_data_http(&http).await;
-                })
+    // Scheduled jobs and background events only run on the live bot
+    if !staging {
+        #[cfg(feature = "minigames")]
+        tokio::spawn(pond_events::run(fishing_manager.clone(), http.clone()));
+        #[cfg(feature = "minigames")]
+        tokio::spawn(boat::run(fishing_manager.clone(), http.clone()));
+        #[cfg(feature = "minigames")]
+        tokio::spawn(wild_fish::run(fishing_manager.clone(), http.clone()));
+        #[cfg(feature = "economy")]
+        tokio::spawn(merchant::run(fishing_manager.clone(), http.clone()));
+        tokio::spawn(reminders::run(fishing_manager.clone(), http.clone()));
+
+        if let Some(recovery) = data_manager.recovery().cloned() {
+            let data_manager = data_manager.clone();
+            let http = http.clone();
+            tokio::spawn(async move {
+                summary::post_recovery_notice(&data_manager, &http, &recovery).await;
+            });
+        }
+
+        // History older than this moves to compressed archives after the reset
+        let archive_months: u32 = std::env::var("ARCHIVE_AFTER_MONTHS")
+            .map(|months| {
+                months
+                    .parse()
+                    .expect("ARCHIVE_AFTER_MONTHS must be a number")
             })
-            .unwrap(),
-        )
-        .await
-        .unwrap();
+            .unwrap_or(6);
+        let archive_dir = std::path::PathBuf::from(
+            std::env::var("ARCHIVE_DIR").unwrap_or_else(|_| "archive".to_string()),
+        );
+
+        let summary_http = http.clone();
+
+        // Checked every minute, so `/setresettime` takes effect without a restart
+        sched
+            .add(
+                Job::new_async("0 * * * * *", move |_uuid, _l| {
+                    let fishing_manager = fishing_manager_clone.clone();
+                    let data_manager = data_manager_clone.clone();
+                    let http = http.clone();
+                    let archive_dir = archive_dir.clone();
+                    Box::pin(async move {
+                        let due = fishing_manager.guilds_due_for_reset(Utc::now()).await;
+                        if due.is_empty() {
+                            return;
+                        }
+                        let mut digests = Vec::new();
+                        for guild_id in &due {
+                            // 0. Draw the lottery and close the team month for the summary
+                            #[cfg(feature = "economy")]
+                            fishing_manager.draw_lottery(guild_id).await;
+                            #[cfg(feature = "minigames")]
+                            fishing_manager.finish_team_month_if_due(guild_id).await;
+                            // 1. Catch up on renames, then post final summary for the day
+                            // unless the server posts it at its own time
+                            usernames::refresh_usernames(&fishing_manager, &http, guild_id).await;
+                            if data_manager.guild(guild_id).await.summary_time.is_none() {
+                                summary::post_daily_summary(&fishing_manager, &http, guild_id)
+                                    .await;
+                            }
+                            let tz = fishing_manager.guild_timezone(guild_id).await;
+                            if Utc::now().with_timezone(&tz).weekday() == Weekday::Sun {
+                                summary::post_weekly_summary(&fishing_manager, &http, guild_id)
+                                    .await;
+                            }
+                            // Gather the admin digest while the day is still there
+                            digests
+                                .extend(digest::prepare(&fishing_manager, &http, guild_id).await);
+                            // Stop reminding long-gone members, if the server asked to
+                            if data_manager.guild(guild_id).await.auto_untrack_inactive {
+                                roles::untrack_inactive(&fishing_manager, &http, guild_id).await;
+                            }
+                        }
+                        // 2. Backup data before wipe, keeping the outcome for the digests
+                        let backup = data_manager.backup_now().await;
+                        // 3. Reset these servers for the next day
+                        let broken = fishing_manager.reset_daily_data(&due).await;
+                        // 4. Take back streak rewards from broken streaks
+                        roles::revoke_streak_roles(&http, &broken).await;
+                        // 5. Show the new Fish of the Day on the buttons
+                        for guild_id in &due {
+                            events::refresh_fish_buttons(&http, &data_manager, guild_id).await;
+                        }
+                        // 6. DM the digest to admins who opted in
+                        for prepared in &digests {
+                            digest::send(&http, prepared, &backup).await;
+                        }
+                        // 7. Move old history out of the live data
+                        let cutoff = Utc::now()
+                            .checked_sub_months(chrono::Months::new(archive_months))
+                            .map_or(0, |at| at.timestamp_millis() as u64);
+                        match data_manager.compact(cutoff, &archive_dir).await {
+                            Ok(compaction) => {
+                                if let Some(file) = compaction.file {
+                                    tracing::info!(
+                                        "🗜️ Archived {} catches and {} days to {}",
+                                        compaction.catches,
+                                        compaction.days,
+                                        file.display()
+                                    );
+                                }
+                            }
+                            Err(e) => tracing::error!("❌ Error archiving old history: {}", e),
+                        }
+                    })
+                })
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        // Daily summaries of servers that post them apart from the reset
+        let summary_manager = fishing_manager.clone();
+        sched
+            .add(
+                Job::new_async("0 * * * * *", move |_uuid, _l| {
+                    let fishing_manager = summary_manager.clone();
+                    let http = summary_http.clone();
+                    Box::pin(async move {
+                        for guild_id in fishing_manager.guilds_due_for_summary(Utc::now()).await {
+                            usernames::refresh_usernames(&fishing_manager, &http, &guild_id).await;
+                            summary::post_daily_summary(&fishing_manager, &http, &guild_id).await;
+                        }
+                    })
+                })
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        // Extra backups between resets, e.g. `0 0 * * * *` for hourly
+        if let Ok(schedule) = std::env::var("BACKUP_CRON") {
+            let data_manager = data_manager.clone();
+            sched
+                .add(
+                    Job::new_async(schedule.as_str(), move |_uuid, _l| {
+                        let data_manager = data_manager.clone();
+                        Box::pin(async move { data_manager.backup().await })
+                    })
+                    .expect("invalid BACKUP_CRON schedule"),
+                )
+                .await
+                .unwrap();
+        }
+
+        sched.start().await.unwrap();
+
+        // Optional go-live listener for stream frenzies
+        #[cfg(feature = "web")]
+        if let (Ok(addr), Ok(secret)) = (
+            std::env::var("STREAM_WEBHOOK_ADDR"),
+            std::env::var("STREAM_WEBHOOK_SECRET"),
+        ) {
+            tokio::spawn(webhook::serve(
+                addr,
+                secret,
+                fishing_manager.clone(),
+                webhook_http,
+            ));
+        }
+    }
 
-    sched.start().await.unwrap();
+    let mut commands = commands::all();
+    if staging {
+        for command in &mut commands {
+            command.name = format!("{}-beta", command.name);
+            qualify(command, None);
+        }
+    }
 
     let framework = poise::Framework::builder()
         .options(poise::FrameworkOptions {
-            commands: vec![
-                commands::fishing::fish(),
-                commands::fishing::summary(),
-                commands::admin::fishsetup(),
-                commands::admin::fishsummary(),
-                commands::admin::setbestanglerstreak(),
-                commands::admin::setreminderthreshold(),
-                commands::admin::setrole(),
-                commands::admin::setsummarychannel(),
-                commands::admin::togglereminder(),
-            ],
+            commands,
             event_handler: |ctx, event, _framework, data| {
                 Box::pin(async move {
                     if let serenity::FullEvent::InteractionCreate {
                         interaction: serenity::Interaction::Component(component),
                     } = event
                     {
-                        if let Err(e) = events::handle_button_interaction(
-                            ctx,
-                            component,
-                            &data.data_manager,
-                            &data.fishing_manager,
-                        )
-                        .await
+                        let result =
+                            events::handle_component_interaction(ctx, component, data).await;
+                        #[cfg(feature = "metrics")]
                         {
-                            tracing::error!("Error handling button interaction: {:?}", e);
+                            let name = component
+                                .data
+                                .custom_id
+                                .split(':')
+                                .next()
+                                .unwrap_or_default();
+                            data.metrics.record(
+                                name,
+                                component.id.created_at(),
+                                result.as_ref().err().map(|e| e.as_ref()),
+                            );
+                        }
+                        if let Err(e) = result {
+                            tracing::error!("Error handling component interaction: {:?}", e);
                         }
                     }
                     Ok(())
                 })
             },
+            #[cfg(feature = "metrics")]
+            post_command: |ctx| {
+                Box::pin(async move {
+                    ctx.data().metrics.record(
+                        &ctx.command().qualified_name,
+                        ctx.created_at(),
+                        None,
+                    );
+                })
+            },
+            on_error: |error| {
+                Box::pin(async move {
+                    #[cfg(feature = "metrics")]
+                    if let poise::FrameworkError::Command { error, ctx, .. } = &error {
+                        ctx.data().metrics.record(
+                            &ctx.command().qualified_name,
+                            ctx.created_at(),
+                            Some(error.as_ref()),
+                        );
+                    }
+                    if let Err(e) = poise::builtins::on_error(error).await {
+                        tracing::error!("Error while handling error: {}", e);
+                    }
+                })
+            },
             ..Default::default()
         })
         .setup(|ctx, _ready, framework| {
@@ -100,6 +536,11 @@ async fn main() {
                 Ok(Data {
                     data_manager,
                     fishing_manager,
+                    janitor,
+                    verifier: Arc::new(verify::Verifier::new()),
+                    #[cfg(feature = "metrics")]
+                    metrics,
+                    started_at: std::time::Instant::now(),
                 })
             })
         })
@@ -109,5 +550,17 @@ async fn main() {
         .framework(framework)
         .await;
 
-    client.unwrap().start().await.unwrap();
+    let mut client = client.unwrap();
+
+    // Stop cleanly on Ctrl+C or SIGTERM (what Docker and systemd send) so
+    // batched saves aren't lost
+    let shard_manager = client.shard_manager.clone();
+    tokio::spawn(async move {
+        shutdown_signal().await;
+        tracing::info!("🛑 Shutting down...");
+        shard_manager.shutdown_all().await;
+    });
+
+    client.start().await.unwrap();
+    shutdown_data.flush().await;
 }
@@ -0,0 +1,146 @@
+use crate::data::{MerchantOffer, MerchantVisit};
+use crate::game::FishingManager;
+use crate::items::ITEMS;
+use poise::serenity_prelude as serenity;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// How often the merchant task checks in
+const CHECK_INTERVAL: Duration = Duration::from_secs(30 * 60);
+/// Chance per check that the merchant shows up (about three visits a week)
+const VISIT_CHANCE: f64 = 0.009;
+/// How long the merchant stays
+const VISIT_HOURS: u64 = 6;
+/// Distinct items offered per visit
+const OFFERS_PER_VISIT: usize = 3;
+
+fn roll_offers() -> Vec<MerchantOffer> {
+    let mut rng = rand::thread_rng();
+    ITEMS
+        .choose_multiple(&mut rng, OFFERS_PER_VISIT)
+        .map(|item| MerchantOffer {
+            item: item.id.to_string(),
+            price: rng.gen_range(4..=30) * 10,
+            stock: rng.gen_range(2..=6),
+        })
+        .collect()
+}
+
+/// Embed and buy buttons reflecting the current stock
+pub fn merchant_message(
+    visit: &MerchantVisit,
+    pond_name: &str,
+) -> (serenity::CreateEmbed, Vec<serenity::CreateActionRow>) {
+    let mut embed = serenity::CreateEmbed::new()
+        .color(0x8B4513)
+        .title("🧳 The Travelling Merchant has arrived!")
+        .description(format!(
+            "Exotic wares for sale until <t:{}:t>. Stock is limited — first come, first served!",
+            visit.leaves_at / 1000
+        ))
+        .footer(serenity::CreateEmbedFooter::new(pond_name));
+
+    let mut buttons = Vec::new();
+    for offer in &visit.offers {
+        let item = match crate::items::find_item(&offer.item) {
+            Some(item) => item,
+            None => continue,
+        };
+        embed = embed.field(
+            format!("{} {} — {} 🪙", item.emoji, item.name, offer.price),
+            format!("{}\n*{} left*", item.description, offer.stock),
+            false,
+        );
+        buttons.push(
+            serenity::CreateButton::new(format!("merchant_buy:{}", item.id))
+                .label(format!("Buy {} ({} 🪙)", item.name, offer.price))
+                .style(serenity::ButtonStyle::Success)
+                .disabled(offer.stock == 0),
+        );
+    }
+
+    (embed, vec![serenity::CreateActionRow::Buttons(buttons)])
+}
+
+fn parse_ids(visit: &MerchantVisit) -> Option<(serenity::ChannelId, serenity::MessageId)> {
+    let channel = visit.channel_id.parse::<u64>().ok()?;
+    let message = visit.message_id.as_ref()?.parse::<u64>().ok()?;
+    Some((
+        serenity::ChannelId::new(channel),
+        serenity::MessageId::new(message),
+    ))
+}
+
+/// Spawn, post, and retire merchant visits on a fixed interval
+pub async fn run(fishing_manager: Arc<FishingManager>, http: Arc<serenity::Http>) {
+    let mut interval = tokio::time::interval(CHECK_INTERVAL);
+    loop {
+        interval.tick().await;
+        for guild_id in fishing_manager.guild_ids().await {
+            check(&fishing_manager, &http, &guild_id).await;
+        }
+    }
+}
+
+/// Retire one server's finished visit, or post a new one
+async fn check(fishing_manager: &FishingManager, http: &serenity::Http, guild_id: &str) {
+    if let Some(visit) = fishing_manager.end_merchant_visit(guild_id).await {
+        if let Some((channel, message)) = parse_ids(&visit) {
+            let edit = serenity::EditMessage::new()
+                .embed(
+                    serenity::CreateEmbed::new()
+                        .color(0x808080)
+                        .title("🧳 The Travelling Merchant has moved on")
+                        .description("Keep an eye out — they'll be back later this week."),
+                )
+                .components(vec![]);
+            let _ = channel.edit_message(http, message, edit).await;
+        }
+    }
+
+    let visit = match fishing_manager.merchant_visit(guild_id).await {
+        // Already posted; nothing to do until it leaves
+        Some(visit) if visit.message_id.is_some() => return,
+        // Started but never posted (e.g. restart mid-spawn)
+        Some(visit) => visit,
+        None => {
+            if !rand::thread_rng().gen_bool(VISIT_CHANCE) {
+                return;
+            }
+            let leaves_at =
+                chrono::Utc::now().timestamp_millis() as u64 + VISIT_HOURS * 60 * 60 * 1000;
+            match fishing_manager
+                .begin_merchant_visit(guild_id, roll_offers(), leaves_at)
+                .await
+            {
+                Some(visit) => visit,
+                None => return,
+            }
+        }
+    };
+
+    let channel = match visit.channel_id.parse::<u64>() {
+        Ok(id) => serenity::ChannelId::new(id),
+        Err(_) => return,
+    };
+    let branding = fishing_manager.branding(guild_id).await;
+    let (embed, components) = merchant_message(&visit, &branding.pond_name);
+    match channel
+        .send_message(
+            http,
+            serenity::CreateMessage::new()
+                .embed(embed)
+                .components(components),
+        )
+        .await
+    {
+        Ok(msg) => {
+            fishing_manager
+                .set_merchant_message(guild_id, msg.id.to_string())
+                .await
+        }
+        Err(e) => tracing::error!("❌ Error posting merchant visit: {}", e),
+    }
+}
@@ -0,0 +1,134 @@
+use poise::serenity_prelude as serenity;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+/// Samples older than this fall out of the rolling window
+const WINDOW_MILLIS: i64 = 60 * 60 * 1000;
+/// Hard cap so a busy pond can't grow the window without bound
+const MAX_SAMPLES: usize = 5000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailureSource {
+    /// Discord rejected or failed an API call
+    Discord,
+    /// Our own code returned an error
+    Bot,
+}
+
+#[derive(Debug, Clone)]
+pub struct Failure {
+    pub at_millis: i64,
+    pub name: String,
+    pub source: FailureSource,
+    pub message: String,
+}
+
+struct Sample {
+    at_millis: i64,
+    latency_ms: u64,
+    failure: Option<Failure>,
+}
+
+/// Latency percentiles and failures over the rolling window
+#[derive(Debug, Clone)]
+pub struct PerfSummary {
+    pub count: usize,
+    pub p50_ms: u64,
+    pub p95_ms: u64,
+    pub max_ms: u64,
+    pub discord_failures: usize,
+    pub bot_failures: usize,
+    pub recent_failures: Vec<Failure>,
+}
+
+/// Rolling record of interaction handling latency and failures
+pub struct Metrics {
+    samples: Mutex<VecDeque<Sample>>,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        Self {
+            samples: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Record a handled interaction. Latency runs from the interaction's
+    /// creation on Discord's side until now, so it includes gateway delay.
+    pub fn record(
+        &self,
+        name: &str,
+        created_at: serenity::Timestamp,
+        error: Option<&(dyn std::error::Error + Send + Sync + 'static)>,
+    ) {
+        let now_millis = chrono::Utc::now().timestamp_millis();
+        let latency_ms = (now_millis - created_at.timestamp_millis()).max(0) as u64;
+
+        let failure = error.map(|e| Failure {
+            at_millis: now_millis,
+            name: name.to_string(),
+            source: if e.downcast_ref::<serenity::Error>().is_some() {
+                FailureSource::Discord
+            } else {
+                FailureSource::Bot
+            },
+            message: e.to_string(),
+        });
+
+        let mut samples = self.samples.lock().expect("metrics lock poisoned");
+        samples.push_back(Sample {
+            at_millis: now_millis,
+            latency_ms,
+            failure,
+        });
+        Self::prune(&mut samples, now_millis);
+    }
+
+    fn prune(samples: &mut VecDeque<Sample>, now_millis: i64) {
+        while samples.len() > MAX_SAMPLES
+            || samples
+                .front()
+                .is_some_and(|s| now_millis - s.at_millis > WINDOW_MILLIS)
+        {
+            samples.pop_front();
+        }
+    }
+
+    pub fn summary(&self) -> PerfSummary {
+        let mut samples = self.samples.lock().expect("metrics lock poisoned");
+        Self::prune(&mut samples, chrono::Utc::now().timestamp_millis());
+
+        let mut latencies: Vec<u64> = samples.iter().map(|s| s.latency_ms).collect();
+        latencies.sort_unstable();
+        let percentile = |p: f64| -> u64 {
+            if latencies.is_empty() {
+                return 0;
+            }
+            let idx = ((latencies.len() as f64 - 1.0) * p).round() as usize;
+            latencies[idx]
+        };
+
+        let failures: Vec<&Failure> = samples.iter().filter_map(|s| s.failure.as_ref()).collect();
+
+        PerfSummary {
+            count: latencies.len(),
+            p50_ms: percentile(0.50),
+            p95_ms: percentile(0.95),
+            max_ms: latencies.last().copied().unwrap_or(0),
+            discord_failures: failures
+                .iter()
+                .filter(|f| f.source == FailureSource::Discord)
+                .count(),
+            bot_failures: failures
+                .iter()
+                .filter(|f| f.source == FailureSource::Bot)
+                .count(),
+            recent_failures: failures
+                .iter()
+                .rev()
+                .take(5)
+                .map(|f| (*f).clone())
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use crate::fish::PondEventKind;
+use crate::game::FishingManager;
+use poise::serenity_prelude as serenity;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// How often the pond checks for expired events and rolls for a new one
+const ROLL_INTERVAL: Duration = Duration::from_secs(15 * 60);
+/// Chance per roll that an event starts (roughly two a day)
+const EVENT_CHANCE: f64 = 0.02;
+
+/// Roll for events on a fixed interval and announce them in the pond channel
+pub async fn run(fishing_manager: Arc<FishingManager>, http: Arc<serenity::Http>) {
+    let mut interval = tokio::time::interval(ROLL_INTERVAL);
+    loop {
+        interval.tick().await;
+        for guild_id in fishing_manager.guild_ids().await {
+            roll(&fishing_manager, &http, &guild_id).await;
+        }
+    }
+}
+
+/// End one server's finished event and maybe start a new one
+async fn roll(fishing_manager: &FishingManager, http: &serenity::Http, guild_id: &str) {
+    let pond_name = fishing_manager.branding(guild_id).await.pond_name;
+    if let Some((ended, channel)) = fishing_manager.expire_pond_event(guild_id).await {
+        announce(
+            http,
+            channel,
+            &pond_name,
+            ended.name(),
+            ended.ended_message(),
+            0x808080,
+        )
+        .await;
+    }
+
+    let (starts, kind, minutes) = {
+        let mut rng = rand::thread_rng();
+        (
+            rng.gen_bool(EVENT_CHANCE),
+            *PondEventKind::ALL.choose(&mut rng).expect("non-empty"),
+            rng.gen_range(45..=90u64),
+        )
+    };
+    if !starts {
+        return;
+    }
+
+    if let Some(channel) = fishing_manager
+        .start_pond_event(guild_id, kind, minutes)
+        .await
+    {
+        let text = format!("{} (for the next {} minutes)", kind.announcement(), minutes);
+        announce(http, channel, &pond_name, kind.name(), &text, 0x00B3B3).await;
+    }
+}
+
+async fn announce(
+    http: &serenity::Http,
+    channel: Option<String>,
+    pond_name: &str,
+    title: &str,
+    description: &str,
+    color: u32,
+) {
+    let channel_id = match channel.and_then(|id| id.parse::<u64>().ok()) {
+        Some(id) => serenity::ChannelId::new(id),
+        None => return,
+    };
+
+    let embed = serenity::CreateEmbed::new()
+        .color(color)
+        .title(title)
+        .description(description)
+        .footer(serenity::CreateEmbedFooter::new(pond_name));
+    if let Err(e) = channel_id
+        .send_message(http, serenity::CreateMessage::new().embed(embed))
+        .await
+    {
+        tracing::error!("❌ Error announcing pond event: {}", e);
+    }
+}
@@ -0,0 +1,53 @@
+use crate::game::FishingManager;
+use poise::serenity_prelude as serenity;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Longest the loop sleeps, so newly set reminders are picked up quickly
+const MAX_WAIT: Duration = Duration::from_secs(60);
+
+/// Sleep until the next `/remindme` time, DM whoever is due, and repeat
+pub async fn run(fishing_manager: Arc<FishingManager>, http: Arc<serenity::Http>) {
+    loop {
+        let now = chrono::Utc::now();
+        for reminder in fishing_manager.due_dm_reminders(now).await {
+            send(&http, &reminder).await;
+        }
+
+        let wait = match fishing_manager.next_dm_reminder(chrono::Utc::now()).await {
+            Some(next) => (next - chrono::Utc::now())
+                .to_std()
+                .unwrap_or_default()
+                .min(MAX_WAIT),
+            None => MAX_WAIT,
+        };
+        tokio::time::sleep(wait).await;
+    }
+}
+
+async fn send(http: &serenity::Http, reminder: &crate::game::DueReminder) {
+    let Ok(user_id) = reminder.user_id.parse::<u64>() else {
+        return;
+    };
+    let text = crate::i18n::strings(reminder.locale);
+    let content = if reminder.streak > 0 {
+        crate::data::fill_template(
+            text.dm_reminder_streak,
+            &[("streak", &reminder.streak.to_string())],
+        )
+    } else {
+        text.dm_reminder.to_string()
+    };
+    if let Err(e) = serenity::UserId::new(user_id)
+        .direct_message(http, serenity::CreateMessage::new().content(content))
+        .await
+    {
+        // Usually DMs switched off; nothing to do but note it
+        tracing::warn!(
+            "⚠️ Could not DM a reminder to {} in {}: {}",
+            reminder.user_id,
+            reminder.guild_id,
+            e
+        );
+    }
+}
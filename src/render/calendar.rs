@@ -0,0 +1,106 @@
+use super::{canvas, draw_text, encode_png, fill_rect, fit_text, rgba, MUTED, TEXT};
+use chrono::{Datelike, Duration, NaiveDate};
+use std::collections::HashMap;
+
+/// Weeks shown, about three months
+pub const WEEKS: i64 = 13;
+const CELL: i32 = 18;
+const GAP: i32 = 4;
+const LEFT: i32 = 60;
+const TOP: i32 = 80;
+const EMPTY_COLOR: u32 = 0x2B2D3A;
+/// One catch, then two or more (boosters can cast twice)
+const FISHED_COLORS: [u32; 2] = [0x26A641, 0x39D353];
+
+/// File name the calendar is attached under, for `attachment://` references
+pub const FILE_NAME: &str = "calendar.png";
+
+/// First day shown: the Monday `WEEKS - 1` weeks before this week's Monday
+pub fn first_day(today: NaiveDate) -> NaiveDate {
+    today
+        - Duration::days(today.weekday().num_days_from_monday() as i64)
+        - Duration::weeks(WEEKS - 1)
+}
+
+/// GitHub-style grid of fishing days: one column per week, Monday on top
+pub fn render(username: &str, catches: &HashMap<NaiveDate, u64>, today: NaiveDate) -> Vec<u8> {
+    let width = (LEFT + WEEKS as i32 * (CELL + GAP) + 24) as u32;
+    let height = (TOP + 7 * (CELL + GAP) + 50) as u32;
+    let mut img = canvas(width, height);
+
+    draw_text(
+        &mut img,
+        24.0,
+        16.0,
+        22.0,
+        rgba(TEXT),
+        &fit_text(
+            22.0,
+            &format!("{}'s fishing days", username),
+            width as f32 - 48.0,
+        ),
+    );
+    for (row, label) in [(0, "Mon"), (2, "Wed"), (4, "Fri")] {
+        draw_text(
+            &mut img,
+            20.0,
+            (TOP + row * (CELL + GAP) + 1) as f32,
+            12.0,
+            rgba(MUTED),
+            label,
+        );
+    }
+
+    let start = first_day(today);
+    let mut last_month = None;
+    let mut fished_days = 0;
+    for week in 0..WEEKS {
+        let x = LEFT + week as i32 * (CELL + GAP);
+        let monday = start + Duration::weeks(week);
+        if last_month != Some(monday.month()) {
+            last_month = Some(monday.month());
+            draw_text(
+                &mut img,
+                x as f32,
+                (TOP - 22) as f32,
+                12.0,
+                rgba(MUTED),
+                &monday.format("%b").to_string(),
+            );
+        }
+        for row in 0..7 {
+            let day = monday + Duration::days(row);
+            if day > today {
+                continue;
+            }
+            let count = catches.get(&day).copied().unwrap_or(0);
+            let color = match count {
+                0 => EMPTY_COLOR,
+                1 => FISHED_COLORS[0],
+                _ => FISHED_COLORS[1],
+            };
+            if count > 0 {
+                fished_days += 1;
+            }
+            fill_rect(
+                &mut img,
+                x,
+                TOP + row as i32 * (CELL + GAP),
+                CELL as u32,
+                CELL as u32,
+                rgba(color),
+            );
+        }
+    }
+
+    draw_text(
+        &mut img,
+        LEFT as f32,
+        (TOP + 7 * (CELL + GAP) + 12) as f32,
+        14.0,
+        rgba(MUTED),
+        &format!("Fished on {} days", fished_days),
+    );
+
+    encode_png(&img)
+}
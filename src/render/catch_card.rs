@@ -0,0 +1,152 @@
+use super::{blend, canvas, draw_text, encode_png, fill_rect, fit_text, rgba, MUTED, TEXT};
+use crate::game::CatchOutcome;
+use image::{Rgba, RgbaImage};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+const WIDTH: u32 = 640;
+const HEIGHT: u32 = 220;
+const ART_SIZE: (u32, u32) = (180, 120);
+
+/// File name the card is attached under, for `attachment://` references
+pub const FILE_NAME: &str = "catch.png";
+
+/// Rendered fish art keyed by (colour, shiny)
+type ArtCache = HashMap<(u32, bool), Arc<RgbaImage>>;
+
+/// Fish art only depends on colour and shininess, so it's drawn once per pair
+fn fish_art(color: u32, shiny: bool) -> Arc<RgbaImage> {
+    static CACHE: Mutex<Option<ArtCache>> = Mutex::new(None);
+    let mut cache = CACHE.lock().unwrap();
+    cache
+        .get_or_insert_with(HashMap::new)
+        .entry((color, shiny))
+        .or_insert_with(|| Arc::new(draw_fish(color, shiny)))
+        .clone()
+}
+
+/// A simple side-on fish: oval body, tail fin, and an eye
+fn draw_fish(color: u32, shiny: bool) -> RgbaImage {
+    let (w, h) = ART_SIZE;
+    let mut art = RgbaImage::from_pixel(w, h, Rgba([0, 0, 0, 0]));
+    let body = rgba(color);
+    let (cx, cy) = (w as f32 * 0.42, h as f32 * 0.5);
+    let (rx, ry) = (w as f32 * 0.34, h as f32 * 0.3);
+
+    for y in 0..h as i32 {
+        for x in 0..w as i32 {
+            let (fx, fy) = (x as f32 + 0.5, y as f32 + 0.5);
+            // Oval body with a soft edge
+            let d = ((fx - cx) / rx).powi(2) + ((fy - cy) / ry).powi(2);
+            if d <= 1.0 {
+                blend(&mut art, x, y, body, ((1.0 - d) * 12.0).min(1.0));
+            }
+            // Tail: a triangle opening away from the body
+            let tail_x = fx - (cx + rx * 0.85);
+            if tail_x >= 0.0 && tail_x <= w as f32 * 0.2 && (fy - cy).abs() <= tail_x * 1.1 {
+                blend(&mut art, x, y, body, 1.0);
+            }
+        }
+    }
+
+    // Eye
+    let (ex, ey, er) = (cx - rx * 0.55, cy - ry * 0.25, 7.0f32);
+    for y in (ey - er) as i32..=(ey + er) as i32 {
+        for x in (ex - er) as i32..=(ex + er) as i32 {
+            let d = ((x as f32 - ex).powi(2) + (y as f32 - ey).powi(2)).sqrt();
+            if d <= er {
+                blend(&mut art, x, y, rgba(0xFFFFFF), 1.0);
+            }
+            if d <= er * 0.5 {
+                blend(&mut art, x, y, rgba(0x111111), 1.0);
+            }
+        }
+    }
+
+    if shiny {
+        for (sx, sy) in [(0.15, 0.15), (0.8, 0.12), (0.6, 0.88), (0.9, 0.7)] {
+            let (sx, sy) = ((sx * w as f32) as i32, (sy * h as f32) as i32);
+            for i in -6..=6 {
+                blend(
+                    &mut art,
+                    sx + i,
+                    sy,
+                    rgba(0xFFFFFF),
+                    1.0 - i.abs() as f32 / 7.0,
+                );
+                blend(
+                    &mut art,
+                    sx,
+                    sy + i,
+                    rgba(0xFFFFFF),
+                    1.0 - i.abs() as f32 / 7.0,
+                );
+            }
+        }
+    }
+    art
+}
+
+/// Render the card for a catch: fish art, name, rarity, size, and streak
+pub fn render(username: &str, outcome: &CatchOutcome) -> Vec<u8> {
+    let style = crate::ui::rarity_style(outcome.species.rarity);
+    let color = if outcome.shiny {
+        crate::ui::SHINY_COLOR
+    } else {
+        style.color
+    };
+    let mut img = canvas(WIDTH, HEIGHT);
+    fill_rect(&mut img, 0, 0, 10, HEIGHT, rgba(color));
+
+    let art = fish_art(color, outcome.shiny);
+    image::imageops::overlay(
+        &mut img,
+        art.as_ref(),
+        30,
+        ((HEIGHT - ART_SIZE.1) / 2) as i64,
+    );
+
+    let x = 240.0;
+    let max_width = WIDTH as f32 - x - 20.0;
+    let name = if outcome.shiny {
+        format!("Shiny {}", outcome.species.name)
+    } else {
+        outcome.species.name.to_string()
+    };
+    draw_text(
+        &mut img,
+        x,
+        20.0,
+        34.0,
+        rgba(TEXT),
+        &fit_text(34.0, &name, max_width),
+    );
+    draw_text(
+        &mut img,
+        x,
+        64.0,
+        22.0,
+        rgba(color),
+        outcome.species.rarity.label(),
+    );
+    let lines = [
+        (
+            MUTED,
+            format!("{} cm  ·  {}", outcome.size_cm, outcome.location.name),
+        ),
+        (TEXT, username.to_string()),
+        (MUTED, format!("{} day streak", outcome.streak)),
+    ];
+    for (i, (line_color, line)) in lines.iter().enumerate() {
+        draw_text(
+            &mut img,
+            x,
+            102.0 + i as f32 * 34.0,
+            20.0,
+            rgba(*line_color),
+            &fit_text(20.0, line, max_width),
+        );
+    }
+
+    encode_png(&img)
+}
@@ -0,0 +1,82 @@
+use super::{canvas, draw_text, encode_png, fill_rect, rgba, text_width, MUTED, TEXT};
+
+const WIDTH: u32 = 700;
+const HEIGHT: u32 = 320;
+const BAR_COLOR: u32 = 0xFFD700;
+/// Today's bar is highlighted
+const TODAY_COLOR: u32 = 0x00B3B3;
+
+/// File name the chart is attached under, for `attachment://` references
+pub const FILE_NAME: &str = "catches.png";
+
+/// Bar chart of catches per day, oldest first. The last entry is today.
+pub fn render(days: &[(String, u64)]) -> Vec<u8> {
+    let mut img = canvas(WIDTH, HEIGHT);
+    draw_text(
+        &mut img,
+        24.0,
+        16.0,
+        22.0,
+        rgba(TEXT),
+        &format!("Catches over the last {} days", days.len()),
+    );
+
+    let (left, right, top, bottom) = (24.0, WIDTH as f32 - 24.0, 80.0, HEIGHT as f32 - 40.0);
+    fill_rect(
+        &mut img,
+        left as i32,
+        bottom as i32,
+        (right - left) as u32,
+        2,
+        rgba(MUTED),
+    );
+
+    let max = days
+        .iter()
+        .map(|(_, count)| *count)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    let slot = (right - left) / days.len().max(1) as f32;
+    let bar_width = (slot * 0.6).max(1.0);
+    for (i, (date, count)) in days.iter().enumerate() {
+        let height = (bottom - top) * *count as f32 / max as f32;
+        let x = left + slot * i as f32 + (slot - bar_width) / 2.0;
+        let color = if i + 1 == days.len() {
+            TODAY_COLOR
+        } else {
+            BAR_COLOR
+        };
+        fill_rect(
+            &mut img,
+            x as i32,
+            (bottom - height) as i32,
+            bar_width as u32,
+            height as u32,
+            rgba(color),
+        );
+
+        let center = x + bar_width / 2.0;
+        let value = count.to_string();
+        draw_text(
+            &mut img,
+            center - text_width(14.0, &value) / 2.0,
+            bottom - height - 22.0,
+            14.0,
+            rgba(TEXT),
+            &value,
+        );
+        // Day of the month is enough under a two-week window
+        let label = date.rsplit('-').next().unwrap_or(date);
+        draw_text(
+            &mut img,
+            center - text_width(13.0, label) / 2.0,
+            bottom + 8.0,
+            13.0,
+            rgba(MUTED),
+            label,
+        );
+    }
+
+    encode_png(&img)
+}
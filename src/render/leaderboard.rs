@@ -0,0 +1,158 @@
+use super::{
+    blend, canvas, draw_text, encode_png, fill_rect, fit_text, rgba, text_width, MUTED, TEXT,
+};
+use image::RgbaImage;
+use poise::serenity_prelude as serenity;
+
+const WIDTH: u32 = 560;
+const ROW_HEIGHT: i32 = 60;
+const TOP: i32 = 70;
+const AVATAR_SIZE: u32 = 44;
+/// Gold, silver, and bronze for the podium ranks
+const PODIUM_COLORS: [u32; 3] = [0xFFD700, 0xC0C0C0, 0xCD7F32];
+const STRIPE_COLOR: u32 = 0x262834;
+const PLACEHOLDER_COLOR: u32 = 0x5865F2;
+
+/// File name the leaderboard is attached under, for `attachment://` references
+pub const FILE_NAME: &str = "leaderboard.png";
+
+/// One row of the board
+pub struct Entry {
+    pub name: String,
+    pub catches: u64,
+    pub avatar: Option<RgbaImage>,
+}
+
+/// Download a member's avatar as a small PNG. `None` on any failure, so the
+/// board falls back to an initial instead.
+pub async fn fetch_avatar(
+    http: &serenity::Http,
+    client: &reqwest::Client,
+    user_id: serenity::UserId,
+) -> Option<RgbaImage> {
+    let user = user_id.to_user(http).await.ok()?;
+    let url = match &user.avatar {
+        Some(hash) => format!(
+            "https://cdn.discordapp.com/avatars/{}/{}.png?size=64",
+            user.id, hash
+        ),
+        None => user.default_avatar_url(),
+    };
+    let bytes = client
+        .get(url)
+        .send()
+        .await
+        .ok()?
+        .error_for_status()
+        .ok()?
+        .bytes()
+        .await
+        .ok()?;
+    let avatar = image::load_from_memory(&bytes).ok()?.to_rgba8();
+    Some(image::imageops::resize(
+        &avatar,
+        AVATAR_SIZE,
+        AVATAR_SIZE,
+        image::imageops::FilterType::Triangle,
+    ))
+}
+
+/// Draw an avatar (or a coloured disc with the name's initial) clipped to a circle
+fn draw_avatar(img: &mut RgbaImage, x: i32, y: i32, name: &str, avatar: Option<&RgbaImage>) {
+    let r = AVATAR_SIZE as f32 / 2.0;
+    for ay in 0..AVATAR_SIZE {
+        for ax in 0..AVATAR_SIZE {
+            let d = ((ax as f32 + 0.5 - r).powi(2) + (ay as f32 + 0.5 - r).powi(2)).sqrt();
+            // Anti-alias the last pixel of the edge
+            let coverage = (r - d).clamp(0.0, 1.0);
+            if coverage == 0.0 {
+                continue;
+            }
+            let (color, alpha) = match avatar {
+                Some(avatar) => {
+                    let pixel = *avatar.get_pixel(ax, ay);
+                    (pixel, pixel.0[3] as f32 / 255.0)
+                }
+                None => (rgba(PLACEHOLDER_COLOR), 1.0),
+            };
+            blend(img, x + ax as i32, y + ay as i32, color, coverage * alpha);
+        }
+    }
+    if avatar.is_none() {
+        let initial: String = name.chars().take(1).flat_map(char::to_uppercase).collect();
+        let w = text_width(22.0, &initial);
+        draw_text(
+            img,
+            x as f32 + r - w / 2.0,
+            y as f32 + r - 13.0,
+            22.0,
+            rgba(TEXT),
+            &initial,
+        );
+    }
+}
+
+/// Ranked rows with avatar, name, and catch count under a title
+pub fn render(title: &str, entries: &[Entry]) -> Vec<u8> {
+    let height = (TOP + entries.len().max(1) as i32 * ROW_HEIGHT + 20) as u32;
+    let mut img = canvas(WIDTH, height);
+
+    draw_text(
+        &mut img,
+        24.0,
+        20.0,
+        26.0,
+        rgba(TEXT),
+        &fit_text(26.0, title, WIDTH as f32 - 48.0),
+    );
+
+    for (i, entry) in entries.iter().enumerate() {
+        let y = TOP + i as i32 * ROW_HEIGHT;
+        if i % 2 == 0 {
+            fill_rect(
+                &mut img,
+                12,
+                y,
+                WIDTH - 24,
+                ROW_HEIGHT as u32,
+                rgba(STRIPE_COLOR),
+            );
+        }
+        let rank_color = PODIUM_COLORS.get(i).copied().unwrap_or(MUTED);
+        draw_text(
+            &mut img,
+            28.0,
+            (y + 16) as f32,
+            22.0,
+            rgba(rank_color),
+            &format!("#{}", i + 1),
+        );
+
+        let avatar_y = y + (ROW_HEIGHT - AVATAR_SIZE as i32) / 2;
+        draw_avatar(&mut img, 84, avatar_y, &entry.name, entry.avatar.as_ref());
+
+        let count = format!("{} catches", entry.catches);
+        let count_width = text_width(20.0, &count);
+        let count_x = WIDTH as f32 - 32.0 - count_width;
+        draw_text(
+            &mut img,
+            count_x,
+            (y + 18) as f32,
+            20.0,
+            rgba(MUTED),
+            &count,
+        );
+
+        let name_x = 84.0 + AVATAR_SIZE as f32 + 16.0;
+        draw_text(
+            &mut img,
+            name_x,
+            (y + 17) as f32,
+            21.0,
+            rgba(TEXT),
+            &fit_text(21.0, &entry.name, count_x - name_x - 16.0),
+        );
+    }
+
+    encode_png(&img)
+}
@@ -0,0 +1,116 @@
+//! PNG cards and charts for the `image-gen` feature. Everything is drawn with
+//! a few primitives over `image` buffers and the bundled DejaVu font, so no
+//! system fonts or graphics libraries are needed at runtime.
+
+pub mod calendar;
+pub mod catch_card;
+pub mod catch_chart;
+pub mod leaderboard;
+
+use ab_glyph::{Font, FontRef, PxScale, ScaleFont};
+use image::{Rgba, RgbaImage};
+use std::sync::OnceLock;
+
+static FONT_BYTES: &[u8] = include_bytes!("../../assets/fonts/DejaVuSans-Bold.ttf");
+
+pub const BACKGROUND: u32 = 0x1E1F29;
+pub const TEXT: u32 = 0xF2F3F5;
+pub const MUTED: u32 = 0x9DA3AE;
+
+fn font() -> &'static FontRef<'static> {
+    static FONT: OnceLock<FontRef<'static>> = OnceLock::new();
+    FONT.get_or_init(|| FontRef::try_from_slice(FONT_BYTES).expect("the bundled font is valid"))
+}
+
+pub fn rgba(color: u32) -> Rgba<u8> {
+    Rgba([(color >> 16) as u8, (color >> 8) as u8, color as u8, 255])
+}
+
+/// Mix `color` into the pixel at (x, y) with the given coverage (0.0 to 1.0)
+pub fn blend(img: &mut RgbaImage, x: i32, y: i32, color: Rgba<u8>, coverage: f32) {
+    if x < 0 || y < 0 || x >= img.width() as i32 || y >= img.height() as i32 {
+        return;
+    }
+    let coverage = coverage.clamp(0.0, 1.0);
+    let pixel = img.get_pixel_mut(x as u32, y as u32);
+    for channel in 0..3 {
+        let under = pixel.0[channel] as f32;
+        pixel.0[channel] = (under + (color.0[channel] as f32 - under) * coverage) as u8;
+    }
+    pixel.0[3] = pixel.0[3].max((coverage * 255.0) as u8);
+}
+
+pub fn fill_rect(img: &mut RgbaImage, x: i32, y: i32, w: u32, h: u32, color: Rgba<u8>) {
+    for py in y..y + h as i32 {
+        for px in x..x + w as i32 {
+            blend(img, px, py, color, 1.0);
+        }
+    }
+}
+
+/// A blank canvas in the card background colour
+pub fn canvas(width: u32, height: u32) -> RgbaImage {
+    RgbaImage::from_pixel(width, height, rgba(BACKGROUND))
+}
+
+/// Width in pixels of `text` at `size`
+pub fn text_width(size: f32, text: &str) -> f32 {
+    let font = font().as_scaled(PxScale::from(size));
+    text.chars().map(|c| font.h_advance(font.glyph_id(c))).sum()
+}
+
+/// Draw `text` with its top-left corner at (x, y). Returns the width drawn.
+pub fn draw_text(
+    img: &mut RgbaImage,
+    x: f32,
+    y: f32,
+    size: f32,
+    color: Rgba<u8>,
+    text: &str,
+) -> f32 {
+    let scale = PxScale::from(size);
+    let scaled = font().as_scaled(scale);
+    let baseline = y + scaled.ascent();
+    let mut caret = x;
+    for c in text.chars() {
+        let id = scaled.glyph_id(c);
+        let glyph = id.with_scale_and_position(scale, ab_glyph::point(caret, baseline));
+        caret += scaled.h_advance(id);
+        if let Some(outline) = font().outline_glyph(glyph) {
+            let bounds = outline.px_bounds();
+            outline.draw(|gx, gy, coverage| {
+                blend(
+                    img,
+                    bounds.min.x as i32 + gx as i32,
+                    bounds.min.y as i32 + gy as i32,
+                    color,
+                    coverage,
+                );
+            });
+        }
+    }
+    caret - x
+}
+
+/// Shorten `text` with an ellipsis until it fits in `max_width`
+pub fn fit_text(size: f32, text: &str, max_width: f32) -> String {
+    if text_width(size, text) <= max_width {
+        return text.to_string();
+    }
+    let mut chars: Vec<char> = text.chars().collect();
+    while !chars.is_empty() {
+        chars.pop();
+        let candidate = format!("{}…", chars.iter().collect::<String>());
+        if text_width(size, &candidate) <= max_width {
+            return candidate;
+        }
+    }
+    String::new()
+}
+
+pub fn encode_png(img: &RgbaImage) -> Vec<u8> {
+    let mut bytes = std::io::Cursor::new(Vec::new());
+    img.write_to(&mut bytes, image::ImageFormat::Png)
+        .expect("encoding to memory doesn't fail");
+    bytes.into_inner()
+}
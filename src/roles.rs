@@ -0,0 +1,219 @@
+use crate::game::{BrokenStreak, CatchOutcome, FishingManager};
+use poise::serenity_prelude as serenity;
+
+fn parse_role(id: &str) -> Option<serenity::RoleId> {
+    id.parse::<u64>().ok().map(serenity::RoleId::new)
+}
+
+/// Everyone in the server holding any of the tracked `role_ids`, each once
+pub async fn tracked_members(
+    fishing_manager: &FishingManager,
+    http: &serenity::Http,
+    guild_id: serenity::GuildId,
+    role_ids: &[String],
+) -> Result<Vec<serenity::UserId>, serenity::Error> {
+    Ok(
+        tracked_member_roles(fishing_manager, http, guild_id, role_ids)
+            .await?
+            .into_iter()
+            .map(|(member, _)| member)
+            .collect(),
+    )
+}
+
+/// Like [`tracked_members`], with which of `role_ids` each member holds
+pub async fn tracked_member_roles(
+    fishing_manager: &FishingManager,
+    http: &serenity::Http,
+    guild_id: serenity::GuildId,
+    role_ids: &[String],
+) -> Result<Vec<(serenity::UserId, Vec<String>)>, serenity::Error> {
+    let mut members: Vec<(serenity::UserId, Vec<String>)> = Vec::new();
+    let mut index = std::collections::HashMap::new();
+    for role_id in role_ids.iter().filter_map(|id| parse_role(id)) {
+        for member in role_members(fishing_manager, http, guild_id, role_id).await? {
+            let i = *index.entry(member).or_insert_with(|| {
+                members.push((member, Vec::new()));
+                members.len() - 1
+            });
+            members[i].1.push(role_id.to_string());
+        }
+    }
+    Ok(members)
+}
+
+/// Everyone in the server holding `role_id`. Paging through every member is
+/// slow on big servers, so the list is cached when a cache is configured.
+pub async fn role_members(
+    fishing_manager: &FishingManager,
+    http: &serenity::Http,
+    guild_id: serenity::GuildId,
+    role_id: serenity::RoleId,
+) -> Result<Vec<serenity::UserId>, serenity::Error> {
+    let (guild_key, role_key) = (guild_id.to_string(), role_id.to_string());
+    if let Some(cached) = fishing_manager
+        .cached_role_members(&guild_key, &role_key)
+        .await
+    {
+        return Ok(cached
+            .iter()
+            .filter_map(|id| id.parse::<u64>().ok())
+            .map(serenity::UserId::new)
+            .collect());
+    }
+
+    // PAGINATION: Fetch all members reliably
+    let mut holders = Vec::new();
+    let mut after = None;
+    loop {
+        let page = guild_id.members(http, Some(1000), after).await?;
+        let Some(last) = page.last() else {
+            break;
+        };
+        after = Some(last.user.id);
+        holders.extend(
+            page.iter()
+                .filter(|member| member.roles.contains(&role_id))
+                .map(|member| member.user.id),
+        );
+    }
+
+    let ids: Vec<String> = holders.iter().map(|id| id.to_string()).collect();
+    fishing_manager
+        .cache_role_members(&guild_key, &role_key, &ids)
+        .await;
+    Ok(holders)
+}
+
+/// Give the catcher any streak reward roles they've reached but don't have yet
+pub async fn grant_streak_roles(
+    ctx: &serenity::Context,
+    interaction: &serenity::ComponentInteraction,
+    outcome: &CatchOutcome,
+) {
+    let (guild_id, member) = match (interaction.guild_id, interaction.member.as_ref()) {
+        (Some(guild_id), Some(member)) => (guild_id, member),
+        _ => return,
+    };
+
+    for role_id in outcome.streak_roles.iter().filter_map(|id| parse_role(id)) {
+        if member.roles.contains(&role_id) {
+            continue;
+        }
+        if let Err(e) = ctx
+            .http
+            .add_member_role(
+                guild_id,
+                interaction.user.id,
+                role_id,
+                Some("Reached a fishing streak reward"),
+            )
+            .await
+        {
+            tracing::error!("❌ Error granting streak role {}: {}", role_id, e);
+        }
+    }
+}
+
+/// Take streak reward roles away from everyone whose streak just broke
+pub async fn revoke_streak_roles(http: &serenity::Http, broken: &[BrokenStreak]) {
+    for streak in broken {
+        let guild_id = match streak.guild_id.parse::<u64>() {
+            Ok(id) => serenity::GuildId::new(id),
+            Err(_) => continue,
+        };
+        let user_id = match streak.user_id.parse::<u64>() {
+            Ok(id) => serenity::UserId::new(id),
+            Err(_) => continue,
+        };
+        for role_id in streak.roles.iter().filter_map(|id| parse_role(id)) {
+            // Discord treats removing a role the member doesn't have as a no-op
+            if let Err(e) = http
+                .remove_member_role(guild_id, user_id, role_id, Some("Fishing streak broken"))
+                .await
+            {
+                tracing::error!(
+                    "❌ Error removing streak role {} from {}: {}",
+                    role_id,
+                    user_id,
+                    e
+                );
+            }
+        }
+    }
+
+    if !broken.is_empty() {
+        tracing::info!(
+            "🏷️ Checked streak roles for {} broken streaks",
+            broken.len()
+        );
+    }
+}
+
+/// Take the tracked `role_ids` off a member, so the summary stops reminding
+/// them. Returns whether every role came off.
+pub async fn remove_tracked_roles(
+    http: &serenity::Http,
+    guild_id: serenity::GuildId,
+    user_id: serenity::UserId,
+    role_ids: &[String],
+    reason: &str,
+) -> bool {
+    let mut removed = true;
+    for role_id in role_ids.iter().filter_map(|id| parse_role(id)) {
+        if let Err(e) = http
+            .remove_member_role(guild_id, user_id, role_id, Some(reason))
+            .await
+        {
+            tracing::error!(
+                "❌ Error removing tracked role {} from {}: {}",
+                role_id,
+                user_id,
+                e
+            );
+            removed = false;
+        }
+    }
+    removed
+}
+
+/// Take the tracked roles off everyone who stopped fishing
+/// [`INACTIVE_DAYS`](crate::game::INACTIVE_DAYS) or more days ago. Members
+/// who never fished are left alone, since they may have only just joined.
+pub async fn untrack_inactive(
+    fishing_manager: &FishingManager,
+    http: &serenity::Http,
+    guild_id: &str,
+) {
+    let Ok(g_id) = guild_id.parse::<u64>().map(serenity::GuildId::new) else {
+        return;
+    };
+    let summary = fishing_manager.daily_summary(guild_id).await;
+    let members =
+        match tracked_member_roles(fishing_manager, http, g_id, &summary.tracked_role_ids).await {
+            Ok(members) => members,
+            Err(e) => {
+                tracing::error!("❌ Error fetching members to untrack: {}", e);
+                return;
+            }
+        };
+    let ids: Vec<String> = members.iter().map(|(id, _)| id.to_string()).collect();
+    let inactive = fishing_manager.inactive_players(guild_id, &ids).await;
+
+    let mut untracked = 0;
+    for (user_id, roles) in &members {
+        let idle = inactive
+            .iter()
+            .any(|player| player.user_id == user_id.to_string() && player.days_idle.is_some());
+        if idle && remove_tracked_roles(http, g_id, *user_id, roles, "Stopped fishing").await {
+            untracked += 1;
+        }
+    }
+    if untracked > 0 {
+        tracing::info!(
+            "🏷️ Untracked {} inactive members in {}",
+            untracked,
+            guild_id
+        );
+    }
+}
@@ -0,0 +1,429 @@
+use crate::data::{fill_template, MessageKey, ReminderMode};
+use crate::game::FishingManager;
+use chrono::Utc;
+use poise::serenity_prelude::{self as serenity, CreateEmbed, CreateMessage};
+
+/// Custom ID of the snooze button under the summary's reminder line
+pub const SNOOZE_BUTTON: &str = "reminder_snooze";
+
+/// The end-of-day summary as it would be posted right now
+pub struct DailySummaryDraft {
+    /// Where it goes, and what each channel does with the reminder line;
+    /// empty if no summary channel is set
+    pub channels: Vec<(serenity::ChannelId, ReminderMode)>,
+    pub embed: CreateEmbed,
+    /// The reminder line mentioning everyone overdue, if anyone is
+    pub content: Option<String>,
+    /// Snooze button posted with the reminder line
+    pub snooze: serenity::CreateActionRow,
+    #[cfg(feature = "image-gen")]
+    pub chart: serenity::CreateAttachment,
+}
+
+/// Post the end-of-day aquarium summary to every summary channel, reminding
+/// tracked members who are overdue as each channel is set to
+pub async fn post_daily_summary(
+    fishing_manager: &FishingManager,
+    http: &serenity::Http,
+    guild_id: &str,
+) {
+    let Some(draft) = draft_daily_summary(fishing_manager, http, guild_id).await else {
+        return;
+    };
+    for (channel_id, reminder) in &draft.channels {
+        let mut msg = CreateMessage::new().embed(draft.embed.clone());
+        if let Some(content) = draft
+            .content
+            .as_ref()
+            .filter(|_| *reminder != ReminderMode::Off)
+        {
+            msg = msg.content(content).components(vec![draft.snooze.clone()]);
+        }
+        if *reminder == ReminderMode::List {
+            // Show who is overdue without pinging them
+            msg = msg.allowed_mentions(serenity::CreateAllowedMentions::new());
+        }
+        #[cfg(feature = "image-gen")]
+        {
+            msg = msg.add_file(draft.chart.clone());
+        }
+        if let Err(e) = channel_id.send_message(http, msg).await {
+            tracing::error!("❌ Error sending summary to {}: {}", channel_id, e);
+        }
+    }
+}
+
+/// Build tonight's summary without sending it, e.g. for `/fishsummary preview`
+pub async fn draft_daily_summary(
+    fishing_manager: &FishingManager,
+    http: &serenity::Http,
+    guild_id: &str,
+) -> Option<DailySummaryDraft> {
+    let summary = fishing_manager.daily_summary(guild_id).await;
+
+    let channels: Vec<(serenity::ChannelId, ReminderMode)> = summary
+        .channels
+        .iter()
+        .filter_map(|channel| {
+            let id = channel.channel_id.parse::<u64>().ok()?;
+            Some((serenity::ChannelId::new(id), channel.reminder))
+        })
+        .collect();
+
+    let g_id = serenity::GuildId::new(summary.guild_id.parse::<u64>().ok()?);
+
+    let non_fishers = non_fishers(fishing_manager, http, g_id, &summary).await;
+
+    let branding = fishing_manager.branding(guild_id).await;
+    let text = crate::i18n::strings(summary.locale);
+    let (count, catches) = (
+        non_fishers.len().to_string(),
+        summary.daily_count.to_string(),
+    );
+    let role = if summary.tracked_role_ids.is_empty() {
+        text.everyone.to_string()
+    } else {
+        summary
+            .tracked_role_ids
+            .iter()
+            .map(|id| format!("<@&{}>", id))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    let placeholders = [
+        ("count", count.as_str()),
+        ("catches", catches.as_str()),
+        ("role", role.as_str()),
+        ("pond", branding.pond_name.as_str()),
+    ];
+    let message = |key: MessageKey| fill_template(&summary.messages[&key], &placeholders);
+
+    let mut embed = CreateEmbed::new()
+        .title(text.summary_title)
+        .description(message(MessageKey::Summary))
+        .color(branding.highlight)
+        .field(
+            text.summary_catches,
+            format!("**{}**", summary.daily_count),
+            true,
+        )
+        .field(
+            text.members_missed,
+            format!("**{}**", non_fishers.len()),
+            true,
+        )
+        .footer(serenity::CreateEmbedFooter::new(fill_template(
+            text.summary_footer,
+            &[("pond", &branding.pond_name)],
+        )))
+        .timestamp(Utc::now());
+
+    // Per-spot counts, shown once more than one spot is in play
+    if summary.spot_counts.len() > 1 {
+        let spots_text = summary
+            .spot_counts
+            .iter()
+            .map(|(location, count)| format!("{} {}: **{}**", location.emoji, location.name, count))
+            .collect::<Vec<_>>()
+            .join("\n");
+        embed = embed.field(text.catches_by_spot, spots_text, false);
+    }
+
+    if !summary.best_anglers.is_empty() {
+        let mut anglers_text = String::new();
+        for angler in summary.best_anglers.iter().take(10) {
+            anglers_text.push_str(&fill_template(
+                text.best_angler_line,
+                &[
+                    (
+                        "name",
+                        &crate::ui::titled_name(&angler.username, angler.title),
+                    ),
+                    ("catches", &angler.total_catches.to_string()),
+                    ("streak", &angler.streak.to_string()),
+                ],
+            ));
+            anglers_text.push('\n');
+        }
+        embed = embed.field(
+            fill_template(
+                text.best_anglers,
+                &[("streak", &summary.best_angler_streak.to_string())],
+            ),
+            anglers_text,
+            false,
+        );
+    }
+
+    if !summary.team_standings.is_empty() {
+        let standings = summary
+            .team_standings
+            .iter()
+            .map(|(name, today, month)| {
+                fill_template(
+                    text.team_standing_line,
+                    &[
+                        ("team", name),
+                        ("today", &today.to_string()),
+                        ("month", &month.to_string()),
+                    ],
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        embed = embed.field(text.team_standings, standings, false);
+    }
+
+    if let Some(winner) = &summary.team_winner {
+        embed = embed.field(
+            text.team_of_the_month,
+            fill_template(
+                text.team_winner,
+                &[
+                    ("team", &winner.team),
+                    ("month", &winner.month),
+                    ("points", &winner.points.to_string()),
+                ],
+            ),
+            false,
+        );
+    }
+
+    if let Some(draw) = &summary.lottery {
+        embed = embed.field(
+            text.lottery_winner,
+            fill_template(
+                text.lottery_winner_text,
+                &[
+                    ("winner", &format!("<@{}>", draw.winner_id)),
+                    ("jackpot", &draw.jackpot.to_string()),
+                    ("tickets", &draw.tickets.to_string()),
+                ],
+            ),
+            false,
+        );
+    }
+
+    let records = crate::ui::hall_of_fame_lines(&summary.hall_of_fame, Some(&summary.today_date));
+    if !records.is_empty() {
+        embed = embed.field(text.hall_of_fame, records, false);
+    }
+
+    #[cfg(feature = "image-gen")]
+    let chart = {
+        use crate::render::catch_chart;
+        let mut days: Vec<(String, u64)> = summary
+            .recent_days
+            .iter()
+            .map(|day| (day.date.clone(), day.catches))
+            .collect();
+        days.push((summary.today_date.clone(), summary.daily_count));
+        embed = embed.image(format!("attachment://{}", catch_chart::FILE_NAME));
+        serenity::CreateAttachment::bytes(catch_chart::render(&days), catch_chart::FILE_NAME)
+    };
+
+    embed = embed.field(text.message, message(MessageKey::MissYou), false);
+
+    let content = (!non_fishers.is_empty()).then(|| {
+        let pings = non_fishers
+            .iter()
+            .map(|id| format!("<@{}>", id))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        if pings.len() > 1850 {
+            // Truncate to fit in one Discord message
+            let truncated = &pings[..1800];
+            let last_space = truncated.rfind(' ').unwrap_or(1800);
+            let others = non_fishers.len() - (truncated.split(' ').count());
+            format!(
+                "{}\n{} {}",
+                message(MessageKey::Reminder),
+                &truncated[..last_space],
+                fill_template(text.and_others, &[("count", &others.to_string())])
+            )
+        } else {
+            format!("{}\n{}", message(MessageKey::Reminder), pings)
+        }
+    });
+
+    let snooze =
+        serenity::CreateActionRow::Buttons(vec![serenity::CreateButton::new(SNOOZE_BUTTON)
+            .label(fill_template(
+                text.snooze_button,
+                &[("days", &crate::game::REMINDER_SNOOZE_DAYS.to_string())],
+            ))
+            .style(serenity::ButtonStyle::Secondary)]);
+
+    Some(DailySummaryDraft {
+        channels,
+        embed,
+        content,
+        snooze,
+        #[cfg(feature = "image-gen")]
+        chart,
+    })
+}
+
+/// Tracked members overdue for a reminder, or nobody if the roster can't be fetched
+pub async fn non_fishers(
+    fishing_manager: &FishingManager,
+    http: &serenity::Http,
+    guild_id: serenity::GuildId,
+    summary: &crate::game::DailySummary,
+) -> Vec<serenity::UserId> {
+    match crate::roles::tracked_member_roles(
+        fishing_manager,
+        http,
+        guild_id,
+        &summary.tracked_role_ids,
+    )
+    .await
+    {
+        Ok(members) => members
+            .into_iter()
+            .filter(|(id, roles)| summary.needs_reminder(&id.to_string(), roles))
+            .map(|(id, _)| id)
+            .collect(),
+        Err(e) => {
+            tracing::error!("❌ Error fetching members for summary: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+/// Post the weekly leaderboard: the top anglers by catches over the last seven days
+pub async fn post_weekly_summary(
+    fishing_manager: &FishingManager,
+    http: &serenity::Http,
+    guild_id: &str,
+) {
+    let summary = fishing_manager.daily_summary(guild_id).await;
+    let channel_id = match summary
+        .summary_channel_id
+        .as_ref()
+        .and_then(|id| id.parse::<u64>().ok())
+    {
+        Some(id) => serenity::ChannelId::new(id),
+        None => return,
+    };
+
+    let anglers = fishing_manager
+        .weekly_leaderboard(guild_id, Utc::now().timestamp_millis() as u64)
+        .await;
+    if anglers.is_empty() {
+        return;
+    }
+
+    let branding = fishing_manager.branding(guild_id).await;
+    let text = crate::i18n::strings(summary.locale);
+    let mut embed = CreateEmbed::new()
+        .title(text.weekly_title)
+        .description(text.weekly_description)
+        .color(branding.highlight)
+        .footer(serenity::CreateEmbedFooter::new(fill_template(
+            text.weekly_footer,
+            &[("pond", &branding.pond_name)],
+        )))
+        .timestamp(Utc::now());
+
+    // Big servers get a rendered card instead of a wall of text
+    #[cfg(feature = "image-gen")]
+    let card = {
+        use crate::render::leaderboard;
+        let client = reqwest::Client::new();
+        let mut entries = Vec::new();
+        for angler in &anglers {
+            let avatar = match angler.user_id.parse::<u64>() {
+                Ok(id) => leaderboard::fetch_avatar(http, &client, serenity::UserId::new(id)).await,
+                Err(_) => None,
+            };
+            entries.push(leaderboard::Entry {
+                name: angler.username.clone(),
+                catches: angler.catches,
+                avatar,
+            });
+        }
+        embed = embed.image(format!("attachment://{}", leaderboard::FILE_NAME));
+        serenity::CreateAttachment::bytes(
+            leaderboard::render(text.weekly_card_title, &entries),
+            leaderboard::FILE_NAME,
+        )
+    };
+
+    #[cfg(not(feature = "image-gen"))]
+    {
+        let board = anglers
+            .iter()
+            .enumerate()
+            .map(|(i, angler)| {
+                format!(
+                    "**#{}** {}: {} 🐟",
+                    i + 1,
+                    crate::ui::titled_name(&angler.username, angler.title),
+                    angler.catches
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        embed = embed.field(text.most_catches, board, false);
+    }
+
+    let stats = fishing_manager.server_stats(guild_id).await;
+    embed = embed.field(
+        text.pond_health,
+        crate::ui::server_stats_lines(&stats),
+        false,
+    );
+
+    let msg = CreateMessage::new().embed(embed);
+    #[cfg(feature = "image-gen")]
+    let msg = msg.add_file(card);
+
+    if let Err(e) = channel_id.send_message(http, msg).await {
+        tracing::error!("❌ Error sending weekly summary: {}", e);
+    }
+}
+
+/// Tell every server's summary channel that the saved data failed to load at
+/// startup and what was done about it
+pub async fn post_recovery_notice(
+    data_manager: &crate::data::DataManager,
+    http: &serenity::Http,
+    recovery: &crate::data::Recovery,
+) {
+    let description = match &recovery.backup {
+        Some(backup) => format!(
+            "The saved pond data couldn't be read, so it was restored from the backup `{}`. \
+             Catches since that backup may be missing; admins can fix streaks with `/repairstreak`.",
+            backup
+        ),
+        None => "The saved pond data couldn't be read and no backup could be loaded. \
+                 The pond is running on empty data and **nothing will be saved** until an admin \
+                 repairs the data file."
+            .to_string(),
+    };
+    let embed = CreateEmbed::new()
+        .title("⚠️ Data Recovery")
+        .description(description)
+        .field("Error", format!("`{}`", recovery.error), false)
+        .color(0xFFA500)
+        .timestamp(Utc::now());
+
+    for guild_id in data_manager.guild_ids().await {
+        let channel_id = match data_manager
+            .guild(&guild_id)
+            .await
+            .summary_channel_id
+            .as_ref()
+            .and_then(|id| id.parse::<u64>().ok())
+        {
+            Some(id) => serenity::ChannelId::new(id),
+            None => continue,
+        };
+        let msg = CreateMessage::new().embed(embed.clone());
+        if let Err(e) = channel_id.send_message(http, msg).await {
+            tracing::error!("❌ Error sending recovery notice: {}", e);
+        }
+    }
+}
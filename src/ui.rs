@@ -0,0 +1,664 @@
+use crate::data::{fill_template, BoosterPerk, HallOfFame};
+use crate::fish::{Location, Rarity, Species};
+use crate::game::CatchOutcome;
+use crate::titles::Title;
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use poise::serenity_prelude as serenity;
+use rand::seq::SliceRandom;
+
+/// How a catch of each rarity looks when announced; titles and flavor lines
+/// live in [`crate::i18n`]
+pub struct RarityStyle {
+    pub rarity: Rarity,
+    pub color: u32,
+    pub emoji: &'static str,
+}
+
+pub static RARITY_STYLES: &[RarityStyle] = &[
+    RarityStyle {
+        rarity: Rarity::Common,
+        color: 0x0099FF,
+        emoji: "⚪",
+    },
+    RarityStyle {
+        rarity: Rarity::Uncommon,
+        color: 0x2ECC71,
+        emoji: "🟢",
+    },
+    RarityStyle {
+        rarity: Rarity::Rare,
+        color: 0x3498DB,
+        emoji: "🔵",
+    },
+    RarityStyle {
+        rarity: Rarity::Epic,
+        color: 0x9B59B6,
+        emoji: "🟣",
+    },
+    RarityStyle {
+        rarity: Rarity::Legendary,
+        color: 0xFFD700,
+        emoji: "🌟",
+    },
+];
+
+/// Embed colour for shiny catches, whatever their rarity
+pub const SHINY_COLOR: u32 = 0xFF69B4;
+/// Discord's boost pink, for crystal fish
+pub const CRYSTAL_COLOR: u32 = 0xF47FFF;
+
+/// "Shiny Koi" for shiny catches, plain species name otherwise
+pub fn species_name(species: &crate::fish::Species, shiny: bool) -> String {
+    if shiny {
+        fill_template(crate::i18n::EN.shiny_name, &[("fish", species.name)])
+    } else {
+        species.name.to_string()
+    }
+}
+
+pub fn rarity_style(rarity: Rarity) -> &'static RarityStyle {
+    RARITY_STYLES
+        .iter()
+        .find(|s| s.rarity == rarity)
+        .unwrap_or(&RARITY_STYLES[0])
+}
+
+/// Public embed announcing a successful catch, in the server's language
+pub fn catch_embed(
+    username: &str,
+    avatar_url: String,
+    outcome: &CatchOutcome,
+) -> serenity::CreateEmbed {
+    let text = crate::i18n::strings(outcome.locale);
+    let name = titled_name(username, outcome.title);
+    let rarity = outcome.species.rarity;
+    let style = rarity_style(rarity);
+    let flavor = text
+        .flavors(rarity)
+        .choose(&mut rand::thread_rng())
+        .copied()
+        .unwrap_or_default();
+    let crystal = outcome.booster_perk == Some(BoosterPerk::CrystalFish);
+    let rarity_title = text.rarity_title(rarity);
+    let (color, title) = if outcome.shiny {
+        (
+            SHINY_COLOR,
+            fill_template(text.shiny_title, &[("title", rarity_title)]),
+        )
+    } else if crystal {
+        (CRYSTAL_COLOR, format!("💎 {}", rarity_title))
+    } else {
+        (style.color, rarity_title.to_string())
+    };
+    let title = if outcome.birthday {
+        fill_template(text.birthday_title, &[("title", &title)])
+    } else {
+        title
+    };
+    let mut fish_name = if outcome.shiny {
+        fill_template(text.shiny_name, &[("fish", outcome.species.name)])
+    } else {
+        outcome.species.name.to_string()
+    };
+    if crystal {
+        fish_name = fill_template(text.crystal_name, &[("fish", &fish_name)]);
+    }
+    let description = fill_template(
+        text.catch_description,
+        &[
+            ("name", &name),
+            (
+                "location",
+                &format!("{} {}", outcome.location.emoji, outcome.location.name),
+            ),
+            (
+                "fish",
+                &format!("{} **{}**", outcome.species.emoji, fish_name),
+            ),
+            ("size", &outcome.size_cm.to_string()),
+            (
+                "rarity",
+                &format!("{} {}", style.emoji, text.rarity_label(rarity)),
+            ),
+        ],
+    );
+    let mut embed = serenity::CreateEmbed::new()
+        .color(color)
+        .title(title)
+        .description(format!("{}\n*{}*", description, flavor))
+        .thumbnail(avatar_url)
+        .field(
+            text.streak,
+            fill_template(text.streak_days, &[("days", &outcome.streak.to_string())]),
+            true,
+        )
+        .field(
+            text.total_catches,
+            format!("{}", outcome.total_catches),
+            true,
+        )
+        .field(text.catches_today, format!("{}", outcome.daily_count), true)
+        .field(
+            text.coins,
+            fill_template(
+                text.coins_earned,
+                &[
+                    ("earned", &outcome.coins_earned.to_string()),
+                    ("total", &outcome.coins.to_string()),
+                ],
+            ),
+            true,
+        )
+        .timestamp(Utc::now())
+        .footer(serenity::CreateEmbedFooter::new(&outcome.pond_name));
+
+    if cfg!(feature = "economy") {
+        let tickets = if outcome.lottery_tickets == 1 {
+            text.lottery_ticket
+        } else {
+            text.lottery_tickets
+        };
+        embed = embed.field(
+            text.lottery,
+            fill_template(tickets, &[("count", &outcome.lottery_tickets.to_string())]),
+            true,
+        );
+    }
+
+    if let Some((team, points)) = &outcome.team_points {
+        embed = embed.field(
+            text.team_points,
+            fill_template(
+                text.team_points_earned,
+                &[("points", &points.to_string()), ("team", team)],
+            ),
+            true,
+        );
+    }
+
+    if outcome.fish_of_the_day {
+        let bonus = if outcome.team_points.is_some() {
+            text.fish_of_the_day_team_bonus
+        } else {
+            text.fish_of_the_day_bonus
+        };
+        embed = embed.field(
+            text.fish_of_the_day,
+            fill_template(
+                bonus,
+                &[(
+                    "multiplier",
+                    &crate::game::FISH_OF_THE_DAY_MULTIPLIER.to_string(),
+                )],
+            ),
+            false,
+        );
+    }
+
+    if outcome.birthday {
+        let gift = nightmare_fishing_core::items::find_item(crate::game::BIRTHDAY_GIFT_ITEM)
+            .map(|item| {
+                fill_template(
+                    text.birthday_item,
+                    &[("item", &format!("{} {}", item.emoji, item.name))],
+                )
+            })
+            .unwrap_or_default();
+        embed = embed.field(
+            text.birthday_catch,
+            fill_template(
+                text.birthday_gift,
+                &[
+                    ("name", &name),
+                    ("coins", &crate::game::BIRTHDAY_GIFT_COINS.to_string()),
+                    ("item", &gift),
+                ],
+            ),
+            false,
+        );
+    }
+
+    if let Some(referrer_id) = &outcome.referral_paid {
+        embed = embed.field(
+            text.referral_reward,
+            fill_template(
+                text.referral_paid,
+                &[
+                    ("name", &name),
+                    ("streak", &crate::game::REFERRAL_STREAK.to_string()),
+                    ("referrer", &format!("<@{}>", referrer_id)),
+                    ("coins", &crate::game::REFERRAL_BONUS_COINS.to_string()),
+                ],
+            ),
+            false,
+        );
+    }
+
+    if outcome.combo {
+        embed = embed.field(
+            text.streak_combo,
+            fill_template(
+                text.streak_combo_text,
+                &[
+                    ("streak", &outcome.streak.to_string()),
+                    (
+                        "multiplier",
+                        &crate::game::COMBO_COIN_MULTIPLIER.to_string(),
+                    ),
+                ],
+            ),
+            false,
+        );
+    }
+
+    match outcome.booster_perk {
+        Some(BoosterPerk::ExtraLuck) => {
+            embed = embed.field(text.booster_luck, text.booster_luck_text, true);
+        }
+        Some(BoosterPerk::ExtraCast) => {
+            embed = embed.field(text.booster_perk, text.booster_cast_text, true);
+        }
+        _ => {}
+    }
+
+    if outcome.stream_frenzy {
+        embed = embed.field(text.stream_frenzy, text.stream_frenzy_text, false);
+    }
+
+    if let Some(bait) = outcome.bait_used {
+        embed = embed.field(
+            fill_template(text.bait_used, &[("emoji", bait.emoji)]),
+            fill_template(text.bait_used_text, &[("bait", bait.name)]),
+            true,
+        );
+    }
+
+    if let Some(event) = outcome.pond_event {
+        embed = embed.field(event.name(), event.announcement(), false);
+    }
+
+    if !outcome.new_titles.is_empty() {
+        let earned = outcome
+            .new_titles
+            .iter()
+            .map(|title| format!("**{}** — {}", title.label(), title.description))
+            .collect::<Vec<_>>()
+            .join("\n");
+        embed = embed.field(
+            text.new_title,
+            format!("{}\n{}", earned, text.new_title_hint),
+            false,
+        );
+    }
+
+    if !outcome.new_records.is_empty() {
+        let records = outcome.new_records.join(&format!("** {} **", text.and));
+        embed = embed.field(
+            text.new_record,
+            fill_template(
+                text.new_record_text,
+                &[("name", &name), ("records", &records)],
+            ),
+            false,
+        );
+    }
+
+    embed
+}
+
+/// The catch embed, plus its rendered card (shown as the embed image) when
+/// the `image-gen` feature is on
+pub fn catch_post(
+    username: &str,
+    avatar_url: String,
+    outcome: &CatchOutcome,
+) -> (serenity::CreateEmbed, Option<serenity::CreateAttachment>) {
+    let embed = catch_embed(username, avatar_url, outcome);
+    #[cfg(feature = "image-gen")]
+    {
+        use crate::render::catch_card;
+        let card = serenity::CreateAttachment::bytes(
+            catch_card::render(username, outcome),
+            catch_card::FILE_NAME,
+        );
+        (
+            embed.image(format!("attachment://{}", catch_card::FILE_NAME)),
+            Some(card),
+        )
+    }
+    #[cfg(not(feature = "image-gen"))]
+    (embed, None)
+}
+
+/// One line per all-time record; records set on `today` get a 🆕 marker
+pub fn hall_of_fame_lines(hall: &HallOfFame, today: Option<&str>) -> String {
+    let new = |date: &str| {
+        if today == Some(date) {
+            " 🆕"
+        } else {
+            ""
+        }
+    };
+    let mut lines = Vec::new();
+    if let Some(record) = &hall.longest_streak {
+        lines.push(format!(
+            "🔥 Longest Streak: **{} days** by {} ({}){}",
+            record.value,
+            record.username,
+            record.date,
+            new(&record.date)
+        ));
+    }
+    if let Some(record) = &hall.biggest_fish {
+        let species = record
+            .species
+            .as_deref()
+            .and_then(crate::fish::find_species)
+            .map_or("fish".to_string(), |s| format!("{} {}", s.emoji, s.name));
+        lines.push(format!(
+            "📏 Biggest Fish: **{} cm** {} by {} ({}){}",
+            record.value,
+            species,
+            record.username,
+            record.date,
+            new(&record.date)
+        ));
+    }
+    if let Some(record) = &hall.most_catches_in_day {
+        lines.push(format!(
+            "🎣 Most Catches in a Day: **{}** ({}){}",
+            record.value,
+            record.date,
+            new(&record.date)
+        ));
+    }
+    lines.join("\n")
+}
+
+/// Bold name followed by the equipped title, e.g. "**alice** · 🐉 Pond Legend"
+pub fn titled_name(username: &str, title: Option<&Title>) -> String {
+    match title {
+        Some(title) => format!("**{}** · *{}*", username, title.label()),
+        None => format!("**{}**", username),
+    }
+}
+
+/// Select menu listing every location; locked ones show how to open them
+pub fn location_menu(
+    custom_id: &str,
+    access: &[(&'static Location, bool)],
+) -> serenity::CreateActionRow {
+    let options = access
+        .iter()
+        .map(|(location, unlocked)| {
+            let description = if *unlocked {
+                location.description.to_string()
+            } else {
+                format!(
+                    "🔒 {} catches or {} coins (/unlock)",
+                    location.unlock_catches, location.unlock_coins
+                )
+            };
+            serenity::CreateSelectMenuOption::new(
+                format!("{} {}", location.emoji, location.name),
+                location.id,
+            )
+            .description(description)
+        })
+        .collect();
+
+    serenity::CreateActionRow::SelectMenu(
+        serenity::CreateSelectMenu::new(
+            custom_id,
+            serenity::CreateSelectMenuKind::String { options },
+        )
+        .placeholder("Choose where to cast your line"),
+    )
+}
+
+/// The persistent "Fish!" button row. Biome buttons cast straight into
+/// their location; the general pond button opens the location picker.
+pub fn fish_button_row(biome: Option<&Location>, paused: bool) -> serenity::CreateActionRow {
+    let button = match biome {
+        Some(location) => serenity::CreateButton::new(format!("fish_button:{}", location.id))
+            .label(format!("🎣 Fish in the {}!", location.name)),
+        None => serenity::CreateButton::new("fish_button").label("🎣 Fish!"),
+    };
+    let button = if paused {
+        button.label("🧊 The pond is frozen").disabled(true)
+    } else {
+        button
+    };
+    serenity::CreateActionRow::Buttons(vec![button.style(serenity::ButtonStyle::Primary)])
+}
+
+/// "Spin the wheel" button offered under a catch; only `user_id` may press it
+#[cfg(feature = "minigames")]
+pub fn spin_button_row(user_id: serenity::UserId) -> serenity::CreateActionRow {
+    serenity::CreateActionRow::Buttons(vec![serenity::CreateButton::new(format!(
+        "spin_wheel:{}",
+        user_id
+    ))
+    .label("🎡 Spin the wheel!")
+    .style(serenity::ButtonStyle::Success)])
+}
+
+/// Welcome text that sits above a fish button, with the Fish of the Day if one is set
+pub fn fish_button_content(
+    pond_name: &str,
+    biome: Option<&Location>,
+    featured: Option<&Species>,
+) -> String {
+    let mut content = match biome {
+        Some(location) => format!(
+            "{} Welcome to the {} — click to fish!",
+            location.emoji, location.name
+        ),
+        None => format!("🎣 Welcome to {} — click to fish!", pond_name),
+    };
+    if let Some(species) = featured {
+        let home = crate::fish::species_location(species.id).map_or("", |l| l.name);
+        content.push_str(&format!(
+            "\n🌟 Fish of the Day: {} **{}** ({}) — {}x coins!",
+            species.emoji,
+            species.name,
+            home,
+            crate::game::FISH_OF_THE_DAY_MULTIPLIER
+        ));
+    }
+    content
+}
+
+/// Lines describing a server's recent activity, for `/serverstats` and the
+/// weekly summary
+pub fn server_stats_lines(stats: &crate::analytics::ServerStats) -> String {
+    use crate::analytics::{MONTH_DAYS, WEEK_DAYS};
+    let mut lines = vec![
+        format!("👥 Anglers: **{}**", stats.players),
+        format!(
+            "📅 Fished in the last {} days: **{}** ({:.0}%)",
+            WEEK_DAYS,
+            stats.active_week,
+            stats.weekly_participation() * 100.0
+        ),
+        format!(
+            "🗓️ Fished in the last {} days: **{}** ({:.0}%)",
+            MONTH_DAYS,
+            stats.active_month,
+            stats.monthly_participation() * 100.0
+        ),
+        format!(
+            "🔥 Average streak: **{:.1} days** across {} running streaks",
+            stats.average_streak, stats.streaking
+        ),
+    ];
+    if stats.churned > 0 {
+        let mut names = stats.churned_names.join(", ");
+        if stats.churned > stats.churned_names.len() {
+            names.push_str(&format!(
+                " and {} more",
+                stats.churned - stats.churned_names.len()
+            ));
+        }
+        lines.push(format!(
+            "🎣 Drifted away this week: **{}** ({})",
+            stats.churned, names
+        ));
+    }
+    lines.join("\n")
+}
+
+/// The next reset on a player's clock, e.g. "20:00 today (Asia/Kolkata)"
+pub fn local_reset_time(reset: DateTime<Tz>) -> String {
+    let tz = reset.timezone();
+    let day = if reset.date_naive() == Utc::now().with_timezone(&tz).date_naive() {
+        "today"
+    } else {
+        "tomorrow"
+    };
+    format!("{} {} ({})", reset.format("%H:%M"), day, tz.name())
+}
+
+/// Reply to someone who has already cast since the reset, with the next
+/// reset on their own clock if they've set a timezone
+pub fn already_fished_message(local_reset: Option<DateTime<Tz>>) -> String {
+    match local_reset {
+        Some(reset) => format!(
+            "❌ You've already fished today! The pond resets at **{}**.",
+            local_reset_time(reset)
+        ),
+        None => "❌ You've already fished today! Come back tomorrow.".to_string(),
+    }
+}
+
+/// Reply to anyone trying to fish while `/pausefishing` is on
+pub const POND_FROZEN_MESSAGE: &str =
+    "🧊 The pond is frozen over for now. Fishing (and streaks) will pick up again once it thaws!";
+
+/// Reply to a member banned from fishing with `/fishban`
+pub fn fish_ban_message(until: Option<u64>, reason: Option<&str>) -> String {
+    let mut content = match until {
+        Some(until) => format!(
+            "🚫 You can't fish in this server right now. The ban lifts <t:{}:R>.",
+            until / 1000
+        ),
+        None => "🚫 You can't fish in this server.".to_string(),
+    };
+    if let Some(reason) = reason {
+        content.push_str(&format!("\nReason: {}", reason));
+    }
+    content
+}
+
+/// Reply to `/fish` used outside the channels set with `/fishchannels`,
+/// pointing at the fish button and the channels where it does work
+pub fn fish_channel_message<'a>(
+    button_channel: Option<&str>,
+    fish_channels: impl IntoIterator<Item = &'a String>,
+) -> String {
+    let mut channels: Vec<String> = fish_channels
+        .into_iter()
+        .map(|id| format!("<#{}>", id))
+        .collect();
+    channels.sort();
+    let mut content = format!("🎣 `/fish` only works in {}.", channels.join(", "));
+    if let Some(channel) = button_channel {
+        content.push_str(&format!(
+            " You can also click the fish button in <#{}>.",
+            channel
+        ));
+    }
+    content
+}
+
+/// Ephemeral message text for errors raised by a cast or unlock
+pub fn fishing_error_message(err: &crate::game::FishingError) -> Option<String> {
+    use crate::game::FishingError;
+    match err {
+        FishingError::AlreadyFished => Some(already_fished_message(None)),
+        FishingError::UnknownLocation => Some("❌ That fishing spot doesn't exist.".to_string()),
+        FishingError::LocationLocked => Some(
+            "🔒 You haven't unlocked that spot yet. Keep fishing or use `/unlock` to buy access."
+                .to_string(),
+        ),
+        FishingError::AlreadyUnlocked => Some("✅ You can already fish there!".to_string()),
+        FishingError::NotEnoughCoins { needed, available } => Some(format!(
+            "🪙 You need **{}** coins but only have **{}**.",
+            needed, available
+        )),
+        FishingError::MerchantGone => {
+            Some("🧳 The merchant has already packed up and left.".to_string())
+        }
+        FishingError::OutOfStock => Some("📦 Sorry, that item is sold out!".to_string()),
+        FishingError::CannotGiftSelf => Some("🎁 You can't send a gift to yourself!".to_string()),
+        FishingError::NotEnoughItems { available } => {
+            Some(format!("🎒 You only have **{}** of that item.", available))
+        }
+        FishingError::GiftLimitReached { remaining } => Some(format!(
+            "⏳ That's over today's gifting limit. You can give **{}** more today.",
+            remaining
+        )),
+        FishingError::CannotDuelSelf => Some("⚔️ You can't duel yourself!".to_string()),
+        FishingError::UnknownTitle => Some("❌ That title doesn't exist.".to_string()),
+        FishingError::TitleNotEarned => Some("🏅 You haven't earned that title yet.".to_string()),
+        FishingError::NoSpinAvailable => {
+            Some("🎡 You've already spun the wheel today!".to_string())
+        }
+        FishingError::TeamExists => Some("🏁 A team with that name already exists.".to_string()),
+        FishingError::UnknownTeam => Some("❌ That team doesn't exist.".to_string()),
+        FishingError::TooManyTeams => {
+            Some("🏁 This server already has as many teams as it can hold.".to_string())
+        }
+        FishingError::AlreadyOnTeam => Some("🏁 You're already on that team!".to_string()),
+        FishingError::NeverFished => {
+            Some("🎣 There's no streak to freeze yet — catch a fish first!".to_string())
+        }
+        FishingError::InvalidDate => {
+            Some("📅 Use a past date in YYYY-MM-DD format, e.g. 2024-03-14.".to_string())
+        }
+        FishingError::BoatGone => Some("⛵ That boat has already sailed!".to_string()),
+        FishingError::AlreadyAboard => Some("⛵ You're already aboard!".to_string()),
+        FishingError::WildFishGone => Some("🌊 Too slow — that fish is already gone!".to_string()),
+        FishingError::PondFrozen => Some(POND_FROZEN_MESSAGE.to_string()),
+        FishingError::FishBanned { until, reason } => {
+            Some(fish_ban_message(*until, reason.as_deref()))
+        }
+        FishingError::CannotReferSelf => Some("🤝 You can't refer yourself!".to_string()),
+        FishingError::AlreadyReferred => {
+            Some("🤝 Someone has already been credited for inviting that member.".to_string())
+        }
+        FishingError::NotNewMember => Some(
+            "🤝 That member has already fished here, so they can't be credited as a referral."
+                .to_string(),
+        ),
+        FishingError::BirthdayAlreadySet => {
+            Some("🎂 Your birthday is already registered and can't be changed.".to_string())
+        }
+        FishingError::UnknownTimezone => Some(
+            "🕒 That isn't a timezone I know. Use a name like `Europe/Berlin` or `America/New_York`."
+                .to_string(),
+        ),
+        FishingError::Internal(_) => None,
+    }
+}
+
+/// Celebration text for a legendary catch; `None` when fireworks are off
+pub fn fireworks_burst(intensity: u8, outcome: &CatchOutcome) -> Option<String> {
+    if intensity == 0 {
+        return None;
+    }
+    let row = "🎆✨🎇".repeat(intensity as usize * 2);
+    let mut lines = vec![row.clone()];
+    lines.push(format!(
+        "{} **LEGENDARY {}!** {}",
+        outcome.species.emoji,
+        outcome.species.name.to_uppercase(),
+        outcome.species.emoji
+    ));
+    for _ in 1..intensity {
+        lines.push("🎉🥳🎉".repeat(intensity as usize * 2));
+    }
+    lines.push(row);
+    Some(lines.join("\n"))
+}
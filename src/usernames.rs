@@ -0,0 +1,40 @@
+use crate::game::FishingManager;
+use poise::serenity_prelude as serenity;
+use std::collections::HashMap;
+
+/// Page through every member of the server and update the names stored for
+/// players who have since renamed themselves
+pub async fn refresh_usernames(
+    fishing_manager: &FishingManager,
+    http: &serenity::Http,
+    guild_id: &str,
+) {
+    let Ok(id) = guild_id.parse::<u64>() else {
+        return;
+    };
+    let guild = serenity::GuildId::new(id);
+
+    let mut names = HashMap::new();
+    let mut after = None;
+    loop {
+        let page = match guild.members(http, Some(1000), after).await {
+            Ok(page) => page,
+            Err(e) => {
+                tracing::error!("❌ Error fetching members to refresh usernames: {}", e);
+                return;
+            }
+        };
+        let Some(last) = page.last() else {
+            break;
+        };
+        after = Some(last.user.id);
+        names.extend(page.iter().map(|member| {
+            (
+                member.user.id.to_string(),
+                member.display_name().to_string(),
+            )
+        }));
+    }
+
+    fishing_manager.refresh_usernames(guild_id, &names).await;
+}
@@ -0,0 +1,95 @@
+use poise::serenity_prelude as serenity;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Custom ID prefix of the challenge buttons; the choice index follows
+pub const VERIFY_BUTTON_PREFIX: &str = "verify_catch:";
+/// How long a challenge can be answered
+const CHALLENGE_LIFETIME: Duration = Duration::from_secs(60);
+/// Fish the challenge picks its choices from
+const CHOICES: &[&str] = &["🐟", "🦀", "🐙", "🐢", "🦑", "🐡", "🦐", "🐬"];
+/// Buttons shown per challenge
+const CHOICES_SHOWN: usize = 4;
+
+/// Where a cast was started, which decides what gets reposted afterwards
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CastOrigin {
+    PondButton,
+    Command,
+    Biome,
+}
+
+/// A cast waiting on its challenge
+#[derive(Debug, Clone)]
+pub struct Challenge {
+    pub location_id: String,
+    pub origin: CastOrigin,
+    answer: usize,
+    expires_at: Instant,
+}
+
+/// Pick-the-right-fish challenges that stand between a click and a catch,
+/// so macro clickers can't farm streaks. Kept in memory: a restart only
+/// means clicking the fish button again.
+#[derive(Default)]
+pub struct Verifier {
+    pending: Mutex<HashMap<serenity::UserId, Challenge>>,
+}
+
+impl Verifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start a challenge for this cast, replacing any earlier one.
+    /// Returns the prompt and the buttons to show.
+    pub fn issue(
+        &self,
+        user_id: serenity::UserId,
+        location_id: &str,
+        origin: CastOrigin,
+    ) -> (String, serenity::CreateActionRow) {
+        let mut rng = rand::thread_rng();
+        let shown: Vec<&str> = CHOICES
+            .choose_multiple(&mut rng, CHOICES_SHOWN)
+            .copied()
+            .collect();
+        let answer = rng.gen_range(0..shown.len());
+
+        self.pending.lock().unwrap().insert(
+            user_id,
+            Challenge {
+                location_id: location_id.to_string(),
+                origin,
+                answer,
+                expires_at: Instant::now() + CHALLENGE_LIFETIME,
+            },
+        );
+
+        let buttons = shown
+            .iter()
+            .enumerate()
+            .map(|(i, emoji)| {
+                serenity::CreateButton::new(format!("{}{}", VERIFY_BUTTON_PREFIX, i))
+                    .label(*emoji)
+                    .style(serenity::ButtonStyle::Secondary)
+            })
+            .collect();
+        (
+            format!(
+                "🛡️ Quick check before you cast: click the {}",
+                shown[answer]
+            ),
+            serenity::CreateActionRow::Buttons(buttons),
+        )
+    }
+
+    /// Use up the user's challenge. Returns it only if `choice` was right and in time.
+    pub fn answer(&self, user_id: serenity::UserId, choice: usize) -> Option<Challenge> {
+        let challenge = self.pending.lock().unwrap().remove(&user_id)?;
+        (challenge.answer == choice && challenge.expires_at > Instant::now()).then_some(challenge)
+    }
+}
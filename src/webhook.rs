@@ -0,0 +1,198 @@
+use crate::game::FishingManager;
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode};
+use axum::routing::post;
+use axum::{Json, Router};
+use hmac::{Hmac, Mac};
+use poise::serenity_prelude as serenity;
+use serde::Deserialize;
+use sha2::Sha256;
+use std::sync::Arc;
+
+#[derive(Clone)]
+struct WebhookState {
+    fishing_manager: Arc<FishingManager>,
+    http: Arc<serenity::Http>,
+    secret: String,
+}
+
+/// Body accepted by the generic go-live endpoint (YouTube relays, IFTTT, etc.)
+#[derive(Deserialize)]
+struct LivePayload {
+    streamer: String,
+    url: Option<String>,
+}
+
+/// Run the stream go-live listener until the process exits
+pub async fn serve(
+    addr: String,
+    secret: String,
+    fishing_manager: Arc<FishingManager>,
+    http: Arc<serenity::Http>,
+) {
+    let state = WebhookState {
+        fishing_manager,
+        http,
+        secret,
+    };
+
+    let app = Router::new()
+        .route("/webhooks/twitch", post(twitch_callback))
+        .route("/webhooks/live", post(live_callback))
+        .with_state(state);
+
+    let listener = match tokio::net::TcpListener::bind(&addr).await {
+        Ok(listener) => listener,
+        Err(e) => {
+            tracing::error!(
+                "❌ Could not bind stream webhook listener on {}: {}",
+                addr,
+                e
+            );
+            return;
+        }
+    };
+
+    tracing::info!("📺 Stream webhook listening on {}", addr);
+    if let Err(e) = axum::serve(listener, app).await {
+        tracing::error!("❌ Stream webhook server stopped: {}", e);
+    }
+}
+
+/// Twitch EventSub callback: answers the verification challenge and
+/// reacts to `stream.online` notifications
+async fn twitch_callback(
+    State(state): State<WebhookState>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> (StatusCode, String) {
+    let header = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default()
+            .to_string()
+    };
+
+    if !verify_twitch_signature(
+        &state.secret,
+        &header("Twitch-Eventsub-Message-Id"),
+        &header("Twitch-Eventsub-Message-Timestamp"),
+        &body,
+        &header("Twitch-Eventsub-Message-Signature"),
+    ) {
+        tracing::warn!("⚠️ Rejected Twitch webhook with a bad signature");
+        return (StatusCode::FORBIDDEN, String::new());
+    }
+
+    let payload: serde_json::Value = match serde_json::from_slice(&body) {
+        Ok(payload) => payload,
+        Err(_) => return (StatusCode::BAD_REQUEST, String::new()),
+    };
+
+    match header("Twitch-Eventsub-Message-Type").as_str() {
+        "webhook_callback_verification" => (
+            StatusCode::OK,
+            payload["challenge"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+        ),
+        "notification" if payload["subscription"]["type"] == "stream.online" => {
+            let login = payload["event"]["broadcaster_user_login"]
+                .as_str()
+                .unwrap_or_default();
+            let url = format!("https://twitch.tv/{}", login);
+            trigger_frenzy(&state, login, Some(url)).await;
+            (StatusCode::NO_CONTENT, String::new())
+        }
+        _ => (StatusCode::NO_CONTENT, String::new()),
+    }
+}
+
+/// Generic go-live callback authenticated with the shared secret header
+async fn live_callback(
+    State(state): State<WebhookState>,
+    headers: HeaderMap,
+    Json(payload): Json<LivePayload>,
+) -> StatusCode {
+    let provided = headers
+        .get("X-Webhook-Secret")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default();
+    if provided != state.secret {
+        return StatusCode::UNAUTHORIZED;
+    }
+
+    trigger_frenzy(&state, &payload.streamer, payload.url).await;
+    StatusCode::NO_CONTENT
+}
+
+fn verify_twitch_signature(
+    secret: &str,
+    message_id: &str,
+    timestamp: &str,
+    body: &[u8],
+    signature: &str,
+) -> bool {
+    let expected = match signature.strip_prefix("sha256=").map(hex::decode) {
+        Some(Ok(expected)) => expected,
+        _ => return false,
+    };
+
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(message_id.as_bytes());
+    mac.update(timestamp.as_bytes());
+    mac.update(body);
+    mac.verify_slice(&expected).is_ok()
+}
+
+async fn trigger_frenzy(state: &WebhookState, streamer: &str, url: Option<String>) {
+    // Every server that follows this streamer gets its own frenzy
+    for guild_id in state.fishing_manager.guild_ids().await {
+        if let Some((until, channel_id)) = state
+            .fishing_manager
+            .start_stream_frenzy(&guild_id, streamer)
+            .await
+        {
+            announce_frenzy(state, &guild_id, streamer, url.clone(), until, channel_id).await;
+        }
+    }
+}
+
+async fn announce_frenzy(
+    state: &WebhookState,
+    guild_id: &str,
+    streamer: &str,
+    url: Option<String>,
+    until: u64,
+    channel_id: Option<String>,
+) {
+    let channel_id = match channel_id.and_then(|id| id.parse::<u64>().ok()) {
+        Some(id) => serenity::ChannelId::new(id),
+        None => return,
+    };
+
+    let mut embed = serenity::CreateEmbed::new()
+        .color(0x9146FF)
+        .title(format!("📺 {} is live!", streamer))
+        .description(format!(
+            "**Stream Frenzy!** Rare fish are biting more often until <t:{}:t>. Grab your rod! 🎣",
+            until / 1000
+        ))
+        .footer(serenity::CreateEmbedFooter::new(
+            state.fishing_manager.branding(guild_id).await.pond_name,
+        ));
+    if let Some(url) = url {
+        embed = embed.url(url);
+    }
+
+    if let Err(e) = channel_id
+        .send_message(&state.http, serenity::CreateMessage::new().embed(embed))
+        .await
+    {
+        tracing::error!("❌ Error announcing stream frenzy: {}", e);
+    }
+}
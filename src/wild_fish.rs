@@ -0,0 +1,93 @@
+use crate::game::FishingManager;
+use poise::serenity_prelude as serenity;
+use rand::Rng;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Custom ID prefix of the catch button; the spawn ID follows
+pub const CATCH_BUTTON_PREFIX: &str = "wild_fish:";
+/// How often the pond checks for a surfacing fish
+const CHECK_INTERVAL: Duration = Duration::from_secs(10 * 60);
+/// Chance per check that a fish surfaces (a few a day)
+const SPAWN_CHANCE: f64 = 0.03;
+/// How long a wild fish waits before swimming off
+const LIFETIME_MINUTES: u64 = 10;
+
+/// Surface wild fish on a fixed interval and clear away the ones nobody caught
+pub async fn run(fishing_manager: Arc<FishingManager>, http: Arc<serenity::Http>) {
+    let mut interval = tokio::time::interval(CHECK_INTERVAL);
+    loop {
+        interval.tick().await;
+        for guild_id in fishing_manager.guild_ids().await {
+            check(&fishing_manager, &http, &guild_id).await;
+        }
+    }
+}
+
+/// Clear away one server's uncaught fish and maybe surface a new one
+async fn check(fishing_manager: &FishingManager, http: &serenity::Http, guild_id: &str) {
+    if let Some(wild) = fishing_manager.expire_wild_fish(guild_id).await {
+        if let (Ok(channel), Some(Ok(message))) = (
+            wild.channel_id.parse::<u64>(),
+            wild.message_id.as_ref().map(|id| id.parse::<u64>()),
+        ) {
+            let edit = serenity::EditMessage::new()
+                .embed(
+                    serenity::CreateEmbed::new()
+                        .color(0x808080)
+                        .title("🌊 The wild fish swam away")
+                        .description("Nobody was quick enough this time."),
+                )
+                .components(vec![]);
+            let _ = serenity::ChannelId::new(channel)
+                .edit_message(http, serenity::MessageId::new(message), edit)
+                .await;
+        }
+    }
+
+    if !rand::thread_rng().gen_bool(SPAWN_CHANCE) {
+        return;
+    }
+    let expires_at = chrono::Utc::now().timestamp_millis() as u64 + LIFETIME_MINUTES * 60 * 1000;
+    let wild = match fishing_manager.spawn_wild_fish(guild_id, expires_at).await {
+        Some(wild) => wild,
+        None => return,
+    };
+    let channel = match wild.channel_id.parse::<u64>() {
+        Ok(id) => serenity::ChannelId::new(id),
+        Err(_) => return,
+    };
+
+    let embed = serenity::CreateEmbed::new()
+            .color(0x00B3B3)
+            .title("🐟 A wild fish surfaced!")
+            .description(format!(
+                "Something is splashing near the shore! The first to grab it keeps it — it dives again <t:{}:R>.",
+                expires_at / 1000
+            ))
+            .footer(serenity::CreateEmbedFooter::new(
+                fishing_manager.branding(guild_id).await.pond_name,
+            ));
+    let row = serenity::CreateActionRow::Buttons(vec![serenity::CreateButton::new(format!(
+        "{}{}",
+        CATCH_BUTTON_PREFIX, wild.id
+    ))
+    .label("🎣 Grab it!")
+    .style(serenity::ButtonStyle::Success)]);
+    match channel
+        .send_message(
+            http,
+            serenity::CreateMessage::new()
+                .embed(embed)
+                .components(vec![row]),
+        )
+        .await
+    {
+        Ok(msg) => {
+            fishing_manager
+                .set_wild_fish_message(guild_id, msg.id.to_string())
+                .await
+        }
+        Err(e) => tracing::error!("❌ Error posting wild fish: {}", e),
+    }
+}